@@ -0,0 +1,2 @@
+-- Add migration script here
+CREATE UNIQUE INDEX user_credentials_email_key ON user_credentials (LOWER(email));
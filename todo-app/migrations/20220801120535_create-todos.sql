@@ -0,0 +1,9 @@
+-- Add migration script here
+CREATE TABLE todos (
+    id UUID PRIMARY KEY,
+    user_id UUID NOT NULL,
+    title VARCHAR(100) NOT NULL,
+    FOREIGN KEY (user_id) REFERENCES users (id)
+);
+
+CREATE INDEX todos_user_id_idx ON todos (user_id);
@@ -0,0 +1,11 @@
+-- Add migration script here
+ALTER TABLE todos
+    ADD COLUMN description VARCHAR(1000),
+    ADD COLUMN due_date DATE,
+    ADD COLUMN completed_at TIMESTAMPTZ,
+    ADD COLUMN created_at TIMESTAMPTZ NOT NULL DEFAULT NOW(),
+    ADD COLUMN updated_at TIMESTAMPTZ NOT NULL DEFAULT NOW();
+
+ALTER TABLE todos
+    ALTER COLUMN created_at DROP DEFAULT,
+    ALTER COLUMN updated_at DROP DEFAULT;
@@ -0,0 +1,7 @@
+-- Add migration script here
+DROP INDEX IF EXISTS todos_user_id_idx;
+
+CREATE INDEX todos_user_id_created_at_idx ON todos (user_id, created_at, id);
+CREATE INDEX todos_user_id_updated_at_idx ON todos (user_id, updated_at, id);
+CREATE INDEX todos_user_id_due_date_idx ON todos (user_id, COALESCE(due_date, 'infinity'::DATE), id);
+CREATE INDEX todos_user_id_title_idx ON todos (user_id, (title COLLATE "C"), id);
@@ -0,0 +1,8 @@
+-- Add migration script here
+ALTER TABLE todos
+    ADD COLUMN search_vector TSVECTOR NOT NULL GENERATED ALWAYS AS (
+        setweight(to_tsvector('simple', title), 'A')
+            || setweight(to_tsvector('simple', COALESCE(description, '')), 'B')
+    ) STORED;
+
+CREATE INDEX todos_search_vector_idx ON todos USING GIN (search_vector);
@@ -0,0 +1,20 @@
+-- Add migration script here
+CREATE TABLE tags (
+    id UUID PRIMARY KEY,
+    user_id UUID NOT NULL,
+    name VARCHAR(30) NOT NULL,
+    color VARCHAR(7) NOT NULL,
+    FOREIGN KEY (user_id) REFERENCES users (id)
+);
+
+CREATE UNIQUE INDEX tags_user_id_name_key ON tags (user_id, LOWER(name));
+
+CREATE TABLE todo_tags (
+    todo_id UUID NOT NULL,
+    tag_id UUID NOT NULL,
+    PRIMARY KEY (todo_id, tag_id),
+    FOREIGN KEY (todo_id) REFERENCES todos (id) ON DELETE CASCADE,
+    FOREIGN KEY (tag_id) REFERENCES tags (id) ON DELETE CASCADE
+);
+
+CREATE INDEX todo_tags_tag_id_idx ON todo_tags (tag_id);
@@ -0,0 +1,32 @@
+-- Add migration script here
+CREATE TABLE todo_lists (
+    id UUID PRIMARY KEY,
+    user_id UUID NOT NULL,
+    name VARCHAR(100) NOT NULL,
+    position INTEGER NOT NULL,
+    archived BOOLEAN NOT NULL DEFAULT FALSE,
+    inbox BOOLEAN NOT NULL DEFAULT FALSE,
+    FOREIGN KEY (user_id) REFERENCES users (id)
+);
+
+CREATE UNIQUE INDEX todo_lists_user_id_inbox_key ON todo_lists (user_id) WHERE inbox;
+CREATE INDEX todo_lists_user_id_position_idx ON todo_lists (user_id, position, id);
+
+INSERT INTO todo_lists (id, user_id, name, position, inbox)
+SELECT gen_random_uuid(), id, 'Inbox', 0, TRUE FROM users;
+
+ALTER TABLE todos ADD COLUMN todo_list_id UUID;
+
+UPDATE todos SET todo_list_id = todo_lists.id
+FROM todo_lists
+WHERE todo_lists.user_id = todos.user_id AND todo_lists.inbox;
+
+ALTER TABLE todos
+    ALTER COLUMN todo_list_id SET NOT NULL,
+    ADD FOREIGN KEY (todo_list_id) REFERENCES todo_lists (id);
+
+CREATE INDEX todos_todo_list_id_idx ON todos (todo_list_id);
+CREATE INDEX todos_user_id_todo_list_id_created_at_idx ON todos (user_id, todo_list_id, created_at, id);
+CREATE INDEX todos_user_id_todo_list_id_updated_at_idx ON todos (user_id, todo_list_id, updated_at, id);
+CREATE INDEX todos_user_id_todo_list_id_due_date_idx ON todos (user_id, todo_list_id, COALESCE(due_date, 'infinity'::DATE), id);
+CREATE INDEX todos_user_id_todo_list_id_title_idx ON todos (user_id, todo_list_id, (title COLLATE "C"), id);
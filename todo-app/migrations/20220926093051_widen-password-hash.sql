@@ -0,0 +1,2 @@
+-- Add migration script here
+ALTER TABLE user_credentials ALTER COLUMN password_hash TYPE VARCHAR(255);
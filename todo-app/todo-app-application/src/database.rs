@@ -1,7 +1,15 @@
 mod db;
+mod isolation_level;
 mod repositories;
+mod retry_policy;
 mod transaction;
+mod unit_of_work;
 
-pub use db::DB;
+#[cfg(test)]
+pub use db::MockDB;
+pub use db::{in_transaction, TransactionFuture, DB};
+pub use isolation_level::IsolationLevel;
 pub use repositories::Repositories;
+pub use retry_policy::{RetryPolicy, RetryableError};
 pub use transaction::Transaction;
+pub use unit_of_work::UnitOfWork;
@@ -1,10 +1,83 @@
+#[cfg(test)]
 use std::sync::Arc;
+use std::{future::Future, pin::Pin};
 
 use async_trait::async_trait;
+#[cfg(test)]
+use todo_app_domain::aggregate_root::{
+    email_verification_token::repository::EmailVerificationTokenRepository,
+    password_reset_token::repository::PasswordResetTokenRepository, tag::repository::TagRepository,
+    todo::repository::TodoRepository, todo_list::repository::TodoListRepository,
+    user::repository::UserRepository, user_credential::repository::UserCredentialRepository,
+};
+use todo_app_domain::error::RepositoryError;
 
-use crate::database::{Repositories, Transaction};
+use crate::database::{IsolationLevel, Repositories, Transaction};
 
 #[async_trait]
 pub trait DB: Repositories {
-    async fn begin(&self) -> Arc<dyn Transaction>;
+    async fn begin(&self) -> Result<Box<dyn Transaction>, RepositoryError> {
+        self.begin_with(IsolationLevel::default()).await
+    }
+
+    async fn begin_with(
+        &self,
+        isolation_level: IsolationLevel,
+    ) -> Result<Box<dyn Transaction>, RepositoryError>;
+}
+
+#[cfg(test)]
+mockall::mock! {
+    pub DB {}
+
+    impl Repositories for DB {
+        fn user_repository(&self) -> Arc<dyn UserRepository>;
+        fn user_credential_repository(&self) -> Arc<dyn UserCredentialRepository>;
+        fn todo_list_repository(&self) -> Arc<dyn TodoListRepository>;
+        fn todo_repository(&self) -> Arc<dyn TodoRepository>;
+        fn tag_repository(&self) -> Arc<dyn TagRepository>;
+        fn password_reset_token_repository(&self) -> Arc<dyn PasswordResetTokenRepository>;
+        fn email_verification_token_repository(&self) -> Arc<dyn EmailVerificationTokenRepository>;
+    }
+
+    #[async_trait]
+    impl DB for DB {
+        async fn begin_with(
+            &self,
+            isolation_level: IsolationLevel,
+        ) -> Result<Box<dyn Transaction>, RepositoryError>;
+    }
+}
+
+#[cfg(test)]
+impl std::fmt::Debug for MockDB {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        f.debug_struct("MockDB").finish_non_exhaustive()
+    }
+}
+
+pub type TransactionFuture<'a, T, E> = Pin<Box<dyn Future<Output = Result<T, E>> + Send + 'a>>;
+
+/// Runs `f` in a new transaction, committing it if `f` returns `Ok` and rolling it back otherwise.
+///
+/// Repositories taken from the transaction must not outlive the future returned by `f`.
+pub async fn in_transaction<T, E, F>(db: &dyn DB, f: F) -> Result<T, E>
+where
+    F: for<'a> FnOnce(&'a dyn Transaction) -> TransactionFuture<'a, T, E>,
+    E: From<RepositoryError>,
+{
+    let tx = db.begin().await?;
+    let result = f(tx.as_ref()).await;
+    match result {
+        Ok(value) => {
+            tx.commit().await?;
+            Ok(value)
+        }
+        Err(e) => {
+            // The error from `f` is the one worth reporting; a failed rollback is
+            // discarded along with the transaction by the database anyway.
+            let _ = tx.rollback().await;
+            Err(e)
+        }
+    }
 }
@@ -1,12 +1,18 @@
 use std::{fmt::Debug, sync::Arc};
 
 use todo_app_domain::aggregate_root::{
-    todo::repository::TodoRepository, user::repository::UserRepository,
-    user_credential::repository::UserCredentialRepository,
+    email_verification_token::repository::EmailVerificationTokenRepository,
+    password_reset_token::repository::PasswordResetTokenRepository, tag::repository::TagRepository,
+    todo::repository::TodoRepository, todo_list::repository::TodoListRepository,
+    user::repository::UserRepository, user_credential::repository::UserCredentialRepository,
 };
 
 pub trait Repositories: Debug + Send + Sync {
     fn user_repository(&self) -> Arc<dyn UserRepository>;
     fn user_credential_repository(&self) -> Arc<dyn UserCredentialRepository>;
+    fn todo_list_repository(&self) -> Arc<dyn TodoListRepository>;
     fn todo_repository(&self) -> Arc<dyn TodoRepository>;
+    fn tag_repository(&self) -> Arc<dyn TagRepository>;
+    fn password_reset_token_repository(&self) -> Arc<dyn PasswordResetTokenRepository>;
+    fn email_verification_token_repository(&self) -> Arc<dyn EmailVerificationTokenRepository>;
 }
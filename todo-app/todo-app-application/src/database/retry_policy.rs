@@ -0,0 +1,90 @@
+use std::time::Duration;
+
+use todo_app_domain::error::RepositoryError;
+
+/// Errors that tell whether the failed unit of work may succeed if run again.
+pub trait RetryableError {
+    fn is_retryable(&self) -> bool;
+}
+
+impl RetryableError for RepositoryError {
+    fn is_retryable(&self) -> bool {
+        matches!(self, Self::ConcurrentModification)
+    }
+}
+
+/// How many times a unit of work is attempted, and how long to wait between attempts.
+///
+/// The backoff doubles after every failed attempt, starting from `initial_backoff`
+/// and capped at `max_backoff`.
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub struct RetryPolicy {
+    max_attempts: u32,
+    initial_backoff: Duration,
+    max_backoff: Duration,
+}
+
+impl RetryPolicy {
+    pub fn new(max_attempts: u32, initial_backoff: Duration, max_backoff: Duration) -> Self {
+        Self {
+            max_attempts: max_attempts.max(1),
+            initial_backoff,
+            max_backoff,
+        }
+    }
+
+    /// Attempts the unit of work only once.
+    pub fn never() -> Self {
+        Self::new(1, Duration::ZERO, Duration::ZERO)
+    }
+
+    pub fn max_attempts(&self) -> u32 {
+        self.max_attempts
+    }
+
+    /// The time to wait after the given failed attempt (starting at 1).
+    pub fn backoff(&self, attempt: u32) -> Duration {
+        let factor = 2u32.saturating_pow(attempt.saturating_sub(1));
+        self.initial_backoff
+            .saturating_mul(factor)
+            .min(self.max_backoff)
+    }
+}
+
+impl Default for RetryPolicy {
+    fn default() -> Self {
+        Self::new(3, Duration::from_millis(10), Duration::from_millis(100))
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn retry_policy_backoff() {
+        let policy = RetryPolicy::new(5, Duration::from_millis(10), Duration::from_millis(50));
+
+        let tests = vec![(1, 10), (2, 20), (3, 40), (4, 50), (5, 50), (40, 50)];
+        for (attempt, expected) in tests {
+            assert_eq!(
+                policy.backoff(attempt),
+                Duration::from_millis(expected),
+                "attempt: {attempt}"
+            );
+        }
+    }
+
+    #[test]
+    fn retry_policy_max_attempts() {
+        let tests = vec![(0, 1), (1, 1), (3, 3)];
+        for (max_attempts, expected) in tests {
+            let policy = RetryPolicy::new(max_attempts, Duration::ZERO, Duration::ZERO);
+            assert_eq!(
+                policy.max_attempts(),
+                expected,
+                "max_attempts: {max_attempts}"
+            );
+        }
+    }
+}
@@ -1,9 +1,10 @@
 use async_trait::async_trait;
+use todo_app_domain::error::RepositoryError;
 
 use crate::database::Repositories;
 
 #[async_trait]
 pub trait Transaction: Repositories {
-    async fn commit(self: Box<Self>);
-    async fn rollback(self: Box<Self>);
+    async fn commit(self: Box<Self>) -> Result<(), RepositoryError>;
+    async fn rollback(self: Box<Self>) -> Result<(), RepositoryError>;
 }
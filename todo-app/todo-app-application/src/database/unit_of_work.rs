@@ -0,0 +1,88 @@
+use todo_app_domain::error::RepositoryError;
+
+use crate::database::{
+    IsolationLevel, RetryPolicy, RetryableError, Transaction, TransactionFuture, DB,
+};
+
+/// Runs a closure in a transaction with the given isolation level, running it again
+/// in a new transaction when it fails with a retryable error.
+///
+/// The closure may be called several times, so it must not have side effects outside
+/// the transaction.
+#[derive(Clone, Copy, Debug, Default, PartialEq, Eq)]
+pub struct UnitOfWork {
+    isolation_level: IsolationLevel,
+    retry_policy: RetryPolicy,
+}
+
+impl UnitOfWork {
+    pub fn new(isolation_level: IsolationLevel, retry_policy: RetryPolicy) -> Self {
+        Self {
+            isolation_level,
+            retry_policy,
+        }
+    }
+
+    pub fn isolation_level(&self) -> IsolationLevel {
+        self.isolation_level
+    }
+
+    pub fn retry_policy(&self) -> RetryPolicy {
+        self.retry_policy
+    }
+
+    pub async fn run<T, E, F>(&self, db: &dyn DB, mut f: F) -> Result<T, E>
+    where
+        F: for<'a> FnMut(&'a dyn Transaction) -> TransactionFuture<'a, T, E>,
+        E: From<RepositoryError> + RetryableError + std::fmt::Display,
+    {
+        let max_attempts = self.retry_policy.max_attempts();
+        let mut attempt = 1;
+        loop {
+            tracing::debug!(
+                attempt,
+                max_attempts,
+                isolation_level = %self.isolation_level,
+                "starting unit of work"
+            );
+
+            let e = match self.attempt(db, &mut f).await {
+                Ok(value) => return Ok(value),
+                Err(e) => e,
+            };
+            if !e.is_retryable() || attempt >= max_attempts {
+                tracing::debug!(attempt, max_attempts, error = %e, "unit of work failed");
+                return Err(e);
+            }
+
+            let backoff = self.retry_policy.backoff(attempt);
+            tracing::warn!(
+                attempt,
+                max_attempts,
+                backoff_ms = backoff.as_millis() as u64,
+                error = %e,
+                "retrying unit of work"
+            );
+            tokio::time::sleep(backoff).await;
+            attempt += 1;
+        }
+    }
+
+    async fn attempt<T, E, F>(&self, db: &dyn DB, f: &mut F) -> Result<T, E>
+    where
+        F: for<'a> FnMut(&'a dyn Transaction) -> TransactionFuture<'a, T, E>,
+        E: From<RepositoryError>,
+    {
+        let tx = db.begin_with(self.isolation_level).await?;
+        match f(tx.as_ref()).await {
+            Ok(value) => {
+                tx.commit().await?;
+                Ok(value)
+            }
+            Err(e) => {
+                let _ = tx.rollback().await;
+                Err(e)
+            }
+        }
+    }
+}
@@ -1,2 +1,4 @@
 pub mod database;
+pub mod mail;
+pub mod throttle;
 pub mod usecase;
@@ -0,0 +1,5 @@
+mod mail_message;
+mod mailer;
+
+pub use mail_message::MailMessage;
+pub use mailer::Mailer;
@@ -0,0 +1,18 @@
+use getset::Getters;
+use todo_app_domain::aggregate_root::user_credential::value_object::Email;
+
+#[derive(Clone, Debug, Eq, Getters, PartialEq)]
+pub struct MailMessage {
+    #[getset(get = "pub")]
+    to: Email,
+    #[getset(get = "pub")]
+    subject: String,
+    #[getset(get = "pub")]
+    body: String,
+}
+
+impl MailMessage {
+    pub fn new(to: Email, subject: String, body: String) -> Self {
+        Self { to, subject, body }
+    }
+}
@@ -0,0 +1,8 @@
+use async_trait::async_trait;
+
+use crate::mail::MailMessage;
+
+#[async_trait]
+pub trait Mailer: std::fmt::Debug + Send + Sync {
+    async fn send(&self, message: &MailMessage) -> Result<(), anyhow::Error>;
+}
@@ -0,0 +1,9 @@
+mod login_attempt_store;
+mod login_throttle;
+mod login_throttle_policy;
+
+pub use login_attempt_store::LoginAttemptStore;
+#[cfg(test)]
+pub use login_attempt_store::MockLoginAttemptStore;
+pub use login_throttle::LoginThrottle;
+pub use login_throttle_policy::LoginThrottlePolicy;
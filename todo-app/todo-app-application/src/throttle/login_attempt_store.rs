@@ -0,0 +1,25 @@
+use async_trait::async_trait;
+use chrono::{DateTime, Duration, Utc};
+
+/// Failed login attempts and lockouts, counted per key (an account or an IP address).
+#[async_trait]
+#[cfg_attr(test, mockall::automock)]
+pub trait LoginAttemptStore: std::fmt::Debug + Send + Sync {
+    /// Records a failure and returns the number of failures recorded for the key so far.
+    ///
+    /// The failures are forgotten once `window` passes without a new one.
+    async fn record_failure(&self, key: &str, window: Duration) -> Result<u32, anyhow::Error>;
+
+    /// Locks the key until `until`, and keeps its failures for `window` after that.
+    async fn lock(
+        &self,
+        key: &str,
+        until: DateTime<Utc>,
+        window: Duration,
+    ) -> Result<(), anyhow::Error>;
+
+    async fn locked_until(&self, key: &str) -> Result<Option<DateTime<Utc>>, anyhow::Error>;
+
+    /// Forgets the failures and the lockout of the key.
+    async fn reset(&self, key: &str) -> Result<(), anyhow::Error>;
+}
@@ -0,0 +1,82 @@
+use std::{net::IpAddr, sync::Arc};
+
+use chrono::{DateTime, Duration, Utc};
+
+use crate::throttle::{LoginAttemptStore, LoginThrottlePolicy};
+
+/// Locks accounts and IP addresses out after repeated failed logins.
+///
+/// Accounts are keyed by the email address as entered, whether or not it belongs to a user,
+/// so that a lockout does not reveal which addresses are registered.
+#[derive(Clone, Debug)]
+pub struct LoginThrottle {
+    store: Arc<dyn LoginAttemptStore>,
+    policy: LoginThrottlePolicy,
+}
+
+impl LoginThrottle {
+    pub fn new(store: Arc<dyn LoginAttemptStore>, policy: LoginThrottlePolicy) -> Self {
+        Self { store, policy }
+    }
+
+    /// Returns how long the login has to wait if the account or the IP address is locked out.
+    pub async fn check(&self, email: &str, ip: IpAddr) -> Result<Option<Duration>, anyhow::Error> {
+        let now = Utc::now();
+        let mut retry_after = None;
+        for key in [account_key(email), ip_key(ip)] {
+            if let Some(locked_until) = self.store.locked_until(&key).await? {
+                let remaining = locked_until - now;
+                if remaining > Duration::zero() {
+                    retry_after = retry_after.max(Some(remaining));
+                }
+            }
+        }
+
+        Ok(retry_after)
+    }
+
+    pub async fn record_failure(&self, email: &str, ip: IpAddr) -> Result<(), anyhow::Error> {
+        let keys = [
+            (account_key(email), self.policy.max_account_failures()),
+            (ip_key(ip), self.policy.max_ip_failures()),
+        ];
+        for (key, max_failures) in keys {
+            let failures = self
+                .store
+                .record_failure(&key, self.policy.failure_window())
+                .await?;
+            if let Some(lockout) = self.policy.lockout(failures, max_failures) {
+                let locked_until = Utc::now() + lockout;
+                self.store
+                    .lock(&key, locked_until, self.policy.failure_window())
+                    .await?;
+                audit_lockout(&key, failures, locked_until);
+            }
+        }
+
+        Ok(())
+    }
+
+    /// Forgets the failures of the account; those of the IP address are kept.
+    pub async fn record_success(&self, email: &str) -> Result<(), anyhow::Error> {
+        self.store.reset(&account_key(email)).await
+    }
+}
+
+fn account_key(email: &str) -> String {
+    format!("account:{}", email.to_lowercase())
+}
+
+fn ip_key(ip: IpAddr) -> String {
+    format!("ip:{ip}")
+}
+
+fn audit_lockout(key: &str, failures: u32, locked_until: DateTime<Utc>) {
+    tracing::warn!(
+        target: "audit",
+        key,
+        failures,
+        %locked_until,
+        "login locked out after repeated failures"
+    );
+}
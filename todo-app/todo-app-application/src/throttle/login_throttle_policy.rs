@@ -0,0 +1,93 @@
+use chrono::Duration;
+use getset::CopyGetters;
+
+/// When failed logins lock an account or an IP address out, and for how long.
+///
+/// Once a key reaches its failure limit, every further failure locks it out again, for
+/// `base_lockout` doubled after each lockout and capped at `max_lockout`.
+#[derive(Clone, Copy, CopyGetters, Debug, PartialEq, Eq)]
+pub struct LoginThrottlePolicy {
+    #[getset(get_copy = "pub")]
+    max_account_failures: u32,
+    #[getset(get_copy = "pub")]
+    max_ip_failures: u32,
+    #[getset(get_copy = "pub")]
+    base_lockout: Duration,
+    #[getset(get_copy = "pub")]
+    max_lockout: Duration,
+    /// How long failures are remembered after the last one.
+    #[getset(get_copy = "pub")]
+    failure_window: Duration,
+}
+
+impl LoginThrottlePolicy {
+    pub fn new(
+        max_account_failures: u32,
+        max_ip_failures: u32,
+        base_lockout: Duration,
+        max_lockout: Duration,
+        failure_window: Duration,
+    ) -> Self {
+        Self {
+            max_account_failures: max_account_failures.max(1),
+            max_ip_failures: max_ip_failures.max(1),
+            base_lockout,
+            max_lockout,
+            failure_window,
+        }
+    }
+
+    /// The lockout caused by the given number of failures, if the limit has been reached.
+    pub fn lockout(&self, failures: u32, max_failures: u32) -> Option<Duration> {
+        let lockouts = failures.checked_sub(max_failures)?;
+        let factor = 2i64.saturating_pow(lockouts);
+        let lockout = self.base_lockout.num_milliseconds().saturating_mul(factor);
+
+        Some(Duration::milliseconds(lockout).min(self.max_lockout))
+    }
+}
+
+impl Default for LoginThrottlePolicy {
+    fn default() -> Self {
+        Self::new(
+            5,
+            20,
+            Duration::minutes(1),
+            Duration::hours(1),
+            Duration::minutes(15),
+        )
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn login_throttle_policy_lockout() {
+        let policy = LoginThrottlePolicy::new(
+            3,
+            10,
+            Duration::seconds(10),
+            Duration::seconds(50),
+            Duration::minutes(1),
+        );
+
+        let tests = vec![
+            (0, None),
+            (2, None),
+            (3, Some(10)),
+            (4, Some(20)),
+            (5, Some(40)),
+            (6, Some(50)),
+            (u32::MAX, Some(50)),
+        ];
+        for (failures, expected) in tests {
+            assert_eq!(
+                policy.lockout(failures, policy.max_account_failures()),
+                expected.map(Duration::seconds),
+                "failures: {failures}"
+            );
+        }
+    }
+}
@@ -1,7 +1,65 @@
+mod change_email_usecase;
+mod change_password_usecase;
+mod create_tag_usecase;
+mod create_todo_list_usecase;
+mod create_todo_usecase;
+mod delete_tag_usecase;
+mod delete_todo_list_usecase;
+mod delete_todo_usecase;
+mod delete_user_usecase;
+mod email_verification_policy;
+mod get_tag_usecase;
+mod get_todo_list_usecase;
+mod get_todo_usecase;
+mod get_user_usecase;
+mod list_tags_usecase;
+mod list_todo_lists_usecase;
+mod list_todos_usecase;
 mod login_usecase;
+mod move_todo_usecase;
+mod password_verifier;
+mod request_password_reset_usecase;
+mod reset_password_usecase;
+mod search_todos_usecase;
+mod set_todo_tags_usecase;
 mod signup_usecase;
+mod update_tag_usecase;
+mod update_todo_list_usecase;
+mod update_todo_usecase;
+mod update_user_usecase;
+mod verify_email_usecase;
 
 pub mod error;
 
+pub use change_email_usecase::ChangeEmailUsecase;
+pub use change_password_usecase::ChangePasswordUsecase;
+pub use create_tag_usecase::CreateTagUsecase;
+pub use create_todo_list_usecase::CreateTodoListUsecase;
+pub use create_todo_usecase::CreateTodoUsecase;
+pub use delete_tag_usecase::DeleteTagUsecase;
+pub use delete_todo_list_usecase::DeleteTodoListUsecase;
+pub use delete_todo_usecase::DeleteTodoUsecase;
+pub use delete_user_usecase::DeleteUserUsecase;
+pub use email_verification_policy::EmailVerificationPolicy;
+pub use get_tag_usecase::GetTagUsecase;
+pub use get_todo_list_usecase::GetTodoListUsecase;
+pub use get_todo_usecase::GetTodoUsecase;
+pub use get_user_usecase::GetUserUsecase;
+pub use list_tags_usecase::ListTagsUsecase;
+pub use list_todo_lists_usecase::ListTodoListsUsecase;
+pub use list_todos_usecase::{ListTodosQuery, ListTodosUsecase};
 pub use login_usecase::LoginUsecase;
+pub use move_todo_usecase::MoveTodoUsecase;
+#[cfg(test)]
+pub use password_verifier::MockPasswordVerifier;
+pub use password_verifier::{Argon2PasswordVerifier, PasswordVerifier};
+pub use request_password_reset_usecase::RequestPasswordResetUsecase;
+pub use reset_password_usecase::ResetPasswordUsecase;
+pub use search_todos_usecase::SearchTodosUsecase;
+pub use set_todo_tags_usecase::SetTodoTagsUsecase;
 pub use signup_usecase::SignupUsecase;
+pub use update_tag_usecase::UpdateTagUsecase;
+pub use update_todo_list_usecase::UpdateTodoListUsecase;
+pub use update_todo_usecase::UpdateTodoUsecase;
+pub use update_user_usecase::UpdateUserUsecase;
+pub use verify_email_usecase::VerifyEmailUsecase;
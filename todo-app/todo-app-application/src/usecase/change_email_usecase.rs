@@ -0,0 +1,135 @@
+use std::{net::IpAddr, sync::Arc};
+
+use nameof::name_of;
+use todo_app_domain::{
+    aggregate_root::{user::value_object::UserId, user_credential::value_object::Email},
+    error::ValidationErrors,
+};
+
+use crate::{
+    database::{IsolationLevel, RetryPolicy, UnitOfWork, DB},
+    mail::Mailer,
+    throttle::LoginThrottle,
+    usecase::{
+        change_password_usecase::{verify_current_password, wrong_password_error},
+        error::UsecaseError,
+        get_user_usecase::user_not_found_error,
+        verify_email_usecase::{issue_email_verification_token, send_email_verification_mail},
+        EmailVerificationPolicy,
+    },
+};
+
+#[derive(Clone, Debug)]
+pub struct ChangeEmailUsecase {
+    db: Arc<dyn DB>,
+    mailer: Arc<dyn Mailer>,
+    email_verification_policy: EmailVerificationPolicy,
+    login_throttle: LoginThrottle,
+    unit_of_work: UnitOfWork,
+}
+
+impl ChangeEmailUsecase {
+    pub fn new(
+        db: Arc<dyn DB>,
+        mailer: Arc<dyn Mailer>,
+        email_verification_policy: EmailVerificationPolicy,
+        login_throttle: LoginThrottle,
+    ) -> Self {
+        Self {
+            db,
+            mailer,
+            email_verification_policy,
+            login_throttle,
+            unit_of_work: UnitOfWork::new(IsolationLevel::RepeatableRead, RetryPolicy::default()),
+        }
+    }
+
+    pub fn with_unit_of_work(self, unit_of_work: UnitOfWork) -> Self {
+        Self {
+            unit_of_work,
+            ..self
+        }
+    }
+
+    /// Changes the email address and mails a verification token to the new address.
+    ///
+    /// Failed checks of the password count towards the same lockout as failed logins.
+    pub async fn execute(
+        &self,
+        user_id: &UserId,
+        password: String,
+        email: String,
+        ip: IpAddr,
+    ) -> Result<Email, UsecaseError> {
+        let email = Email::try_from(email).map_err(|email| UsecaseError::Expected {
+            message: "invalid email change",
+            errors: ValidationErrors::builder()
+                .error(name_of!(email), email)
+                .build(),
+        })?;
+
+        let password_field = name_of!(password);
+        let password_hash = verify_current_password(
+            self.db.as_ref(),
+            &self.login_throttle,
+            user_id,
+            &password,
+            ip,
+            password_field,
+        )
+        .await?;
+
+        let token_lifetime = self.email_verification_policy.token_lifetime();
+        let (user_credential, token) = self
+            .unit_of_work
+            .run(self.db.as_ref(), |tx| {
+                let user_id = user_id.clone();
+                let password_hash = password_hash.clone();
+                let email = email.clone();
+                Box::pin(async move {
+                    let user_credential_repository = tx.user_credential_repository();
+                    let mut user_credential = user_credential_repository
+                        .find(&user_id)
+                        .await?
+                        .ok_or_else(user_not_found_error)?;
+
+                    // The password may have been changed since it was verified.
+                    if user_credential.password_hash() != &password_hash {
+                        return Err(wrong_password_error(password_field));
+                    }
+
+                    if user_credential.email() == &email {
+                        return Ok((user_credential, None));
+                    }
+
+                    user_credential.set_email(email);
+                    user_credential_repository.update(&user_credential).await?;
+
+                    let token = issue_email_verification_token(
+                        tx,
+                        user_credential.user_id(),
+                        user_credential.email(),
+                        token_lifetime,
+                    )
+                    .await?;
+
+                    Ok((user_credential, Some(token)))
+                })
+            })
+            .await?;
+
+        if let Some((email_verification_token, token)) = token {
+            send_email_verification_mail(
+                self.mailer.as_ref(),
+                &user_credential,
+                &token,
+                email_verification_token.expires_at(),
+            )
+            .await;
+        }
+
+        let (_, email, _, _) = user_credential.into_inner();
+
+        Ok(email)
+    }
+}
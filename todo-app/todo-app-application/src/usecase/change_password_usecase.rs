@@ -0,0 +1,145 @@
+use std::{net::IpAddr, sync::Arc};
+
+use nameof::name_of;
+use todo_app_domain::{
+    aggregate_root::{
+        user::value_object::UserId,
+        user_credential::value_object::{Password, PasswordHash, PasswordHasher},
+    },
+    error::{ValidationError, ValidationErrors},
+};
+
+use crate::{
+    database::{IsolationLevel, RetryPolicy, UnitOfWork, DB},
+    throttle::LoginThrottle,
+    usecase::{error::UsecaseError, get_user_usecase::user_not_found_error},
+};
+
+#[derive(Clone, Debug)]
+pub struct ChangePasswordUsecase {
+    db: Arc<dyn DB>,
+    password_hasher: PasswordHasher,
+    login_throttle: LoginThrottle,
+    unit_of_work: UnitOfWork,
+}
+
+impl ChangePasswordUsecase {
+    pub fn new(
+        db: Arc<dyn DB>,
+        password_hasher: PasswordHasher,
+        login_throttle: LoginThrottle,
+    ) -> Self {
+        Self {
+            db,
+            password_hasher,
+            login_throttle,
+            unit_of_work: UnitOfWork::new(IsolationLevel::RepeatableRead, RetryPolicy::default()),
+        }
+    }
+
+    pub fn with_unit_of_work(self, unit_of_work: UnitOfWork) -> Self {
+        Self {
+            unit_of_work,
+            ..self
+        }
+    }
+
+    /// Failed checks of the current password count towards the same lockout as failed logins.
+    pub async fn execute(
+        &self,
+        user_id: &UserId,
+        current_password: String,
+        new_password: String,
+        ip: IpAddr,
+    ) -> Result<(), UsecaseError> {
+        let new_password =
+            Password::try_from(new_password).map_err(|new_password| UsecaseError::Expected {
+                message: "invalid password change",
+                errors: ValidationErrors::builder()
+                    .error(name_of!(new_password), new_password)
+                    .build(),
+            })?;
+        let password_field = name_of!(current_password);
+        let current_password_hash = verify_current_password(
+            self.db.as_ref(),
+            &self.login_throttle,
+            user_id,
+            &current_password,
+            ip,
+            password_field,
+        )
+        .await?;
+        let password_hash = new_password.to_hash(&self.password_hasher);
+
+        self.unit_of_work
+            .run(self.db.as_ref(), |tx| {
+                let user_id = user_id.clone();
+                let current_password_hash = current_password_hash.clone();
+                let password_hash = password_hash.clone();
+                Box::pin(async move {
+                    let user_credential_repository = tx.user_credential_repository();
+                    let mut user_credential = user_credential_repository
+                        .find(&user_id)
+                        .await?
+                        .ok_or_else(user_not_found_error)?;
+
+                    // The password may have been changed since it was verified.
+                    if user_credential.password_hash() != &current_password_hash {
+                        return Err(wrong_password_error(password_field));
+                    }
+
+                    user_credential.set_password_hash(password_hash);
+                    user_credential_repository.update(&user_credential).await?;
+
+                    Ok(())
+                })
+            })
+            .await
+    }
+}
+
+/// Checks the user's password outside of any transaction, as argon2 is deliberately slow, and
+/// returns the hash it matched, to be compared with the stored one inside the transaction.
+pub(crate) async fn verify_current_password(
+    db: &dyn DB,
+    login_throttle: &LoginThrottle,
+    user_id: &UserId,
+    password: &str,
+    ip: IpAddr,
+    field: &'static str,
+) -> Result<PasswordHash, UsecaseError> {
+    let user_credential = db
+        .user_credential_repository()
+        .find(user_id)
+        .await?
+        .ok_or_else(user_not_found_error)?;
+    let (_, email, password_hash, _) = user_credential.into_inner();
+
+    if let Some(retry_after) = login_throttle.check(email.as_str(), ip).await? {
+        return Err(UsecaseError::TooManyRequests { retry_after });
+    }
+
+    let verified = {
+        let password_hash = password_hash.clone();
+        let password = password.to_owned();
+        tokio::task::spawn_blocking(move || password_hash.verify(&password))
+            .await
+            .map_err(anyhow::Error::from)?
+    };
+    if !verified {
+        login_throttle.record_failure(email.as_str(), ip).await?;
+        return Err(wrong_password_error(field));
+    }
+    login_throttle.record_success(email.as_str()).await?;
+
+    Ok(password_hash)
+}
+
+pub(crate) fn wrong_password_error(field: &'static str) -> UsecaseError {
+    UsecaseError::Expected {
+        message: "wrong password",
+        errors: ValidationErrors::builder()
+            .error(field, ValidationError::Invalid)
+            .build(),
+    }
+}
@@ -0,0 +1,53 @@
+use std::sync::Arc;
+
+use nameof::name_of;
+use todo_app_domain::{
+    aggregate_root::{
+        tag::{
+            entity::Tag,
+            value_object::{TagColor, TagName},
+        },
+        user::value_object::UserId,
+    },
+    error::ValidationErrors,
+};
+
+use crate::{database::DB, usecase::error::UsecaseError};
+
+#[derive(Clone, Debug)]
+pub struct CreateTagUsecase {
+    db: Arc<dyn DB>,
+}
+
+impl CreateTagUsecase {
+    pub fn new(db: Arc<dyn DB>) -> Self {
+        Self { db }
+    }
+
+    pub async fn execute(
+        &self,
+        user_id: UserId,
+        name: String,
+        color: String,
+    ) -> Result<Tag, UsecaseError> {
+        let name = TagName::try_from(name);
+        let color = TagColor::try_from(color);
+        let (name, color) = match (name, color) {
+            (Ok(name), Ok(color)) => (name, color),
+            (name, color) => {
+                return Err(UsecaseError::Expected {
+                    message: "invalid tag",
+                    errors: ValidationErrors::builder()
+                        .result(name_of!(name), name)
+                        .result(name_of!(color), color)
+                        .build(),
+                });
+            }
+        };
+
+        let tag = Tag::new(user_id, name, color);
+        self.db.tag_repository().insert(&tag).await?;
+
+        Ok(tag)
+    }
+}
@@ -0,0 +1,82 @@
+use std::sync::Arc;
+
+use nameof::name_of;
+use todo_app_domain::{
+    aggregate_root::{
+        todo_list::{entity::TodoList, value_object::TodoListName},
+        user::value_object::UserId,
+    },
+    error::{ValidationError, ValidationErrors},
+};
+
+use crate::{
+    database::{IsolationLevel, RetryPolicy, UnitOfWork, DB},
+    usecase::error::UsecaseError,
+};
+
+#[derive(Clone, Debug)]
+pub struct CreateTodoListUsecase {
+    db: Arc<dyn DB>,
+    unit_of_work: UnitOfWork,
+}
+
+impl CreateTodoListUsecase {
+    pub fn new(db: Arc<dyn DB>) -> Self {
+        Self {
+            db,
+            // Concurrent creates only insert, so only a serializable transaction notices that
+            // they took the same position.
+            unit_of_work: UnitOfWork::new(IsolationLevel::Serializable, RetryPolicy::default()),
+        }
+    }
+
+    pub fn with_unit_of_work(self, unit_of_work: UnitOfWork) -> Self {
+        Self {
+            unit_of_work,
+            ..self
+        }
+    }
+
+    /// Creates a list after the user's other lists.
+    pub async fn execute(&self, user_id: UserId, name: String) -> Result<TodoList, UsecaseError> {
+        let name = TodoListName::try_from(name).map_err(|name| UsecaseError::Expected {
+            message: "invalid todo list",
+            errors: ValidationErrors::builder()
+                .error(name_of!(name), name)
+                .build(),
+        })?;
+
+        self.unit_of_work
+            .run(self.db.as_ref(), |tx| {
+                let user_id = user_id.clone();
+                let name = name.clone();
+                Box::pin(async move {
+                    let todo_list_repository = tx.todo_list_repository();
+                    let position = match todo_list_repository
+                        .find_by_user_id(&user_id)
+                        .await?
+                        .iter()
+                        .map(|todo_list| todo_list.position())
+                        .max()
+                    {
+                        Some(position) => position
+                            .checked_add(1)
+                            .filter(|position| *position <= TodoList::MAX_POSITION)
+                            .ok_or_else(|| UsecaseError::Expected {
+                                message: "no position left for a new todo list",
+                                errors: ValidationErrors::builder()
+                                    .error("position", ValidationError::Invalid)
+                                    .build(),
+                            })?,
+                        None => 0,
+                    };
+
+                    let todo_list = TodoList::new(user_id, name, position);
+                    todo_list_repository.insert(&todo_list).await?;
+
+                    Ok(todo_list)
+                })
+            })
+            .await
+    }
+}
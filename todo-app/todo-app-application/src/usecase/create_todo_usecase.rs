@@ -0,0 +1,106 @@
+use std::sync::Arc;
+
+use chrono::{NaiveDate, Utc};
+use nameof::name_of;
+use todo_app_domain::{
+    aggregate_root::{
+        todo::{
+            entity::Todo,
+            value_object::{TodoDescription, TodoTitle},
+        },
+        todo_list::value_object::TodoListId,
+        user::value_object::UserId,
+    },
+    error::{ValidationError, ValidationErrors},
+};
+
+use crate::{
+    database::DB,
+    usecase::{
+        error::UsecaseError, get_todo_list_usecase::todo_list_not_found_error,
+        get_user_usecase::user_not_found_error, login_usecase::email_not_verified_error,
+        move_todo_usecase::find_open_todo_list, EmailVerificationPolicy,
+    },
+};
+
+#[derive(Clone, Debug)]
+pub struct CreateTodoUsecase {
+    db: Arc<dyn DB>,
+    email_verification_policy: EmailVerificationPolicy,
+}
+
+impl CreateTodoUsecase {
+    pub fn new(db: Arc<dyn DB>, email_verification_policy: EmailVerificationPolicy) -> Self {
+        Self {
+            db,
+            email_verification_policy,
+        }
+    }
+
+    pub async fn execute(
+        &self,
+        user_id: UserId,
+        title: String,
+        description: Option<String>,
+        due_date: Option<String>,
+        todo_list_id: Option<TodoListId>,
+    ) -> Result<Todo, UsecaseError> {
+        let title = TodoTitle::try_from(title);
+        let description = description.map(TodoDescription::try_from).transpose();
+        let due_date = due_date.map(parse_due_date).transpose();
+        let (title, description, due_date) = match (title, description, due_date) {
+            (Ok(title), Ok(description), Ok(due_date)) => (title, description, due_date),
+            (title, description, due_date) => {
+                return Err(UsecaseError::Expected {
+                    message: "invalid todo",
+                    errors: ValidationErrors::builder()
+                        .result(name_of!(title), title)
+                        .result(name_of!(description), description)
+                        .result(name_of!(due_date), due_date)
+                        .build(),
+                });
+            }
+        };
+
+        if self.email_verification_policy.required_for_todo_creation() {
+            let user_credential = self
+                .db
+                .user_credential_repository()
+                .find(&user_id)
+                .await?
+                .ok_or_else(user_not_found_error)?;
+            if !user_credential.is_email_verified() {
+                return Err(email_not_verified_error());
+            }
+        }
+
+        let todo_list_repository = self.db.todo_list_repository();
+        let todo_list = match todo_list_id {
+            Some(todo_list_id) => {
+                find_open_todo_list(todo_list_repository.as_ref(), &user_id, &todo_list_id).await?
+            }
+            None => todo_list_repository
+                .find_inbox(&user_id)
+                .await?
+                .ok_or_else(todo_list_not_found_error)?,
+        };
+
+        let (todo_list_id, _, _, _, _, _) = todo_list.into_inner();
+        let todo = Todo::new(
+            user_id,
+            todo_list_id,
+            title,
+            description,
+            due_date,
+            Utc::now(),
+        );
+        self.db.todo_repository().insert(&todo).await?;
+
+        Ok(todo)
+    }
+}
+
+/// Parses a date in the `YYYY-MM-DD` format.
+pub(crate) fn parse_due_date(value: String) -> Result<NaiveDate, ValidationError> {
+    NaiveDate::parse_from_str(&value, "%Y-%m-%d").map_err(|_| ValidationError::Invalid)
+}
@@ -0,0 +1,52 @@
+use std::sync::Arc;
+
+use todo_app_domain::aggregate_root::{tag::value_object::TagId, user::value_object::UserId};
+
+use crate::{
+    database::{UnitOfWork, DB},
+    usecase::{error::UsecaseError, get_tag_usecase::tag_not_found_error},
+};
+
+#[derive(Clone, Debug)]
+pub struct DeleteTagUsecase {
+    db: Arc<dyn DB>,
+    unit_of_work: UnitOfWork,
+}
+
+impl DeleteTagUsecase {
+    pub fn new(db: Arc<dyn DB>) -> Self {
+        Self {
+            db,
+            unit_of_work: UnitOfWork::default(),
+        }
+    }
+
+    pub fn with_unit_of_work(self, unit_of_work: UnitOfWork) -> Self {
+        Self {
+            unit_of_work,
+            ..self
+        }
+    }
+
+    /// Deletes the tag and removes it from the todos it is assigned to.
+    pub async fn execute(&self, user_id: &UserId, tag_id: &TagId) -> Result<(), UsecaseError> {
+        self.unit_of_work
+            .run(self.db.as_ref(), |tx| {
+                let user_id = user_id.clone();
+                let tag_id = tag_id.clone();
+                Box::pin(async move {
+                    let tag_repository = tx.tag_repository();
+                    tag_repository
+                        .find(&tag_id)
+                        .await?
+                        .filter(|tag| tag.user_id() == &user_id)
+                        .ok_or_else(tag_not_found_error)?;
+
+                    tag_repository.delete(&tag_id).await?;
+
+                    Ok(())
+                })
+            })
+            .await
+    }
+}
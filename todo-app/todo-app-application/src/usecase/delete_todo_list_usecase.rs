@@ -0,0 +1,120 @@
+use std::{str::FromStr, sync::Arc};
+
+use chrono::Utc;
+use nameof::name_of;
+use todo_app_domain::{
+    aggregate_root::{todo_list::value_object::TodoListId, user::value_object::UserId},
+    error::{ValidationError, ValidationErrors},
+};
+
+use crate::{
+    database::{IsolationLevel, RetryPolicy, UnitOfWork, DB},
+    usecase::{error::UsecaseError, get_todo_list_usecase::todo_list_not_found_error},
+};
+
+/// What happens to the todos of a deleted list.
+#[derive(Clone, Copy, Debug, Eq, PartialEq)]
+enum TodoListDeletion {
+    /// The todos are deleted with the list.
+    Cascade,
+    /// The todos are moved to the user's inbox.
+    MoveToInbox,
+}
+
+impl FromStr for TodoListDeletion {
+    type Err = ValidationError;
+
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        match s {
+            "cascade" => Ok(Self::Cascade),
+            "move_to_inbox" => Ok(Self::MoveToInbox),
+            _ => Err(ValidationError::Invalid),
+        }
+    }
+}
+
+#[derive(Clone, Debug)]
+pub struct DeleteTodoListUsecase {
+    db: Arc<dyn DB>,
+    unit_of_work: UnitOfWork,
+}
+
+impl DeleteTodoListUsecase {
+    pub fn new(db: Arc<dyn DB>) -> Self {
+        Self {
+            db,
+            unit_of_work: UnitOfWork::new(IsolationLevel::RepeatableRead, RetryPolicy::default()),
+        }
+    }
+
+    pub fn with_unit_of_work(self, unit_of_work: UnitOfWork) -> Self {
+        Self {
+            unit_of_work,
+            ..self
+        }
+    }
+
+    /// Deletes the list, and its todos (`cascade`) or moves them to the inbox (`move_to_inbox`);
+    /// the inbox itself cannot be deleted.
+    pub async fn execute(
+        &self,
+        user_id: &UserId,
+        todo_list_id: &TodoListId,
+        todos: Option<String>,
+    ) -> Result<(), UsecaseError> {
+        let todos = todos
+            .ok_or(ValidationError::Required)
+            .and_then(|todos| todos.parse::<TodoListDeletion>())
+            .map_err(|todos| UsecaseError::Expected {
+                message: "invalid todo list deletion",
+                errors: ValidationErrors::builder()
+                    .error(name_of!(todos), todos)
+                    .build(),
+            })?;
+
+        self.unit_of_work
+            .run(self.db.as_ref(), |tx| {
+                let user_id = user_id.clone();
+                let todo_list_id = todo_list_id.clone();
+                Box::pin(async move {
+                    let todo_list_repository = tx.todo_list_repository();
+                    let todo_list = todo_list_repository
+                        .find(&todo_list_id)
+                        .await?
+                        .filter(|todo_list| todo_list.user_id() == &user_id)
+                        .ok_or_else(todo_list_not_found_error)?;
+                    if todo_list.inbox() {
+                        return Err(UsecaseError::Expected {
+                            message: "the inbox cannot be deleted",
+                            errors: ValidationErrors::builder()
+                                .error("todo_list_id", ValidationError::Invalid)
+                                .build(),
+                        });
+                    }
+
+                    let todo_repository = tx.todo_repository();
+                    match todos {
+                        TodoListDeletion::Cascade => {
+                            todo_repository
+                                .delete_by_todo_list_id(&todo_list_id)
+                                .await?;
+                        }
+                        TodoListDeletion::MoveToInbox => {
+                            let inbox = todo_list_repository
+                                .find_inbox(&user_id)
+                                .await?
+                                .ok_or_else(todo_list_not_found_error)?;
+                            todo_repository
+                                .move_by_todo_list_id(&todo_list_id, inbox.id(), Utc::now())
+                                .await?;
+                        }
+                    }
+
+                    todo_list_repository.delete(&todo_list_id).await?;
+
+                    Ok(())
+                })
+            })
+            .await
+    }
+}
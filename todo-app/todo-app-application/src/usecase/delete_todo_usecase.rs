@@ -0,0 +1,51 @@
+use std::sync::Arc;
+
+use todo_app_domain::aggregate_root::{todo::value_object::TodoId, user::value_object::UserId};
+
+use crate::{
+    database::{UnitOfWork, DB},
+    usecase::{error::UsecaseError, get_todo_usecase::todo_not_found_error},
+};
+
+#[derive(Clone, Debug)]
+pub struct DeleteTodoUsecase {
+    db: Arc<dyn DB>,
+    unit_of_work: UnitOfWork,
+}
+
+impl DeleteTodoUsecase {
+    pub fn new(db: Arc<dyn DB>) -> Self {
+        Self {
+            db,
+            unit_of_work: UnitOfWork::default(),
+        }
+    }
+
+    pub fn with_unit_of_work(self, unit_of_work: UnitOfWork) -> Self {
+        Self {
+            unit_of_work,
+            ..self
+        }
+    }
+
+    pub async fn execute(&self, user_id: &UserId, todo_id: &TodoId) -> Result<(), UsecaseError> {
+        self.unit_of_work
+            .run(self.db.as_ref(), |tx| {
+                let user_id = user_id.clone();
+                let todo_id = todo_id.clone();
+                Box::pin(async move {
+                    let todo_repository = tx.todo_repository();
+                    todo_repository
+                        .find(&todo_id)
+                        .await?
+                        .filter(|todo| todo.user_id() == &user_id)
+                        .ok_or_else(todo_not_found_error)?;
+
+                    todo_repository.delete(&todo_id).await?;
+
+                    Ok(())
+                })
+            })
+            .await
+    }
+}
@@ -0,0 +1,56 @@
+use std::sync::Arc;
+
+use todo_app_domain::aggregate_root::user::value_object::UserId;
+
+use crate::{
+    database::{UnitOfWork, DB},
+    usecase::error::UsecaseError,
+};
+
+#[derive(Clone, Debug)]
+pub struct DeleteUserUsecase {
+    db: Arc<dyn DB>,
+    unit_of_work: UnitOfWork,
+}
+
+impl DeleteUserUsecase {
+    pub fn new(db: Arc<dyn DB>) -> Self {
+        Self {
+            db,
+            unit_of_work: UnitOfWork::default(),
+        }
+    }
+
+    pub fn with_unit_of_work(self, unit_of_work: UnitOfWork) -> Self {
+        Self {
+            unit_of_work,
+            ..self
+        }
+    }
+
+    /// Deletes the user together with everything that references them.
+    pub async fn execute(&self, user_id: &UserId) -> Result<(), UsecaseError> {
+        self.unit_of_work
+            .run(self.db.as_ref(), |tx| {
+                let user_id = user_id.clone();
+                Box::pin(async move {
+                    tx.todo_repository().delete_by_user_id(&user_id).await?;
+                    tx.todo_list_repository()
+                        .delete_by_user_id(&user_id)
+                        .await?;
+                    tx.tag_repository().delete_by_user_id(&user_id).await?;
+                    tx.password_reset_token_repository()
+                        .delete_by_user_id(&user_id)
+                        .await?;
+                    tx.email_verification_token_repository()
+                        .delete_by_user_id(&user_id)
+                        .await?;
+                    tx.user_credential_repository().delete(&user_id).await?;
+                    tx.user_repository().delete(&user_id).await?;
+
+                    Ok(())
+                })
+            })
+            .await
+    }
+}
@@ -0,0 +1,33 @@
+use chrono::Duration;
+use getset::CopyGetters;
+
+/// How email verification tokens are issued, and what an unverified user may not do.
+#[derive(Clone, Copy, CopyGetters, Debug, PartialEq, Eq)]
+pub struct EmailVerificationPolicy {
+    #[getset(get_copy = "pub")]
+    token_lifetime: Duration,
+    #[getset(get_copy = "pub")]
+    required_for_login: bool,
+    #[getset(get_copy = "pub")]
+    required_for_todo_creation: bool,
+}
+
+impl EmailVerificationPolicy {
+    pub fn new(
+        token_lifetime: Duration,
+        required_for_login: bool,
+        required_for_todo_creation: bool,
+    ) -> Self {
+        Self {
+            token_lifetime,
+            required_for_login,
+            required_for_todo_creation,
+        }
+    }
+}
+
+impl Default for EmailVerificationPolicy {
+    fn default() -> Self {
+        Self::new(Duration::days(1), false, false)
+    }
+}
@@ -1,5 +1,8 @@
+use chrono::Duration;
 use thiserror::Error;
-use todo_app_domain::error::ValidationErrors;
+use todo_app_domain::error::{DomainError, RepositoryError, ValidationErrors};
+
+use crate::database::RetryableError;
 
 #[derive(Debug, Error)]
 pub enum UsecaseError {
@@ -8,6 +11,36 @@ pub enum UsecaseError {
         message: &'static str,
         errors: ValidationErrors,
     },
+    #[error("UsecaseError::NotFound: {message}")]
+    NotFound { message: &'static str },
+    #[error("UsecaseError::Forbidden: {message}")]
+    Forbidden { message: &'static str },
+    #[error("UsecaseError::TooManyRequests: retry after {retry_after}")]
+    TooManyRequests { retry_after: Duration },
+    #[error("UsecaseError::Domain: {0}")]
+    Domain(#[from] DomainError),
+    /// Conflicts, concurrent modifications and storage outages reported by a repository.
+    #[error("UsecaseError::Repository: {0}")]
+    Repository(RepositoryError),
     #[error("UsecaseError::Unexpected: {0:?}")]
     Unexpected(#[from] anyhow::Error),
 }
+
+impl From<RepositoryError> for UsecaseError {
+    fn from(e: RepositoryError) -> Self {
+        match e {
+            RepositoryError::NotFound => Self::NotFound {
+                message: "not found",
+            },
+            RepositoryError::Domain(e) => Self::Domain(e),
+            RepositoryError::Unexpected(e) => Self::Unexpected(e),
+            e => Self::Repository(e),
+        }
+    }
+}
+
+impl RetryableError for UsecaseError {
+    fn is_retryable(&self) -> bool {
+        matches!(self, Self::Repository(e) if e.is_retryable())
+    }
+}
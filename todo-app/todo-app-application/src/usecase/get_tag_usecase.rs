@@ -0,0 +1,34 @@
+use std::sync::Arc;
+
+use todo_app_domain::aggregate_root::{
+    tag::{entity::Tag, value_object::TagId},
+    user::value_object::UserId,
+};
+
+use crate::{database::DB, usecase::error::UsecaseError};
+
+#[derive(Clone, Debug)]
+pub struct GetTagUsecase {
+    db: Arc<dyn DB>,
+}
+
+impl GetTagUsecase {
+    pub fn new(db: Arc<dyn DB>) -> Self {
+        Self { db }
+    }
+
+    pub async fn execute(&self, user_id: &UserId, tag_id: &TagId) -> Result<Tag, UsecaseError> {
+        self.db
+            .tag_repository()
+            .find(tag_id)
+            .await?
+            .filter(|tag| tag.user_id() == user_id)
+            .ok_or_else(tag_not_found_error)
+    }
+}
+
+pub(crate) fn tag_not_found_error() -> UsecaseError {
+    UsecaseError::NotFound {
+        message: "tag not found",
+    }
+}
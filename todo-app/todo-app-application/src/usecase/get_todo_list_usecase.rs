@@ -0,0 +1,38 @@
+use std::sync::Arc;
+
+use todo_app_domain::aggregate_root::{
+    todo_list::{entity::TodoList, value_object::TodoListId},
+    user::value_object::UserId,
+};
+
+use crate::{database::DB, usecase::error::UsecaseError};
+
+#[derive(Clone, Debug)]
+pub struct GetTodoListUsecase {
+    db: Arc<dyn DB>,
+}
+
+impl GetTodoListUsecase {
+    pub fn new(db: Arc<dyn DB>) -> Self {
+        Self { db }
+    }
+
+    pub async fn execute(
+        &self,
+        user_id: &UserId,
+        todo_list_id: &TodoListId,
+    ) -> Result<TodoList, UsecaseError> {
+        self.db
+            .todo_list_repository()
+            .find(todo_list_id)
+            .await?
+            .filter(|todo_list| todo_list.user_id() == user_id)
+            .ok_or_else(todo_list_not_found_error)
+    }
+}
+
+pub(crate) fn todo_list_not_found_error() -> UsecaseError {
+    UsecaseError::NotFound {
+        message: "todo list not found",
+    }
+}
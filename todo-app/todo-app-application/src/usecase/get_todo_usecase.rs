@@ -0,0 +1,34 @@
+use std::sync::Arc;
+
+use todo_app_domain::aggregate_root::{
+    todo::{entity::Todo, value_object::TodoId},
+    user::value_object::UserId,
+};
+
+use crate::{database::DB, usecase::error::UsecaseError};
+
+#[derive(Clone, Debug)]
+pub struct GetTodoUsecase {
+    db: Arc<dyn DB>,
+}
+
+impl GetTodoUsecase {
+    pub fn new(db: Arc<dyn DB>) -> Self {
+        Self { db }
+    }
+
+    pub async fn execute(&self, user_id: &UserId, todo_id: &TodoId) -> Result<Todo, UsecaseError> {
+        self.db
+            .todo_repository()
+            .find(todo_id)
+            .await?
+            .filter(|todo| todo.user_id() == user_id)
+            .ok_or_else(todo_not_found_error)
+    }
+}
+
+pub(crate) fn todo_not_found_error() -> UsecaseError {
+    UsecaseError::NotFound {
+        message: "todo not found",
+    }
+}
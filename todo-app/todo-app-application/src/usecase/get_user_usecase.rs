@@ -0,0 +1,42 @@
+use std::sync::Arc;
+
+use todo_app_domain::aggregate_root::{
+    user::{entity::User, value_object::UserId},
+    user_credential::entity::UserCredential,
+};
+
+use crate::{database::DB, usecase::error::UsecaseError};
+
+#[derive(Clone, Debug)]
+pub struct GetUserUsecase {
+    db: Arc<dyn DB>,
+}
+
+impl GetUserUsecase {
+    pub fn new(db: Arc<dyn DB>) -> Self {
+        Self { db }
+    }
+
+    pub async fn execute(&self, user_id: &UserId) -> Result<(User, UserCredential), UsecaseError> {
+        let user = self
+            .db
+            .user_repository()
+            .find(user_id)
+            .await?
+            .ok_or_else(user_not_found_error)?;
+        let user_credential = self
+            .db
+            .user_credential_repository()
+            .find(user_id)
+            .await?
+            .ok_or_else(user_not_found_error)?;
+
+        Ok((user, user_credential))
+    }
+}
+
+pub(crate) fn user_not_found_error() -> UsecaseError {
+    UsecaseError::NotFound {
+        message: "user not found",
+    }
+}
@@ -0,0 +1,22 @@
+use std::sync::Arc;
+
+use todo_app_domain::aggregate_root::{tag::entity::Tag, user::value_object::UserId};
+
+use crate::{database::DB, usecase::error::UsecaseError};
+
+#[derive(Clone, Debug)]
+pub struct ListTagsUsecase {
+    db: Arc<dyn DB>,
+}
+
+impl ListTagsUsecase {
+    pub fn new(db: Arc<dyn DB>) -> Self {
+        Self { db }
+    }
+
+    pub async fn execute(&self, user_id: &UserId) -> Result<Vec<Tag>, UsecaseError> {
+        let tags = self.db.tag_repository().find_by_user_id(user_id).await?;
+
+        Ok(tags)
+    }
+}
@@ -0,0 +1,26 @@
+use std::sync::Arc;
+
+use todo_app_domain::aggregate_root::{todo_list::entity::TodoList, user::value_object::UserId};
+
+use crate::{database::DB, usecase::error::UsecaseError};
+
+#[derive(Clone, Debug)]
+pub struct ListTodoListsUsecase {
+    db: Arc<dyn DB>,
+}
+
+impl ListTodoListsUsecase {
+    pub fn new(db: Arc<dyn DB>) -> Self {
+        Self { db }
+    }
+
+    pub async fn execute(&self, user_id: &UserId) -> Result<Vec<TodoList>, UsecaseError> {
+        let todo_lists = self
+            .db
+            .todo_list_repository()
+            .find_by_user_id(user_id)
+            .await?;
+
+        Ok(todo_lists)
+    }
+}
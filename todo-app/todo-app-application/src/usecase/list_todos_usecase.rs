@@ -0,0 +1,145 @@
+use std::sync::Arc;
+
+use nameof::name_of;
+use todo_app_domain::{
+    aggregate_root::{
+        tag::value_object::TagId,
+        todo::repository::{
+            SortDirection, TodoCursor, TodoPage, TodoQuery, TodoSort, TodoSortKey, TodoStatus,
+        },
+        todo_list::value_object::TodoListId,
+        user::value_object::UserId,
+    },
+    error::{ValidationError, ValidationErrors},
+};
+use uuid::Uuid;
+
+use crate::{
+    database::DB,
+    usecase::{create_todo_usecase::parse_due_date, error::UsecaseError},
+};
+
+/// Filters, sort and paging of a todo list, as given by the client.
+#[derive(Clone, Debug, Default, PartialEq, Eq)]
+pub struct ListTodosQuery {
+    /// ID of the todo list the todos must belong to.
+    pub list: Option<String>,
+    pub status: Option<String>,
+    pub due_before: Option<String>,
+    pub due_after: Option<String>,
+    pub contains: Option<String>,
+    /// Comma-separated tag IDs, all of which a todo must have.
+    pub tags: Option<String>,
+    pub sort: Option<String>,
+    pub order: Option<String>,
+    pub cursor: Option<String>,
+    pub limit: Option<String>,
+}
+
+#[derive(Clone, Debug)]
+pub struct ListTodosUsecase {
+    db: Arc<dyn DB>,
+}
+
+impl ListTodosUsecase {
+    pub fn new(db: Arc<dyn DB>) -> Self {
+        Self { db }
+    }
+
+    pub async fn execute(
+        &self,
+        user_id: &UserId,
+        query: ListTodosQuery,
+    ) -> Result<TodoPage, UsecaseError> {
+        let query = todo_query(user_id, query).map_err(|errors| UsecaseError::Expected {
+            message: "invalid todo query",
+            errors,
+        })?;
+
+        let page = self.db.todo_repository().find_page(&query).await?;
+
+        Ok(page)
+    }
+}
+
+fn todo_query(user_id: &UserId, query: ListTodosQuery) -> Result<TodoQuery, ValidationErrors> {
+    let list = query.list.map(parse_todo_list_id).transpose();
+    let status = query.status.map(|s| s.parse::<TodoStatus>()).transpose();
+    let due_before = query.due_before.map(parse_due_date).transpose();
+    let due_after = query.due_after.map(parse_due_date).transpose();
+    let tags = query.tags.map(parse_tag_ids).transpose();
+    let sort = query.sort.map(|s| s.parse::<TodoSortKey>()).transpose();
+    let order = query.order.map(|s| s.parse::<SortDirection>()).transpose();
+    let cursor = query.cursor.map(|s| TodoCursor::decode(&s)).transpose();
+    let limit = query
+        .limit
+        .map(|s| s.parse::<u32>().map_err(|_| ValidationError::Invalid))
+        .transpose();
+    let (list, status, due_before, due_after, tags, sort, order, cursor, limit) = match (
+        list, status, due_before, due_after, tags, sort, order, cursor, limit,
+    ) {
+        (
+            Ok(list),
+            Ok(status),
+            Ok(due_before),
+            Ok(due_after),
+            Ok(tags),
+            Ok(sort),
+            Ok(order),
+            Ok(cursor),
+            Ok(limit),
+        ) => (
+            list, status, due_before, due_after, tags, sort, order, cursor, limit,
+        ),
+        (list, status, due_before, due_after, tags, sort, order, cursor, limit) => {
+            return ValidationErrors::builder()
+                .result(name_of!(list), list)
+                .result(name_of!(status), status)
+                .result(name_of!(due_before), due_before)
+                .result(name_of!(due_after), due_after)
+                .result(name_of!(tags), tags)
+                .result(name_of!(sort), sort)
+                .result(name_of!(order), order)
+                .result(name_of!(cursor), cursor)
+                .result(name_of!(limit), limit)
+                .build()
+                .into();
+        }
+    };
+
+    let sort = TodoSort::new(sort.unwrap_or_default(), order.unwrap_or_default());
+    let query = TodoQuery::new(user_id.clone())
+        .with_todo_list_id(list)
+        .with_status(status)
+        .with_due_before(due_before)
+        .with_due_after(due_after)
+        .with_text(query.contains)
+        .with_tag_ids(tags.unwrap_or_default())
+        .with_sort(sort);
+    let query = query
+        .with_cursor(cursor)
+        .map_err(|error| ValidationErrors::builder().error("cursor", error).build())?;
+    match limit {
+        Some(limit) => query
+            .with_limit(limit)
+            .map_err(|error| ValidationErrors::builder().error("limit", error).build()),
+        None => Ok(query),
+    }
+}
+
+fn parse_todo_list_id(value: String) -> Result<TodoListId, ValidationError> {
+    Uuid::parse_str(&value)
+        .map(TodoListId::from)
+        .map_err(|_| ValidationError::Invalid)
+}
+
+fn parse_tag_ids(value: String) -> Result<Vec<TagId>, ValidationError> {
+    value
+        .split(',')
+        .map(|id| {
+            Uuid::parse_str(id)
+                .map(TagId::from)
+                .map_err(|_| ValidationError::Invalid)
+        })
+        .collect()
+}
@@ -1,32 +1,88 @@
-use std::sync::Arc;
+use std::{net::IpAddr, sync::Arc};
 
-use todo_app_domain::aggregate_root::user::value_object::UserId;
+use todo_app_domain::aggregate_root::{
+    user::value_object::UserId, user_credential::value_object::PasswordHasher,
+};
 
-use crate::{database::DB, usecase::error::UsecaseError};
+use crate::{
+    database::DB,
+    throttle::LoginThrottle,
+    usecase::{
+        error::UsecaseError, Argon2PasswordVerifier, EmailVerificationPolicy, PasswordVerifier,
+    },
+};
 
 #[derive(Clone, Debug)]
 pub struct LoginUsecase {
     db: Arc<dyn DB>,
+    password_hasher: PasswordHasher,
+    email_verification_policy: EmailVerificationPolicy,
+    login_throttle: LoginThrottle,
+    password_verifier: Arc<dyn PasswordVerifier>,
 }
 
 impl LoginUsecase {
-    pub fn new(db: Arc<dyn DB>) -> Self {
-        Self { db }
+    pub fn new(
+        db: Arc<dyn DB>,
+        password_hasher: PasswordHasher,
+        email_verification_policy: EmailVerificationPolicy,
+        login_throttle: LoginThrottle,
+    ) -> Self {
+        Self {
+            db,
+            password_hasher,
+            email_verification_policy,
+            login_throttle,
+            password_verifier: Arc::new(Argon2PasswordVerifier),
+        }
+    }
+
+    pub fn with_password_verifier(self, password_verifier: Arc<dyn PasswordVerifier>) -> Self {
+        Self {
+            password_verifier,
+            ..self
+        }
     }
 
-    pub async fn execute(&self, email: &str, password: &str) -> Result<UserId, UsecaseError> {
+    /// Checks the password, unless the account or the client's IP address is locked out.
+    pub async fn execute(
+        &self,
+        email: &str,
+        password: &str,
+        ip: IpAddr,
+    ) -> Result<UserId, UsecaseError> {
+        if let Some(retry_after) = self.login_throttle.check(email, ip).await? {
+            return Err(UsecaseError::TooManyRequests { retry_after });
+        }
+
         let user_credential = self
             .db
             .user_credential_repository()
             .find_by_email(email)
-            .await?
-            .ok_or(login_failed_error())?;
+            .await?;
+        // Unknown addresses are verified against a dummy hash, so that the response time does
+        // not tell whether an address is registered.
+        let password_hash = match &user_credential {
+            Some(user_credential) => user_credential.password_hash(),
+            None => self.password_hasher.dummy_hash(),
+        };
+        let verified = self.password_verifier.verify(password_hash, password);
+        let user_credential = match user_credential.filter(|_| verified) {
+            Some(user_credential) => user_credential,
+            None => {
+                self.login_throttle.record_failure(email, ip).await?;
+                return Err(login_failed_error());
+            }
+        };
+        self.login_throttle.record_success(email).await?;
 
-        if !user_credential.password_hash().verify(password) {
-            return Err(login_failed_error());
+        if self.email_verification_policy.required_for_login()
+            && !user_credential.is_email_verified()
+        {
+            return Err(email_not_verified_error());
         }
 
-        let (user_id, _, _) = user_credential.into_inner();
+        let (user_id, _, _, _) = user_credential.into_inner();
 
         Ok(user_id)
     }
@@ -38,3 +94,96 @@ fn login_failed_error() -> UsecaseError {
         errors: Default::default(),
     }
 }
+
+pub(crate) fn email_not_verified_error() -> UsecaseError {
+    UsecaseError::Forbidden {
+        message: "email not verified",
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use std::net::Ipv4Addr;
+
+    use todo_app_domain::aggregate_root::user_credential::{
+        entity::UserCredential,
+        repository::MockUserCredentialRepository,
+        value_object::{Email, Password},
+    };
+
+    use super::*;
+    use crate::{
+        database::MockDB,
+        throttle::{LoginThrottlePolicy, MockLoginAttemptStore},
+        usecase::MockPasswordVerifier,
+    };
+
+    #[tokio::test]
+    async fn login_verifies_password_of_unknown_email() {
+        let password_hasher = PasswordHasher::default();
+        let password_hash = Password::try_from("password".to_owned())
+            .unwrap()
+            .to_hash(&password_hasher);
+        let user_credential = UserCredential::new(
+            UserId::new(),
+            Email::try_from("user@example.com".to_owned()).unwrap(),
+            password_hash.clone(),
+        );
+
+        let tests = vec![
+            ("user@example.com", password_hash),
+            ("unknown@example.com", password_hasher.dummy_hash().clone()),
+        ];
+
+        for (email, expected_hash) in tests {
+            let mut user_credential_repository = MockUserCredentialRepository::new();
+            let found = user_credential.clone();
+            user_credential_repository
+                .expect_find_by_email()
+                .returning(move |email| {
+                    let found = Some(found.clone()).filter(|found| found.email().as_str() == email);
+                    Box::pin(async move { Ok(found) })
+                });
+            let user_credential_repository = Arc::new(user_credential_repository);
+            let mut db = MockDB::new();
+            db.expect_user_credential_repository()
+                .returning(move || user_credential_repository.clone());
+
+            let mut login_attempt_store = MockLoginAttemptStore::new();
+            login_attempt_store
+                .expect_locked_until()
+                .returning(|_| Box::pin(async { Ok(None) }));
+            login_attempt_store
+                .expect_record_failure()
+                .returning(|_, _| Box::pin(async { Ok(1) }));
+
+            let mut password_verifier = MockPasswordVerifier::new();
+            password_verifier
+                .expect_verify()
+                .withf(move |password_hash, password| {
+                    *password_hash == expected_hash && password == "wrongpassword"
+                })
+                .times(1)
+                .return_const(false);
+
+            let login_usecase = LoginUsecase::new(
+                Arc::new(db),
+                password_hasher.clone(),
+                EmailVerificationPolicy::default(),
+                LoginThrottle::new(
+                    Arc::new(login_attempt_store),
+                    LoginThrottlePolicy::default(),
+                ),
+            )
+            .with_password_verifier(Arc::new(password_verifier));
+
+            let result = login_usecase
+                .execute(email, "wrongpassword", Ipv4Addr::LOCALHOST.into())
+                .await;
+            assert!(
+                matches!(result, Err(UsecaseError::Expected { .. })),
+                "email: {email}, result: {result:?}"
+            );
+        }
+    }
+}
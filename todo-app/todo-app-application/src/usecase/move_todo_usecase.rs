@@ -0,0 +1,104 @@
+use std::sync::Arc;
+
+use chrono::Utc;
+use todo_app_domain::{
+    aggregate_root::{
+        todo::{entity::Todo, value_object::TodoId},
+        todo_list::{entity::TodoList, repository::TodoListRepository, value_object::TodoListId},
+        user::value_object::UserId,
+    },
+    error::{ValidationError, ValidationErrors},
+};
+
+use crate::{
+    database::{IsolationLevel, RetryPolicy, UnitOfWork, DB},
+    usecase::{error::UsecaseError, get_todo_usecase::todo_not_found_error},
+};
+
+#[derive(Clone, Debug)]
+pub struct MoveTodoUsecase {
+    db: Arc<dyn DB>,
+    unit_of_work: UnitOfWork,
+}
+
+impl MoveTodoUsecase {
+    pub fn new(db: Arc<dyn DB>) -> Self {
+        Self {
+            db,
+            unit_of_work: UnitOfWork::new(IsolationLevel::RepeatableRead, RetryPolicy::default()),
+        }
+    }
+
+    pub fn with_unit_of_work(self, unit_of_work: UnitOfWork) -> Self {
+        Self {
+            unit_of_work,
+            ..self
+        }
+    }
+
+    /// Moves the todo to another of the user's lists, which must not be archived.
+    pub async fn execute(
+        &self,
+        user_id: &UserId,
+        todo_id: &TodoId,
+        todo_list_id: &TodoListId,
+    ) -> Result<Todo, UsecaseError> {
+        self.unit_of_work
+            .run(self.db.as_ref(), |tx| {
+                let user_id = user_id.clone();
+                let todo_id = todo_id.clone();
+                let todo_list_id = todo_list_id.clone();
+                Box::pin(async move {
+                    let todo_repository = tx.todo_repository();
+                    let mut todo = todo_repository
+                        .find(&todo_id)
+                        .await?
+                        .filter(|todo| todo.user_id() == &user_id)
+                        .ok_or_else(todo_not_found_error)?;
+
+                    let todo_list = find_open_todo_list(
+                        tx.todo_list_repository().as_ref(),
+                        &user_id,
+                        &todo_list_id,
+                    )
+                    .await?;
+
+                    if todo.todo_list_id() != todo_list.id() {
+                        todo.move_to(todo_list_id, Utc::now());
+                        todo_repository.update(&todo).await?;
+                    }
+
+                    Ok(todo)
+                })
+            })
+            .await
+    }
+}
+
+/// Finds a list of the user that todos may be added to.
+pub(crate) async fn find_open_todo_list(
+    todo_list_repository: &dyn TodoListRepository,
+    user_id: &UserId,
+    todo_list_id: &TodoListId,
+) -> Result<TodoList, UsecaseError> {
+    let todo_list = todo_list_repository
+        .find(todo_list_id)
+        .await?
+        .filter(|todo_list| todo_list.user_id() == user_id)
+        .ok_or_else(|| UsecaseError::Expected {
+            message: "unknown todo list",
+            errors: ValidationErrors::builder()
+                .error("todo_list_id", ValidationError::Invalid)
+                .build(),
+        })?;
+    if todo_list.archived() {
+        return Err(UsecaseError::Expected {
+            message: "todo list is archived",
+            errors: ValidationErrors::builder()
+                .error("todo_list_id", ValidationError::Invalid)
+                .build(),
+        });
+    }
+
+    Ok(todo_list)
+}
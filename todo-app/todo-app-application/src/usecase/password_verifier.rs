@@ -0,0 +1,16 @@
+use todo_app_domain::aggregate_root::user_credential::value_object::PasswordHash;
+
+/// Checks a password against its hash.
+#[cfg_attr(test, mockall::automock)]
+pub trait PasswordVerifier: std::fmt::Debug + Send + Sync {
+    fn verify(&self, password_hash: &PasswordHash, password: &str) -> bool;
+}
+
+#[derive(Clone, Copy, Debug, Default)]
+pub struct Argon2PasswordVerifier;
+
+impl PasswordVerifier for Argon2PasswordVerifier {
+    fn verify(&self, password_hash: &PasswordHash, password: &str) -> bool {
+        password_hash.verify(password)
+    }
+}
@@ -0,0 +1,182 @@
+use std::sync::Arc;
+
+use chrono::{Duration, Utc};
+use todo_app_domain::aggregate_root::{
+    password_reset_token::entity::PasswordResetToken, user_credential::value_object::Email,
+};
+use tokio::sync::Semaphore;
+
+use crate::{
+    database::{UnitOfWork, DB},
+    mail::{MailMessage, Mailer},
+    usecase::error::UsecaseError,
+};
+
+/// Requests handled in the background at a time; further ones are dropped until one finishes.
+const MAX_PENDING_REQUESTS: usize = 32;
+
+#[derive(Clone, Debug)]
+pub struct RequestPasswordResetUsecase {
+    db: Arc<dyn DB>,
+    mailer: Arc<dyn Mailer>,
+    token_lifetime: Duration,
+    unit_of_work: UnitOfWork,
+    pending_requests: Arc<Semaphore>,
+}
+
+impl RequestPasswordResetUsecase {
+    pub fn new(db: Arc<dyn DB>, mailer: Arc<dyn Mailer>, token_lifetime: Duration) -> Self {
+        Self {
+            db,
+            mailer,
+            token_lifetime,
+            unit_of_work: UnitOfWork::default(),
+            pending_requests: Arc::new(Semaphore::new(MAX_PENDING_REQUESTS)),
+        }
+    }
+
+    pub fn with_unit_of_work(self, unit_of_work: UnitOfWork) -> Self {
+        Self {
+            unit_of_work,
+            ..self
+        }
+    }
+
+    pub fn with_max_pending_requests(self, max_pending_requests: usize) -> Self {
+        Self {
+            pending_requests: Arc::new(Semaphore::new(max_pending_requests)),
+            ..self
+        }
+    }
+
+    /// Mails a password reset token to the owner of the email address, if any.
+    ///
+    /// The work is done in the background and its failures are only logged, so that neither
+    /// the outcome nor the response time tells callers which addresses are registered. Requests
+    /// beyond the limit of pending ones are dropped, so that they cannot pile up work.
+    pub fn execute(&self, email: String) {
+        let permit = match self.pending_requests.clone().try_acquire_owned() {
+            Ok(permit) => permit,
+            Err(_) => {
+                tracing::warn!("too many pending password reset requests, dropping one");
+                return;
+            }
+        };
+
+        let usecase = self.clone();
+        tokio::spawn(async move {
+            if let Err(e) = usecase.request(email).await {
+                tracing::error!("failed to request password reset: {e:?}");
+            }
+            drop(permit);
+        });
+    }
+
+    async fn request(&self, email: String) -> Result<(), UsecaseError> {
+        let email = match Email::try_from(email) {
+            Ok(email) => email,
+            Err(_) => return Ok(()),
+        };
+
+        let user_credential = match self
+            .db
+            .user_credential_repository()
+            .find_by_email(email.as_str())
+            .await?
+        {
+            Some(user_credential) => user_credential,
+            None => return Ok(()),
+        };
+
+        let (password_reset_token, token) = PasswordResetToken::issue(
+            user_credential.user_id().clone(),
+            self.token_lifetime,
+            Utc::now(),
+        );
+
+        self.unit_of_work
+            .run(self.db.as_ref(), |tx| {
+                let password_reset_token = password_reset_token.clone();
+                Box::pin(async move {
+                    let repository = tx.password_reset_token_repository();
+                    repository
+                        .delete_by_user_id(password_reset_token.user_id())
+                        .await?;
+                    repository.insert(&password_reset_token).await?;
+
+                    Ok::<_, UsecaseError>(())
+                })
+            })
+            .await?;
+
+        let message = MailMessage::new(
+            user_credential.email().clone(),
+            "Reset your password".to_owned(),
+            format!(
+                "Use the following token to reset your password. It expires at {}.\n\n{}\n",
+                password_reset_token.expires_at().to_rfc3339(),
+                token.as_str()
+            ),
+        );
+        self.mailer.send(&message).await?;
+
+        Ok(())
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use std::sync::atomic::{AtomicU32, Ordering};
+
+    use async_trait::async_trait;
+    use todo_app_domain::aggregate_root::user_credential::repository::MockUserCredentialRepository;
+
+    use super::*;
+    use crate::database::MockDB;
+
+    mockall::mock! {
+        Mailer {}
+
+        #[async_trait]
+        impl Mailer for Mailer {
+            async fn send(&self, message: &MailMessage) -> Result<(), anyhow::Error>;
+        }
+    }
+
+    impl std::fmt::Debug for MockMailer {
+        fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+            f.debug_struct("MockMailer").finish_non_exhaustive()
+        }
+    }
+
+    #[tokio::test]
+    async fn request_password_reset_drops_requests_beyond_the_limit() {
+        let lookups = Arc::new(AtomicU32::new(0));
+        let mut user_credential_repository = MockUserCredentialRepository::new();
+        let counter = lookups.clone();
+        user_credential_repository
+            .expect_find_by_email()
+            .returning(move |_| {
+                counter.fetch_add(1, Ordering::SeqCst);
+                // Keeps the request pending.
+                Box::pin(std::future::pending())
+            });
+        let user_credential_repository = Arc::new(user_credential_repository);
+        let mut db = MockDB::new();
+        db.expect_user_credential_repository()
+            .returning(move || user_credential_repository.clone());
+
+        let usecase = RequestPasswordResetUsecase::new(
+            Arc::new(db),
+            Arc::new(MockMailer::new()),
+            Duration::hours(1),
+        )
+        .with_max_pending_requests(2);
+        for i in 0..5 {
+            usecase.execute(format!("user{i}@example.com"));
+        }
+        tokio::time::sleep(std::time::Duration::from_millis(50)).await;
+
+        assert_eq!(lookups.load(Ordering::SeqCst), 2);
+    }
+}
@@ -0,0 +1,110 @@
+use std::sync::Arc;
+
+use chrono::Utc;
+use nameof::name_of;
+use todo_app_domain::{
+    aggregate_root::{
+        user::value_object::UserId,
+        user_credential::value_object::{Password, PasswordHasher},
+    },
+    error::{RepositoryError, ValidationError, ValidationErrors},
+    value_object::SecretToken,
+};
+
+use crate::{
+    database::{UnitOfWork, DB},
+    usecase::error::UsecaseError,
+};
+
+#[derive(Clone, Debug)]
+pub struct ResetPasswordUsecase {
+    db: Arc<dyn DB>,
+    password_hasher: PasswordHasher,
+    unit_of_work: UnitOfWork,
+}
+
+impl ResetPasswordUsecase {
+    pub fn new(db: Arc<dyn DB>, password_hasher: PasswordHasher) -> Self {
+        Self {
+            db,
+            password_hasher,
+            unit_of_work: UnitOfWork::default(),
+        }
+    }
+
+    pub fn with_unit_of_work(self, unit_of_work: UnitOfWork) -> Self {
+        Self {
+            unit_of_work,
+            ..self
+        }
+    }
+
+    /// Consumes the password reset token and sets the new password of its user.
+    ///
+    /// Returns the user whose password was reset, so that their sessions can be revoked.
+    pub async fn execute(&self, token: String, password: String) -> Result<UserId, UsecaseError> {
+        let token = SecretToken::try_from(token);
+        let password = Password::try_from(password);
+        let (token, password) = match (token, password) {
+            (Ok(token), Ok(password)) => (token, password),
+            (token, password) => {
+                return Err(UsecaseError::Expected {
+                    message: "invalid password reset",
+                    errors: ValidationErrors::builder()
+                        .result(name_of!(token), token)
+                        .result(name_of!(password), password)
+                        .build(),
+                });
+            }
+        };
+
+        let token_hash = token.to_hash();
+        let password_hash = password.to_hash(&self.password_hasher);
+
+        self.unit_of_work
+            .run(self.db.as_ref(), |tx| {
+                let token_hash = token_hash.clone();
+                let password_hash = password_hash.clone();
+                Box::pin(async move {
+                    let repository = tx.password_reset_token_repository();
+                    let password_reset_token = repository
+                        .find(&token_hash)
+                        .await?
+                        .filter(|password_reset_token| !password_reset_token.is_expired(Utc::now()))
+                        .ok_or_else(invalid_token_error)?;
+
+                    // Deleting the token is what makes it single-use: of two concurrent resets
+                    // with the same token, only one gets to delete it.
+                    match repository.delete(&token_hash).await {
+                        Err(RepositoryError::NotFound) => return Err(invalid_token_error()),
+                        result => result?,
+                    }
+                    repository
+                        .delete_by_user_id(password_reset_token.user_id())
+                        .await?;
+
+                    let user_credential_repository = tx.user_credential_repository();
+                    let mut user_credential = user_credential_repository
+                        .find(password_reset_token.user_id())
+                        .await?
+                        .ok_or_else(invalid_token_error)?;
+                    user_credential.set_password_hash(password_hash);
+                    user_credential_repository.update(&user_credential).await?;
+
+                    let (user_id, _, _, _) = user_credential.into_inner();
+
+                    Ok(user_id)
+                })
+            })
+            .await
+    }
+}
+
+fn invalid_token_error() -> UsecaseError {
+    UsecaseError::Expected {
+        message: "invalid or expired token",
+        errors: ValidationErrors::builder()
+            .error("token", ValidationError::Invalid)
+            .build(),
+    }
+}
@@ -0,0 +1,73 @@
+use std::sync::Arc;
+
+use nameof::name_of;
+use todo_app_domain::{
+    aggregate_root::{todo::repository::TodoSearchHit, user::value_object::UserId},
+    error::{ValidationError, ValidationErrors},
+};
+
+use crate::{database::DB, usecase::error::UsecaseError};
+
+const SEARCH_TEXT_MAX_LENGTH: usize = 200;
+const DEFAULT_LIMIT: u32 = 20;
+const MAX_LIMIT: u32 = 100;
+
+#[derive(Clone, Debug)]
+pub struct SearchTodosUsecase {
+    db: Arc<dyn DB>,
+}
+
+impl SearchTodosUsecase {
+    pub fn new(db: Arc<dyn DB>) -> Self {
+        Self { db }
+    }
+
+    pub async fn execute(
+        &self,
+        user_id: &UserId,
+        q: String,
+        limit: Option<String>,
+    ) -> Result<Vec<TodoSearchHit>, UsecaseError> {
+        let q = parse_search_text(q);
+        let limit = limit.map(parse_limit).transpose();
+        let (q, limit) = match (q, limit) {
+            (Ok(q), Ok(limit)) => (q, limit.unwrap_or(DEFAULT_LIMIT)),
+            (q, limit) => {
+                return Err(UsecaseError::Expected {
+                    message: "invalid todo search",
+                    errors: ValidationErrors::builder()
+                        .result(name_of!(q), q)
+                        .result(name_of!(limit), limit)
+                        .build(),
+                });
+            }
+        };
+
+        let hits = self.db.todo_repository().search(user_id, &q, limit).await?;
+
+        Ok(hits)
+    }
+}
+
+fn parse_search_text(value: String) -> Result<String, ValidationError> {
+    let value = value.trim();
+    if value.is_empty() {
+        return Err(ValidationError::Required);
+    }
+    if value.len() > SEARCH_TEXT_MAX_LENGTH {
+        return Err(ValidationError::Length {
+            min: None,
+            max: Some(SEARCH_TEXT_MAX_LENGTH),
+        });
+    }
+
+    Ok(value.to_owned())
+}
+
+fn parse_limit(value: String) -> Result<u32, ValidationError> {
+    value
+        .parse::<u32>()
+        .ok()
+        .filter(|limit| (1..=MAX_LIMIT).contains(limit))
+        .ok_or(ValidationError::Invalid)
+}
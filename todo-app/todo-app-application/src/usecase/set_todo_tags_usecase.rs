@@ -0,0 +1,83 @@
+use std::sync::Arc;
+
+use chrono::Utc;
+use todo_app_domain::{
+    aggregate_root::{
+        tag::value_object::TagId,
+        todo::{entity::Todo, value_object::TodoId},
+        user::value_object::UserId,
+    },
+    error::{ValidationError, ValidationErrors},
+};
+
+use crate::{
+    database::{IsolationLevel, RetryPolicy, UnitOfWork, DB},
+    usecase::{error::UsecaseError, get_todo_usecase::todo_not_found_error},
+};
+
+#[derive(Clone, Debug)]
+pub struct SetTodoTagsUsecase {
+    db: Arc<dyn DB>,
+    unit_of_work: UnitOfWork,
+}
+
+impl SetTodoTagsUsecase {
+    pub fn new(db: Arc<dyn DB>) -> Self {
+        Self {
+            db,
+            unit_of_work: UnitOfWork::new(IsolationLevel::RepeatableRead, RetryPolicy::default()),
+        }
+    }
+
+    pub fn with_unit_of_work(self, unit_of_work: UnitOfWork) -> Self {
+        Self {
+            unit_of_work,
+            ..self
+        }
+    }
+
+    /// Replaces the tags of the todo; every tag must belong to the user.
+    pub async fn execute(
+        &self,
+        user_id: &UserId,
+        todo_id: &TodoId,
+        tag_ids: Vec<TagId>,
+    ) -> Result<Todo, UsecaseError> {
+        self.unit_of_work
+            .run(self.db.as_ref(), |tx| {
+                let user_id = user_id.clone();
+                let todo_id = todo_id.clone();
+                let tag_ids = tag_ids.clone();
+                Box::pin(async move {
+                    let todo_repository = tx.todo_repository();
+                    let mut todo = todo_repository
+                        .find(&todo_id)
+                        .await?
+                        .filter(|todo| todo.user_id() == &user_id)
+                        .ok_or_else(todo_not_found_error)?;
+
+                    let tag_repository = tx.tag_repository();
+                    for tag_id in &tag_ids {
+                        let owned = tag_repository
+                            .find(tag_id)
+                            .await?
+                            .is_some_and(|tag| tag.user_id() == &user_id);
+                        if !owned {
+                            return Err(UsecaseError::Expected {
+                                message: "unknown tag",
+                                errors: ValidationErrors::builder()
+                                    .error("tag_ids", ValidationError::Invalid)
+                                    .build(),
+                            });
+                        }
+                    }
+
+                    todo.retag(tag_ids, Utc::now());
+                    todo_repository.update(&todo).await?;
+
+                    Ok(todo)
+                })
+            })
+            .await
+    }
+}
@@ -1,23 +1,131 @@
 use std::sync::Arc;
 
-use crate::database::DB;
+use nameof::name_of;
+use todo_app_domain::{
+    aggregate_root::{
+        todo_list::entity::TodoList,
+        user::{
+            entity::User,
+            value_object::{UserId, UserName},
+        },
+        user_credential::{
+            entity::UserCredential,
+            value_object::{Email, Password, PasswordHasher},
+        },
+    },
+    error::ValidationErrors,
+};
+
+use crate::{
+    database::{UnitOfWork, DB},
+    mail::Mailer,
+    usecase::{
+        error::UsecaseError,
+        verify_email_usecase::{issue_email_verification_token, send_email_verification_mail},
+        EmailVerificationPolicy,
+    },
+};
 
 #[derive(Clone, Debug)]
 pub struct SignupUsecase {
     db: Arc<dyn DB>,
+    password_hasher: PasswordHasher,
+    mailer: Arc<dyn Mailer>,
+    email_verification_policy: EmailVerificationPolicy,
+    unit_of_work: UnitOfWork,
 }
 
 impl SignupUsecase {
-    pub fn new(db: Arc<dyn DB>) -> Self {
-        Self { db }
+    pub fn new(
+        db: Arc<dyn DB>,
+        password_hasher: PasswordHasher,
+        mailer: Arc<dyn Mailer>,
+        email_verification_policy: EmailVerificationPolicy,
+    ) -> Self {
+        Self {
+            db,
+            password_hasher,
+            mailer,
+            email_verification_policy,
+            unit_of_work: UnitOfWork::default(),
+        }
+    }
+
+    pub fn with_unit_of_work(self, unit_of_work: UnitOfWork) -> Self {
+        Self {
+            unit_of_work,
+            ..self
+        }
     }
 
     pub async fn execute(
         &self,
-        _name: String,
-        _email: String,
-        _password: String,
-    ) -> Result<(), ()> {
-        Ok(())
+        name: String,
+        email: String,
+        password: String,
+    ) -> Result<UserId, UsecaseError> {
+        let name = UserName::try_from(name);
+        let email = Email::try_from(email);
+        let password = Password::try_from(password);
+        let (name, email, password) = match (name, email, password) {
+            (Ok(name), Ok(email), Ok(password)) => (name, email, password),
+            (name, email, password) => {
+                return Err(UsecaseError::Expected {
+                    message: "invalid signup request",
+                    errors: ValidationErrors::builder()
+                        .result(name_of!(name), name)
+                        .result(name_of!(email), email)
+                        .result(name_of!(password), password)
+                        .build(),
+                });
+            }
+        };
+
+        let user = User::new(name);
+        let user_credential = UserCredential::new(
+            user.id().clone(),
+            email,
+            password.to_hash(&self.password_hasher),
+        );
+
+        let token_lifetime = self.email_verification_policy.token_lifetime();
+        let (email_verification_token, token) = self
+            .unit_of_work
+            .run(self.db.as_ref(), |tx| {
+                let user = user.clone();
+                let user_credential = user_credential.clone();
+                Box::pin(async move {
+                    tx.user_repository().insert(&user).await?;
+                    tx.user_credential_repository()
+                        .insert(&user_credential)
+                        .await?;
+                    tx.todo_list_repository()
+                        .insert(&TodoList::new_inbox(user.id().clone()))
+                        .await?;
+
+                    let token = issue_email_verification_token(
+                        tx,
+                        user.id(),
+                        user_credential.email(),
+                        token_lifetime,
+                    )
+                    .await?;
+
+                    Ok::<_, UsecaseError>(token)
+                })
+            })
+            .await?;
+
+        send_email_verification_mail(
+            self.mailer.as_ref(),
+            &user_credential,
+            &token,
+            email_verification_token.expires_at(),
+        )
+        .await;
+
+        let (user_id, _) = user.into_inner();
+
+        Ok(user_id)
     }
 }
@@ -0,0 +1,91 @@
+use std::sync::Arc;
+
+use nameof::name_of;
+use todo_app_domain::{
+    aggregate_root::{
+        tag::{
+            entity::Tag,
+            value_object::{TagColor, TagId, TagName},
+        },
+        user::value_object::UserId,
+    },
+    error::ValidationErrors,
+};
+
+use crate::{
+    database::{IsolationLevel, RetryPolicy, UnitOfWork, DB},
+    usecase::{error::UsecaseError, get_tag_usecase::tag_not_found_error},
+};
+
+#[derive(Clone, Debug)]
+pub struct UpdateTagUsecase {
+    db: Arc<dyn DB>,
+    unit_of_work: UnitOfWork,
+}
+
+impl UpdateTagUsecase {
+    pub fn new(db: Arc<dyn DB>) -> Self {
+        Self {
+            db,
+            unit_of_work: UnitOfWork::new(IsolationLevel::RepeatableRead, RetryPolicy::default()),
+        }
+    }
+
+    pub fn with_unit_of_work(self, unit_of_work: UnitOfWork) -> Self {
+        Self {
+            unit_of_work,
+            ..self
+        }
+    }
+
+    pub async fn execute(
+        &self,
+        user_id: &UserId,
+        tag_id: &TagId,
+        name: Option<String>,
+        color: Option<String>,
+    ) -> Result<Tag, UsecaseError> {
+        let name = name.map(TagName::try_from).transpose();
+        let color = color.map(TagColor::try_from).transpose();
+        let (name, color) = match (name, color) {
+            (Ok(name), Ok(color)) => (name, color),
+            (name, color) => {
+                return Err(UsecaseError::Expected {
+                    message: "invalid tag",
+                    errors: ValidationErrors::builder()
+                        .result(name_of!(name), name)
+                        .result(name_of!(color), color)
+                        .build(),
+                });
+            }
+        };
+
+        self.unit_of_work
+            .run(self.db.as_ref(), |tx| {
+                let user_id = user_id.clone();
+                let tag_id = tag_id.clone();
+                let name = name.clone();
+                let color = color.clone();
+                Box::pin(async move {
+                    let tag_repository = tx.tag_repository();
+                    let mut tag = tag_repository
+                        .find(&tag_id)
+                        .await?
+                        .filter(|tag| tag.user_id() == &user_id)
+                        .ok_or_else(tag_not_found_error)?;
+
+                    if let Some(name) = name {
+                        tag.rename(name);
+                    }
+                    if let Some(color) = color {
+                        tag.recolor(color);
+                    }
+
+                    tag_repository.update(&tag).await?;
+
+                    Ok(tag)
+                })
+            })
+            .await
+    }
+}
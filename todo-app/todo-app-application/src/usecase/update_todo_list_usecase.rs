@@ -0,0 +1,112 @@
+use std::sync::Arc;
+
+use nameof::name_of;
+use todo_app_domain::{
+    aggregate_root::{
+        todo_list::{
+            entity::TodoList,
+            value_object::{TodoListId, TodoListName},
+        },
+        user::value_object::UserId,
+    },
+    error::{ValidationError, ValidationErrors},
+};
+
+use crate::{
+    database::{IsolationLevel, RetryPolicy, UnitOfWork, DB},
+    usecase::{error::UsecaseError, get_todo_list_usecase::todo_list_not_found_error},
+};
+
+#[derive(Clone, Debug)]
+pub struct UpdateTodoListUsecase {
+    db: Arc<dyn DB>,
+    unit_of_work: UnitOfWork,
+}
+
+impl UpdateTodoListUsecase {
+    pub fn new(db: Arc<dyn DB>) -> Self {
+        Self {
+            db,
+            unit_of_work: UnitOfWork::new(IsolationLevel::RepeatableRead, RetryPolicy::default()),
+        }
+    }
+
+    pub fn with_unit_of_work(self, unit_of_work: UnitOfWork) -> Self {
+        Self {
+            unit_of_work,
+            ..self
+        }
+    }
+
+    /// Renames, repositions or (un)archives the list; the inbox cannot be archived.
+    pub async fn execute(
+        &self,
+        user_id: &UserId,
+        todo_list_id: &TodoListId,
+        name: Option<String>,
+        position: Option<i32>,
+        archived: Option<bool>,
+    ) -> Result<TodoList, UsecaseError> {
+        let name = name.map(TodoListName::try_from).transpose();
+        let position = position
+            .map(|position| {
+                if !(0..=TodoList::MAX_POSITION).contains(&position) {
+                    return Err(ValidationError::Invalid);
+                }
+                Ok(position)
+            })
+            .transpose();
+        let (name, position) = match (name, position) {
+            (Ok(name), Ok(position)) => (name, position),
+            (name, position) => {
+                return Err(UsecaseError::Expected {
+                    message: "invalid todo list",
+                    errors: ValidationErrors::builder()
+                        .result(name_of!(name), name)
+                        .result(name_of!(position), position)
+                        .build(),
+                });
+            }
+        };
+
+        self.unit_of_work
+            .run(self.db.as_ref(), |tx| {
+                let user_id = user_id.clone();
+                let todo_list_id = todo_list_id.clone();
+                let name = name.clone();
+                Box::pin(async move {
+                    let todo_list_repository = tx.todo_list_repository();
+                    let mut todo_list = todo_list_repository
+                        .find(&todo_list_id)
+                        .await?
+                        .filter(|todo_list| todo_list.user_id() == &user_id)
+                        .ok_or_else(todo_list_not_found_error)?;
+
+                    if let Some(name) = name {
+                        todo_list.rename(name);
+                    }
+                    if let Some(position) = position {
+                        todo_list.reposition(position);
+                    }
+                    match archived {
+                        Some(true) if todo_list.inbox() => {
+                            return Err(UsecaseError::Expected {
+                                message: "the inbox cannot be archived",
+                                errors: ValidationErrors::builder()
+                                    .error("archived", ValidationError::Invalid)
+                                    .build(),
+                            });
+                        }
+                        Some(true) => todo_list.archive(),
+                        Some(false) => todo_list.unarchive(),
+                        None => {}
+                    }
+
+                    todo_list_repository.update(&todo_list).await?;
+
+                    Ok(todo_list)
+                })
+            })
+            .await
+    }
+}
@@ -0,0 +1,114 @@
+use std::sync::Arc;
+
+use chrono::Utc;
+use nameof::name_of;
+use todo_app_domain::{
+    aggregate_root::{
+        todo::{
+            entity::Todo,
+            value_object::{TodoDescription, TodoId, TodoTitle},
+        },
+        user::value_object::UserId,
+    },
+    error::ValidationErrors,
+};
+
+use crate::{
+    database::{IsolationLevel, RetryPolicy, UnitOfWork, DB},
+    usecase::{
+        create_todo_usecase::parse_due_date, error::UsecaseError,
+        get_todo_usecase::todo_not_found_error,
+    },
+};
+
+#[derive(Clone, Debug)]
+pub struct UpdateTodoUsecase {
+    db: Arc<dyn DB>,
+    unit_of_work: UnitOfWork,
+}
+
+impl UpdateTodoUsecase {
+    /// Runs in a repeatable read transaction, so a todo modified by a concurrent
+    /// request between the read and the write is retried instead of overwritten.
+    pub fn new(db: Arc<dyn DB>) -> Self {
+        Self {
+            db,
+            unit_of_work: UnitOfWork::new(IsolationLevel::RepeatableRead, RetryPolicy::default()),
+        }
+    }
+
+    pub fn with_unit_of_work(self, unit_of_work: UnitOfWork) -> Self {
+        Self {
+            unit_of_work,
+            ..self
+        }
+    }
+
+    pub async fn execute(
+        &self,
+        user_id: &UserId,
+        todo_id: &TodoId,
+        title: Option<String>,
+        description: Option<Option<String>>,
+        due_date: Option<Option<String>>,
+        completed: Option<bool>,
+    ) -> Result<Todo, UsecaseError> {
+        let title = title.map(TodoTitle::try_from).transpose();
+        let description = description
+            .map(|description| description.map(TodoDescription::try_from).transpose())
+            .transpose();
+        let due_date = due_date
+            .map(|due_date| due_date.map(parse_due_date).transpose())
+            .transpose();
+        let (title, description, due_date) = match (title, description, due_date) {
+            (Ok(title), Ok(description), Ok(due_date)) => (title, description, due_date),
+            (title, description, due_date) => {
+                return Err(UsecaseError::Expected {
+                    message: "invalid todo",
+                    errors: ValidationErrors::builder()
+                        .result(name_of!(title), title)
+                        .result(name_of!(description), description)
+                        .result(name_of!(due_date), due_date)
+                        .build(),
+                });
+            }
+        };
+
+        self.unit_of_work
+            .run(self.db.as_ref(), |tx| {
+                let user_id = user_id.clone();
+                let todo_id = todo_id.clone();
+                let title = title.clone();
+                let description = description.clone();
+                Box::pin(async move {
+                    let todo_repository = tx.todo_repository();
+                    let mut todo = todo_repository
+                        .find(&todo_id)
+                        .await?
+                        .filter(|todo| todo.user_id() == &user_id)
+                        .ok_or_else(todo_not_found_error)?;
+
+                    let now = Utc::now();
+                    if let Some(title) = title {
+                        todo.rename(title, now);
+                    }
+                    if let Some(description) = description {
+                        todo.describe(description, now);
+                    }
+                    if let Some(due_date) = due_date {
+                        todo.reschedule(due_date, now);
+                    }
+                    match completed {
+                        Some(true) => todo.complete(now),
+                        Some(false) => todo.reopen(now),
+                        None => {}
+                    }
+
+                    todo_repository.update(&todo).await?;
+
+                    Ok(todo)
+                })
+            })
+            .await
+    }
+}
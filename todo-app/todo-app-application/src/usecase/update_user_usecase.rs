@@ -0,0 +1,75 @@
+use std::sync::Arc;
+
+use nameof::name_of;
+use todo_app_domain::{
+    aggregate_root::user::{
+        entity::User,
+        value_object::{UserId, UserName},
+    },
+    error::ValidationErrors,
+};
+
+use crate::{
+    database::{IsolationLevel, RetryPolicy, UnitOfWork, DB},
+    usecase::{error::UsecaseError, get_user_usecase::user_not_found_error},
+};
+
+#[derive(Clone, Debug)]
+pub struct UpdateUserUsecase {
+    db: Arc<dyn DB>,
+    unit_of_work: UnitOfWork,
+}
+
+impl UpdateUserUsecase {
+    pub fn new(db: Arc<dyn DB>) -> Self {
+        Self {
+            db,
+            unit_of_work: UnitOfWork::new(IsolationLevel::RepeatableRead, RetryPolicy::default()),
+        }
+    }
+
+    pub fn with_unit_of_work(self, unit_of_work: UnitOfWork) -> Self {
+        Self {
+            unit_of_work,
+            ..self
+        }
+    }
+
+    pub async fn execute(
+        &self,
+        user_id: &UserId,
+        name: Option<String>,
+    ) -> Result<User, UsecaseError> {
+        let name =
+            name.map(UserName::try_from)
+                .transpose()
+                .map_err(|name| UsecaseError::Expected {
+                    message: "invalid user",
+                    errors: ValidationErrors::builder()
+                        .error(name_of!(name), name)
+                        .build(),
+                })?;
+
+        self.unit_of_work
+            .run(self.db.as_ref(), |tx| {
+                let user_id = user_id.clone();
+                let name = name.clone();
+                Box::pin(async move {
+                    let user_repository = tx.user_repository();
+                    let mut user = user_repository
+                        .find(&user_id)
+                        .await?
+                        .ok_or_else(user_not_found_error)?;
+
+                    if let Some(name) = name {
+                        user.set_name(name);
+                    }
+
+                    user_repository.update(&user).await?;
+
+                    Ok(user)
+                })
+            })
+            .await
+    }
+}
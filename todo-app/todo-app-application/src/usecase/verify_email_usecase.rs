@@ -0,0 +1,139 @@
+use std::sync::Arc;
+
+use chrono::{DateTime, Duration, Utc};
+use nameof::name_of;
+use todo_app_domain::{
+    aggregate_root::{
+        email_verification_token::entity::EmailVerificationToken,
+        user::value_object::UserId,
+        user_credential::{entity::UserCredential, value_object::Email},
+    },
+    error::{RepositoryError, ValidationError, ValidationErrors},
+    value_object::SecretToken,
+};
+
+use crate::{
+    database::{Transaction, UnitOfWork, DB},
+    mail::{MailMessage, Mailer},
+    usecase::error::UsecaseError,
+};
+
+#[derive(Clone, Debug)]
+pub struct VerifyEmailUsecase {
+    db: Arc<dyn DB>,
+    unit_of_work: UnitOfWork,
+}
+
+impl VerifyEmailUsecase {
+    pub fn new(db: Arc<dyn DB>) -> Self {
+        Self {
+            db,
+            unit_of_work: UnitOfWork::default(),
+        }
+    }
+
+    pub fn with_unit_of_work(self, unit_of_work: UnitOfWork) -> Self {
+        Self {
+            unit_of_work,
+            ..self
+        }
+    }
+
+    /// Consumes the verification token and marks the address it was issued for as verified.
+    pub async fn execute(&self, token: String) -> Result<(), UsecaseError> {
+        let token = SecretToken::try_from(token).map_err(|token| UsecaseError::Expected {
+            message: "invalid email verification",
+            errors: ValidationErrors::builder()
+                .error(name_of!(token), token)
+                .build(),
+        })?;
+        let token_hash = token.to_hash();
+
+        self.unit_of_work
+            .run(self.db.as_ref(), |tx| {
+                let token_hash = token_hash.clone();
+                Box::pin(async move {
+                    let repository = tx.email_verification_token_repository();
+                    let email_verification_token = repository
+                        .find(&token_hash)
+                        .await?
+                        .filter(|email_verification_token| {
+                            !email_verification_token.is_expired(Utc::now())
+                        })
+                        .ok_or_else(invalid_token_error)?;
+
+                    match repository.delete(&token_hash).await {
+                        Err(RepositoryError::NotFound) => return Err(invalid_token_error()),
+                        result => result?,
+                    }
+
+                    let user_credential_repository = tx.user_credential_repository();
+                    let mut user_credential = user_credential_repository
+                        .find(email_verification_token.user_id())
+                        .await?
+                        .ok_or_else(invalid_token_error)?;
+                    // The address has changed since the token was issued.
+                    if user_credential.email() != email_verification_token.email() {
+                        return Err(invalid_token_error());
+                    }
+
+                    user_credential.verify_email(Utc::now());
+                    user_credential_repository.update(&user_credential).await?;
+
+                    Ok(())
+                })
+            })
+            .await
+    }
+}
+
+fn invalid_token_error() -> UsecaseError {
+    UsecaseError::Expected {
+        message: "invalid or expired token",
+        errors: ValidationErrors::builder()
+            .error("token", ValidationError::Invalid)
+            .build(),
+    }
+}
+
+/// Replaces any outstanding verification tokens of the user with a new one for `email`.
+pub(crate) async fn issue_email_verification_token(
+    tx: &dyn Transaction,
+    user_id: &UserId,
+    email: &Email,
+    lifetime: Duration,
+) -> Result<(EmailVerificationToken, SecretToken), RepositoryError> {
+    let (email_verification_token, token) =
+        EmailVerificationToken::issue(user_id.clone(), email.clone(), lifetime, Utc::now());
+
+    let repository = tx.email_verification_token_repository();
+    repository.delete_by_user_id(user_id).await?;
+    repository.insert(&email_verification_token).await?;
+
+    Ok((email_verification_token, token))
+}
+
+/// Mails the verification token, logging rather than returning a failure so that the
+/// change that triggered it is kept.
+pub(crate) async fn send_email_verification_mail(
+    mailer: &dyn Mailer,
+    user_credential: &UserCredential,
+    token: &SecretToken,
+    expires_at: &DateTime<Utc>,
+) {
+    let message = MailMessage::new(
+        user_credential.email().clone(),
+        "Verify your email address".to_owned(),
+        format!(
+            "Use the following token to verify your email address. It expires at {}.\n\n{}\n",
+            expires_at.to_rfc3339(),
+            token.as_str()
+        ),
+    );
+    if let Err(e) = mailer.send(&message).await {
+        tracing::error!(
+            user_id = %user_credential.user_id().as_uuid(),
+            "failed to send email verification mail: {e:?}"
+        );
+    }
+}
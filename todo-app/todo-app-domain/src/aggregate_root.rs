@@ -1,3 +1,7 @@
+pub mod email_verification_token;
+pub mod password_reset_token;
+pub mod tag;
 pub mod todo;
+pub mod todo_list;
 pub mod user;
 pub mod user_credential;
@@ -0,0 +1,88 @@
+use chrono::{DateTime, Duration, Utc};
+use getset::Getters;
+
+use crate::{
+    aggregate_root::{user::value_object::UserId, user_credential::value_object::Email},
+    value_object::{SecretToken, SecretTokenHash},
+};
+
+/// A single-use proof that the user can read mail sent to `email`.
+#[derive(Clone, Debug, Eq, Getters, PartialEq)]
+pub struct EmailVerificationToken {
+    #[getset(get = "pub")]
+    token_hash: SecretTokenHash,
+    #[getset(get = "pub")]
+    user_id: UserId,
+    #[getset(get = "pub")]
+    email: Email,
+    #[getset(get = "pub")]
+    expires_at: DateTime<Utc>,
+}
+
+impl EmailVerificationToken {
+    /// Issues a token for the address, returning the secret to be sent to it.
+    pub fn issue(
+        user_id: UserId,
+        email: Email,
+        lifetime: Duration,
+        now: DateTime<Utc>,
+    ) -> (Self, SecretToken) {
+        let token = SecretToken::generate();
+        let email_verification_token = Self {
+            token_hash: token.to_hash(),
+            user_id,
+            email,
+            expires_at: now + lifetime,
+        };
+        (email_verification_token, token)
+    }
+
+    pub fn is_expired(&self, now: DateTime<Utc>) -> bool {
+        self.expires_at <= now
+    }
+
+    pub fn into_inner(self) -> (SecretTokenHash, UserId, Email, DateTime<Utc>) {
+        (self.token_hash, self.user_id, self.email, self.expires_at)
+    }
+}
+
+impl From<(SecretTokenHash, UserId, Email, DateTime<Utc>)> for EmailVerificationToken {
+    fn from(
+        (token_hash, user_id, email, expires_at): (SecretTokenHash, UserId, Email, DateTime<Utc>),
+    ) -> Self {
+        Self {
+            token_hash,
+            user_id,
+            email,
+            expires_at,
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn email_verification_token_is_expired_test() {
+        let now = Utc::now();
+        let email = Email::try_from("user@example.com".to_owned()).unwrap();
+        let (email_verification_token, token) =
+            EmailVerificationToken::issue(UserId::new(), email, Duration::days(1), now);
+        assert_eq!(email_verification_token.token_hash(), &token.to_hash());
+
+        let tests = vec![
+            (now, false),
+            (now + Duration::hours(23), false),
+            (now + Duration::days(1), true),
+        ];
+
+        for (input, expected) in tests {
+            assert_eq!(
+                email_verification_token.is_expired(input),
+                expected,
+                "input: {input}"
+            )
+        }
+    }
+}
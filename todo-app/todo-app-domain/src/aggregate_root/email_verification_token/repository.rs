@@ -0,0 +1,3 @@
+mod email_verification_token_repository;
+
+pub use email_verification_token_repository::*;
@@ -0,0 +1,3 @@
+mod password_reset_token;
+
+pub use password_reset_token::PasswordResetToken;
@@ -0,0 +1,89 @@
+use chrono::{DateTime, Duration, Utc};
+use getset::Getters;
+
+use crate::{
+    aggregate_root::user::value_object::UserId,
+    value_object::{SecretToken, SecretTokenHash},
+};
+
+/// A single-use permission to set a new password without knowing the current one.
+#[derive(Clone, Debug, Eq, Getters, PartialEq)]
+pub struct PasswordResetToken {
+    #[getset(get = "pub")]
+    token_hash: SecretTokenHash,
+    #[getset(get = "pub")]
+    user_id: UserId,
+    #[getset(get = "pub")]
+    expires_at: DateTime<Utc>,
+}
+
+impl PasswordResetToken {
+    /// Issues a token for the user, returning the secret to be sent to them.
+    pub fn issue(user_id: UserId, lifetime: Duration, now: DateTime<Utc>) -> (Self, SecretToken) {
+        let token = SecretToken::generate();
+        let password_reset_token = Self {
+            token_hash: token.to_hash(),
+            user_id,
+            expires_at: now + lifetime,
+        };
+        (password_reset_token, token)
+    }
+
+    pub fn is_expired(&self, now: DateTime<Utc>) -> bool {
+        self.expires_at <= now
+    }
+
+    pub fn into_inner(self) -> (SecretTokenHash, UserId, DateTime<Utc>) {
+        (self.token_hash, self.user_id, self.expires_at)
+    }
+}
+
+impl From<(SecretTokenHash, UserId, DateTime<Utc>)> for PasswordResetToken {
+    fn from((token_hash, user_id, expires_at): (SecretTokenHash, UserId, DateTime<Utc>)) -> Self {
+        Self {
+            token_hash,
+            user_id,
+            expires_at,
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn password_reset_token_issue_test() {
+        let now = Utc::now();
+        let (password_reset_token, token) =
+            PasswordResetToken::issue(UserId::new(), Duration::hours(1), now);
+
+        assert_eq!(password_reset_token.token_hash(), &token.to_hash());
+        assert_eq!(
+            password_reset_token.expires_at(),
+            &(now + Duration::hours(1))
+        );
+    }
+
+    #[test]
+    fn password_reset_token_is_expired_test() {
+        let now = Utc::now();
+        let (password_reset_token, _) =
+            PasswordResetToken::issue(UserId::new(), Duration::hours(1), now);
+
+        let tests = vec![
+            (now, false),
+            (now + Duration::minutes(59), false),
+            (now + Duration::hours(1), true),
+            (now + Duration::days(1), true),
+        ];
+
+        for (input, expected) in tests {
+            assert_eq!(
+                password_reset_token.is_expired(input),
+                expected,
+                "input: {input}"
+            )
+        }
+    }
+}
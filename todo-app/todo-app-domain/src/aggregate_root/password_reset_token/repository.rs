@@ -0,0 +1,3 @@
+mod password_reset_token_repository;
+
+pub use password_reset_token_repository::*;
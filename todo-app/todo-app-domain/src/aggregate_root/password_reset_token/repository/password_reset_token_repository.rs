@@ -0,0 +1,30 @@
+use std::fmt::Debug;
+
+use async_trait::async_trait;
+use mockall::automock;
+
+use crate::{
+    aggregate_root::{
+        password_reset_token::entity::PasswordResetToken, user::value_object::UserId,
+    },
+    error::RepositoryError,
+    value_object::SecretTokenHash,
+};
+
+#[async_trait]
+#[automock]
+pub trait PasswordResetTokenRepository: Debug + Send + Sync {
+    async fn find(
+        &self,
+        token_hash: &SecretTokenHash,
+    ) -> Result<Option<PasswordResetToken>, RepositoryError>;
+
+    async fn insert(
+        &self,
+        password_reset_token: &PasswordResetToken,
+    ) -> Result<(), RepositoryError>;
+
+    async fn delete(&self, token_hash: &SecretTokenHash) -> Result<(), RepositoryError>;
+
+    async fn delete_by_user_id(&self, user_id: &UserId) -> Result<(), RepositoryError>;
+}
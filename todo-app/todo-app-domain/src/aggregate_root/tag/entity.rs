@@ -0,0 +1,3 @@
+mod tag;
+
+pub use tag::Tag;
@@ -0,0 +1,52 @@
+use getset::Getters;
+
+use crate::aggregate_root::{
+    tag::value_object::{TagColor, TagId, TagName},
+    user::value_object::UserId,
+};
+
+#[derive(Clone, Debug, Eq, Getters, PartialEq)]
+pub struct Tag {
+    #[getset(get = "pub")]
+    id: TagId,
+    #[getset(get = "pub")]
+    user_id: UserId,
+    #[getset(get = "pub")]
+    name: TagName,
+    #[getset(get = "pub")]
+    color: TagColor,
+}
+
+impl Tag {
+    pub fn new(user_id: UserId, name: TagName, color: TagColor) -> Self {
+        Self {
+            id: TagId::new(),
+            user_id,
+            name,
+            color,
+        }
+    }
+
+    pub fn rename(&mut self, name: TagName) {
+        self.name = name;
+    }
+
+    pub fn recolor(&mut self, color: TagColor) {
+        self.color = color;
+    }
+
+    pub fn into_inner(self) -> (TagId, UserId, TagName, TagColor) {
+        (self.id, self.user_id, self.name, self.color)
+    }
+}
+
+impl From<(TagId, UserId, TagName, TagColor)> for Tag {
+    fn from((id, user_id, name, color): (TagId, UserId, TagName, TagColor)) -> Self {
+        Self {
+            id,
+            user_id,
+            name,
+            color,
+        }
+    }
+}
@@ -0,0 +1,3 @@
+mod tag_repository;
+
+pub use tag_repository::*;
@@ -0,0 +1,33 @@
+use std::fmt::Debug;
+
+use async_trait::async_trait;
+use mockall::automock;
+
+use crate::{
+    aggregate_root::{
+        tag::{entity::Tag, value_object::TagId},
+        user::value_object::UserId,
+    },
+    error::RepositoryError,
+};
+
+#[async_trait]
+#[automock]
+pub trait TagRepository: Debug + Send + Sync {
+    async fn find(&self, tag_id: &TagId) -> Result<Option<Tag>, RepositoryError>;
+
+    /// Returns the user's tags ordered by name.
+    async fn find_by_user_id(&self, user_id: &UserId) -> Result<Vec<Tag>, RepositoryError>;
+
+    /// Fails with `DomainError::TagNameAlreadyTaken` if the user has a tag with the same name,
+    /// ignoring case.
+    async fn insert(&self, tag: &Tag) -> Result<(), RepositoryError>;
+
+    /// Fails like `insert` on a duplicate name.
+    async fn update(&self, tag: &Tag) -> Result<(), RepositoryError>;
+
+    /// Also removes the tag from the todos it is assigned to.
+    async fn delete(&self, tag_id: &TagId) -> Result<(), RepositoryError>;
+
+    async fn delete_by_user_id(&self, user_id: &UserId) -> Result<(), RepositoryError>;
+}
@@ -0,0 +1,7 @@
+mod tag_color;
+mod tag_id;
+mod tag_name;
+
+pub use tag_color::TagColor;
+pub use tag_id::TagId;
+pub use tag_name::TagName;
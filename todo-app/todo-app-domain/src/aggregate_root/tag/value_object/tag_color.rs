@@ -0,0 +1,76 @@
+use crate::error::ValidationError;
+
+/// A `#rrggbb` colour, stored in lowercase.
+#[derive(Clone, Debug, Eq, PartialEq)]
+pub struct TagColor(String);
+
+impl TagColor {
+    pub fn as_str(&self) -> &str {
+        AsRef::as_ref(self)
+    }
+
+    pub fn into_string(self) -> String {
+        Into::into(self)
+    }
+}
+
+impl AsRef<str> for TagColor {
+    fn as_ref(&self) -> &str {
+        &self.0
+    }
+}
+
+impl TryFrom<String> for TagColor {
+    type Error = ValidationError;
+
+    fn try_from(value: String) -> Result<Self, Self::Error> {
+        if value.is_empty() {
+            return Err(Self::Error::Required);
+        }
+
+        let valid = match value.strip_prefix('#') {
+            Some(hex) => hex.len() == 6 && hex.chars().all(|c| c.is_ascii_hexdigit()),
+            None => false,
+        };
+        if !valid {
+            return Err(Self::Error::Invalid);
+        }
+
+        Ok(Self(value.to_ascii_lowercase()))
+    }
+}
+
+impl From<TagColor> for String {
+    fn from(value: TagColor) -> Self {
+        value.0
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::error::ValidationError;
+
+    use super::*;
+
+    #[test]
+    fn tag_color_try_from() {
+        let tests = vec![
+            ("", Err(ValidationError::Required)),
+            ("#1e90ff", Ok(TagColor("#1e90ff".to_owned()))),
+            ("#1E90FF", Ok(TagColor("#1e90ff".to_owned()))),
+            ("1e90ff", Err(ValidationError::Invalid)),
+            ("#1e90f", Err(ValidationError::Invalid)),
+            ("#1e90ffa", Err(ValidationError::Invalid)),
+            ("#1e90fg", Err(ValidationError::Invalid)),
+            ("blue", Err(ValidationError::Invalid)),
+        ];
+
+        for (input, expected) in tests {
+            assert_eq!(
+                TagColor::try_from(input.to_owned()),
+                expected,
+                "input: {input}"
+            );
+        }
+    }
+}
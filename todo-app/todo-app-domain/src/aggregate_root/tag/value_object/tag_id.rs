@@ -0,0 +1,36 @@
+use uuid::Uuid;
+
+#[derive(Clone, Debug, Default, Eq, Hash, Ord, PartialEq, PartialOrd)]
+pub struct TagId(Uuid);
+
+impl TagId {
+    pub fn new() -> Self {
+        Self(Uuid::new_v4())
+    }
+
+    pub fn as_uuid(&self) -> &Uuid {
+        AsRef::as_ref(self)
+    }
+
+    pub fn into_uuid(self) -> Uuid {
+        Into::into(self)
+    }
+}
+
+impl AsRef<Uuid> for TagId {
+    fn as_ref(&self) -> &Uuid {
+        &self.0
+    }
+}
+
+impl From<Uuid> for TagId {
+    fn from(value: Uuid) -> Self {
+        Self(value)
+    }
+}
+
+impl From<TagId> for Uuid {
+    fn from(value: TagId) -> Self {
+        value.0
+    }
+}
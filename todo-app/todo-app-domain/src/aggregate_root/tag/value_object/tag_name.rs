@@ -0,0 +1,81 @@
+use crate::error::ValidationError;
+
+const TAG_NAME_MAX_LENGTH: usize = 30;
+
+#[derive(Clone, Debug, Eq, PartialEq)]
+pub struct TagName(String);
+
+impl TagName {
+    pub fn as_str(&self) -> &str {
+        AsRef::as_ref(self)
+    }
+
+    pub fn into_string(self) -> String {
+        Into::into(self)
+    }
+}
+
+impl AsRef<str> for TagName {
+    fn as_ref(&self) -> &str {
+        &self.0
+    }
+}
+
+impl TryFrom<String> for TagName {
+    type Error = ValidationError;
+
+    fn try_from(value: String) -> Result<Self, Self::Error> {
+        if value.is_empty() {
+            return Err(Self::Error::Required);
+        }
+
+        if value.len() > TAG_NAME_MAX_LENGTH {
+            return Err(Self::Error::Length {
+                min: None,
+                max: Some(TAG_NAME_MAX_LENGTH),
+            });
+        }
+
+        Ok(Self(value))
+    }
+}
+
+impl From<TagName> for String {
+    fn from(value: TagName) -> Self {
+        value.0
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::error::ValidationError;
+
+    use super::*;
+
+    #[test]
+    fn tag_name_try_from() {
+        let tests = vec![
+            ("", Err(ValidationError::Required)),
+            ("work", Ok(TagName("work".to_owned()))),
+            (
+                "xxxxxxxxxxxxxxxxxxxxxxxxxxxxxx",
+                Ok(TagName("xxxxxxxxxxxxxxxxxxxxxxxxxxxxxx".to_owned())),
+            ),
+            (
+                "xxxxxxxxxxxxxxxxxxxxxxxxxxxxxxx",
+                Err(ValidationError::Length {
+                    min: None,
+                    max: Some(TAG_NAME_MAX_LENGTH),
+                }),
+            ),
+        ];
+
+        for (input, expected) in tests {
+            assert_eq!(
+                TagName::try_from(input.to_owned()),
+                expected,
+                "input: {input}"
+            );
+        }
+    }
+}
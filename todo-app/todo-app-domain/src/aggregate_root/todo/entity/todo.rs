@@ -1,30 +1,255 @@
-use getset::Getters;
+use chrono::{DateTime, NaiveDate, Timelike, Utc};
+use getset::{CopyGetters, Getters};
 
-use crate::aggregate_root::todo::value_object::{TodoId, TodoTitle};
+use crate::aggregate_root::{
+    tag::value_object::TagId,
+    todo::value_object::{TodoDescription, TodoId, TodoTitle},
+    todo_list::value_object::TodoListId,
+    user::value_object::UserId,
+};
 
-#[derive(Clone, Debug, Eq, Getters, PartialEq)]
+#[derive(Clone, CopyGetters, Debug, Eq, Getters, PartialEq)]
 pub struct Todo {
     #[getset(get = "pub")]
     id: TodoId,
-    #[getset(get = "pub", set = "pub")]
+    #[getset(get = "pub")]
+    user_id: UserId,
+    #[getset(get = "pub")]
+    todo_list_id: TodoListId,
+    #[getset(get = "pub")]
     title: TodoTitle,
+    #[getset(get = "pub")]
+    description: Option<TodoDescription>,
+    #[getset(get_copy = "pub")]
+    due_date: Option<NaiveDate>,
+    /// Sorted, without duplicates.
+    #[getset(get = "pub")]
+    tag_ids: Vec<TagId>,
+    #[getset(get_copy = "pub")]
+    completed_at: Option<DateTime<Utc>>,
+    #[getset(get_copy = "pub")]
+    created_at: DateTime<Utc>,
+    #[getset(get_copy = "pub")]
+    updated_at: DateTime<Utc>,
 }
 
+pub type TodoParts = (
+    TodoId,
+    UserId,
+    TodoListId,
+    TodoTitle,
+    Option<TodoDescription>,
+    Option<NaiveDate>,
+    Vec<TagId>,
+    Option<DateTime<Utc>>,
+    DateTime<Utc>,
+    DateTime<Utc>,
+);
+
 impl Todo {
-    pub fn new(title: TodoTitle) -> Self {
+    pub fn new(
+        user_id: UserId,
+        todo_list_id: TodoListId,
+        title: TodoTitle,
+        description: Option<TodoDescription>,
+        due_date: Option<NaiveDate>,
+        now: DateTime<Utc>,
+    ) -> Self {
+        let now = truncate_to_micros(now);
         Self {
             id: TodoId::new(),
+            user_id,
+            todo_list_id,
             title,
+            description,
+            due_date,
+            tag_ids: vec![],
+            completed_at: None,
+            created_at: now,
+            updated_at: now,
+        }
+    }
+
+    pub fn is_completed(&self) -> bool {
+        self.completed_at.is_some()
+    }
+
+    pub fn rename(&mut self, title: TodoTitle, now: DateTime<Utc>) {
+        self.title = title;
+        self.updated_at = truncate_to_micros(now);
+    }
+
+    pub fn describe(&mut self, description: Option<TodoDescription>, now: DateTime<Utc>) {
+        self.description = description;
+        self.updated_at = truncate_to_micros(now);
+    }
+
+    pub fn reschedule(&mut self, due_date: Option<NaiveDate>, now: DateTime<Utc>) {
+        self.due_date = due_date;
+        self.updated_at = truncate_to_micros(now);
+    }
+
+    pub fn move_to(&mut self, todo_list_id: TodoListId, now: DateTime<Utc>) {
+        self.todo_list_id = todo_list_id;
+        self.updated_at = truncate_to_micros(now);
+    }
+
+    /// Replaces the tags of the todo.
+    pub fn retag(&mut self, mut tag_ids: Vec<TagId>, now: DateTime<Utc>) {
+        tag_ids.sort();
+        tag_ids.dedup();
+        self.tag_ids = tag_ids;
+        self.updated_at = truncate_to_micros(now);
+    }
+
+    pub fn has_tag(&self, tag_id: &TagId) -> bool {
+        self.tag_ids.binary_search(tag_id).is_ok()
+    }
+
+    /// Marks the todo as completed; completing it again keeps the original completion time.
+    pub fn complete(&mut self, now: DateTime<Utc>) {
+        if self.completed_at.is_none() {
+            let now = truncate_to_micros(now);
+            self.completed_at = Some(now);
+            self.updated_at = now;
         }
     }
 
-    pub fn into_inner(self) -> (TodoId, TodoTitle) {
-        (self.id, self.title)
+    pub fn reopen(&mut self, now: DateTime<Utc>) {
+        if self.completed_at.is_some() {
+            self.completed_at = None;
+            self.updated_at = truncate_to_micros(now);
+        }
+    }
+
+    pub fn into_inner(self) -> TodoParts {
+        (
+            self.id,
+            self.user_id,
+            self.todo_list_id,
+            self.title,
+            self.description,
+            self.due_date,
+            self.tag_ids,
+            self.completed_at,
+            self.created_at,
+            self.updated_at,
+        )
+    }
+}
+
+/// Timestamps are stored with microsecond precision, so the todo keeps no more than that;
+/// otherwise a todo would be read back with different timestamps than it was written with.
+fn truncate_to_micros(now: DateTime<Utc>) -> DateTime<Utc> {
+    now.with_nanosecond(now.nanosecond() / 1_000 * 1_000)
+        .expect("truncated nanoseconds are in range")
+}
+
+impl From<TodoParts> for Todo {
+    fn from(
+        (
+            id,
+            user_id,
+            todo_list_id,
+            title,
+            description,
+            due_date,
+            tag_ids,
+            completed_at,
+            created_at,
+            updated_at,
+        ): TodoParts,
+    ) -> Self {
+        Self {
+            id,
+            user_id,
+            todo_list_id,
+            title,
+            description,
+            due_date,
+            tag_ids,
+            completed_at,
+            created_at,
+            updated_at,
+        }
     }
 }
 
-impl From<(TodoId, TodoTitle)> for Todo {
-    fn from((id, title): (TodoId, TodoTitle)) -> Self {
-        Self { id, title }
+#[cfg(test)]
+mod tests {
+    use chrono::{Duration, TimeZone};
+
+    use super::*;
+
+    #[test]
+    fn todo_complete_and_reopen() {
+        let created_at = Utc.ymd(2022, 9, 26).and_hms(9, 30, 0);
+        let mut todo = Todo::new(
+            UserId::new(),
+            TodoListId::new(),
+            TodoTitle::try_from("todo".to_owned()).unwrap(),
+            None,
+            None,
+            created_at,
+        );
+        assert!(!todo.is_completed());
+        assert_eq!(todo.updated_at(), created_at);
+
+        let completed_at = created_at + Duration::minutes(1);
+        todo.complete(completed_at);
+        todo.complete(completed_at + Duration::minutes(1));
+        assert!(todo.is_completed());
+        assert_eq!(todo.completed_at(), Some(completed_at));
+        assert_eq!(todo.updated_at(), completed_at);
+
+        let reopened_at = completed_at + Duration::minutes(2);
+        todo.reopen(reopened_at);
+        assert!(!todo.is_completed());
+        assert_eq!(todo.completed_at(), None);
+        assert_eq!(todo.updated_at(), reopened_at);
+        assert_eq!(todo.created_at(), created_at);
+    }
+
+    #[test]
+    fn todo_retag() {
+        let mut todo = Todo::new(
+            UserId::new(),
+            TodoListId::new(),
+            TodoTitle::try_from("todo".to_owned()).unwrap(),
+            None,
+            None,
+            Utc::now(),
+        );
+        let (a, b) = (TagId::new(), TagId::new());
+        let (a, b) = if a < b { (a, b) } else { (b, a) };
+
+        let retagged_at = todo.created_at() + Duration::minutes(1);
+        todo.retag(vec![b.clone(), a.clone(), b.clone()], retagged_at);
+        assert_eq!(todo.tag_ids(), &vec![a.clone(), b.clone()]);
+        assert!(todo.has_tag(&a));
+        assert_eq!(todo.updated_at(), retagged_at);
+
+        todo.retag(vec![], retagged_at);
+        assert!(!todo.has_tag(&a));
+    }
+
+    #[test]
+    fn todo_timestamps_are_truncated_to_micros() {
+        let now = Utc.ymd(2022, 9, 26).and_hms_nano(9, 30, 0, 123_456_789);
+        let mut todo = Todo::new(
+            UserId::new(),
+            TodoListId::new(),
+            TodoTitle::try_from("todo".to_owned()).unwrap(),
+            None,
+            None,
+            now,
+        );
+        let truncated = Utc.ymd(2022, 9, 26).and_hms_micro(9, 30, 0, 123_456);
+        assert_eq!(todo.created_at(), truncated);
+        assert_eq!(todo.updated_at(), truncated);
+
+        todo.complete(now + Duration::minutes(1));
+        assert_eq!(todo.completed_at(), Some(truncated + Duration::minutes(1)));
+        assert_eq!(todo.updated_at(), truncated + Duration::minutes(1));
     }
 }
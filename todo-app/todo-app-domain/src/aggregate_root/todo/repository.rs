@@ -1,3 +1,13 @@
+mod todo_cursor;
+mod todo_page;
+mod todo_query;
 mod todo_repository;
+mod todo_search_hit;
+mod todo_sort;
 
+pub use todo_cursor::*;
+pub use todo_page::*;
+pub use todo_query::*;
 pub use todo_repository::*;
+pub use todo_search_hit::*;
+pub use todo_sort::*;
@@ -0,0 +1,154 @@
+use chrono::{DateTime, NaiveDate, SecondsFormat, Utc};
+use uuid::Uuid;
+
+use crate::{
+    aggregate_root::todo::{
+        entity::Todo,
+        repository::{SortDirection, TodoSort, TodoSortKey, TodoSortValue},
+        value_object::TodoId,
+    },
+    error::ValidationError,
+};
+
+/// Position right after a todo in a sorted list, for fetching the page that follows it.
+///
+/// Encoded as an opaque string that also records the sort it was created for.
+#[derive(Clone, Debug, Eq, PartialEq)]
+pub struct TodoCursor {
+    direction: SortDirection,
+    value: TodoSortValue,
+    id: TodoId,
+}
+
+impl TodoCursor {
+    pub fn after(todo: &Todo, sort: TodoSort) -> Self {
+        Self {
+            direction: sort.direction(),
+            value: sort.key().value_of(todo),
+            id: todo.id().clone(),
+        }
+    }
+
+    pub fn sort(&self) -> TodoSort {
+        TodoSort::new(self.value.key(), self.direction)
+    }
+
+    pub fn value(&self) -> &TodoSortValue {
+        &self.value
+    }
+
+    pub fn id(&self) -> &TodoId {
+        &self.id
+    }
+
+    pub fn encode(&self) -> String {
+        let value = match &self.value {
+            TodoSortValue::CreatedAt(t) | TodoSortValue::UpdatedAt(t) => {
+                t.to_rfc3339_opts(SecondsFormat::AutoSi, true)
+            }
+            TodoSortValue::DueDate(d) => d.map(|d| d.to_string()).unwrap_or_default(),
+            TodoSortValue::Title(t) => t.clone(),
+        };
+        let cursor = format!(
+            "{}.{}.{}.{}",
+            self.value.key().as_str(),
+            self.direction.as_str(),
+            self.id.as_uuid().simple(),
+            value
+        );
+        hex::encode(cursor)
+    }
+
+    pub fn decode(s: &str) -> Result<Self, ValidationError> {
+        let cursor = hex::decode(s).map_err(|_| ValidationError::Invalid)?;
+        let cursor = String::from_utf8(cursor).map_err(|_| ValidationError::Invalid)?;
+        let mut parts = cursor.splitn(4, '.');
+        let mut next = || parts.next().ok_or(ValidationError::Invalid);
+        let key = next()?.parse::<TodoSortKey>()?;
+        let direction = next()?.parse::<SortDirection>()?;
+        let id = Uuid::parse_str(next()?).map_err(|_| ValidationError::Invalid)?;
+        let value = next()?;
+
+        let value = match key {
+            TodoSortKey::CreatedAt => TodoSortValue::CreatedAt(parse_timestamp(value)?),
+            TodoSortKey::UpdatedAt => TodoSortValue::UpdatedAt(parse_timestamp(value)?),
+            TodoSortKey::DueDate if value.is_empty() => TodoSortValue::DueDate(None),
+            TodoSortKey::DueDate => TodoSortValue::DueDate(Some(
+                value
+                    .parse::<NaiveDate>()
+                    .map_err(|_| ValidationError::Invalid)?,
+            )),
+            TodoSortKey::Title => TodoSortValue::Title(value.to_owned()),
+        };
+
+        Ok(Self {
+            direction,
+            value,
+            id: TodoId::from(id),
+        })
+    }
+}
+
+fn parse_timestamp(value: &str) -> Result<DateTime<Utc>, ValidationError> {
+    DateTime::parse_from_rfc3339(value)
+        .map(|t| t.with_timezone(&Utc))
+        .map_err(|_| ValidationError::Invalid)
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::aggregate_root::{
+        todo::value_object::TodoTitle, todo_list::value_object::TodoListId,
+        user::value_object::UserId,
+    };
+
+    use super::*;
+
+    #[test]
+    fn todo_cursor_round_trip() {
+        let todo = Todo::new(
+            UserId::new(),
+            TodoListId::new(),
+            TodoTitle::try_from("todo.with.dots".to_owned()).unwrap(),
+            None,
+            Some(NaiveDate::from_ymd(2022, 9, 1)),
+            Utc::now(),
+        );
+        let keys = [
+            TodoSortKey::CreatedAt,
+            TodoSortKey::UpdatedAt,
+            TodoSortKey::DueDate,
+            TodoSortKey::Title,
+        ];
+
+        for key in keys {
+            for direction in [SortDirection::Asc, SortDirection::Desc] {
+                let cursor = TodoCursor::after(&todo, TodoSort::new(key, direction));
+                assert_eq!(
+                    TodoCursor::decode(&cursor.encode()),
+                    Ok(cursor.clone()),
+                    "cursor: {cursor:?}"
+                );
+            }
+        }
+    }
+
+    #[test]
+    fn todo_cursor_decode_invalid() {
+        let tests = vec![
+            "".to_owned(),
+            "not hex".to_owned(),
+            hex::encode("title.asc"),
+            hex::encode("priority.asc.00000000000000000000000000000000."),
+            hex::encode("due_date.asc.00000000000000000000000000000000.tomorrow"),
+        ];
+
+        for input in tests {
+            assert_eq!(
+                TodoCursor::decode(&input),
+                Err(ValidationError::Invalid),
+                "input: {input}"
+            );
+        }
+    }
+}
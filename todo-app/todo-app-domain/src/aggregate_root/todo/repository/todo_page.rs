@@ -0,0 +1,40 @@
+use crate::aggregate_root::todo::{
+    entity::Todo,
+    repository::{TodoCursor, TodoQuery},
+};
+
+#[derive(Clone, Debug, Eq, PartialEq)]
+pub struct TodoPage {
+    todos: Vec<Todo>,
+    next_cursor: Option<TodoCursor>,
+}
+
+impl TodoPage {
+    /// Builds the page from up to `limit + 1` todos in query order; the extra one tells that
+    /// there is a next page.
+    pub fn new(mut todos: Vec<Todo>, query: &TodoQuery) -> Self {
+        let limit = query.limit() as usize;
+        let next_cursor = if todos.len() > limit {
+            todos.truncate(limit);
+            todos
+                .last()
+                .map(|todo| TodoCursor::after(todo, query.sort()))
+        } else {
+            None
+        };
+
+        Self { todos, next_cursor }
+    }
+
+    pub fn todos(&self) -> &[Todo] {
+        &self.todos
+    }
+
+    pub fn next_cursor(&self) -> Option<&TodoCursor> {
+        self.next_cursor.as_ref()
+    }
+
+    pub fn into_inner(self) -> (Vec<Todo>, Option<TodoCursor>) {
+        (self.todos, self.next_cursor)
+    }
+}
@@ -0,0 +1,358 @@
+use std::str::FromStr;
+
+use chrono::NaiveDate;
+
+use crate::{
+    aggregate_root::{
+        tag::value_object::TagId,
+        todo::{
+            entity::Todo,
+            repository::{TodoCursor, TodoSort},
+        },
+        todo_list::value_object::TodoListId,
+        user::value_object::UserId,
+    },
+    error::ValidationError,
+};
+
+#[derive(Clone, Copy, Debug, Eq, PartialEq)]
+pub enum TodoStatus {
+    Open,
+    Completed,
+}
+
+impl FromStr for TodoStatus {
+    type Err = ValidationError;
+
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        match s {
+            "open" => Ok(Self::Open),
+            "completed" => Ok(Self::Completed),
+            _ => Err(ValidationError::Invalid),
+        }
+    }
+}
+
+/// A page of a user's todos, filtered and sorted.
+#[derive(Clone, Debug, Eq, PartialEq)]
+pub struct TodoQuery {
+    user_id: UserId,
+    todo_list_id: Option<TodoListId>,
+    status: Option<TodoStatus>,
+    due_before: Option<NaiveDate>,
+    due_after: Option<NaiveDate>,
+    text: Option<String>,
+    tag_ids: Vec<TagId>,
+    sort: TodoSort,
+    cursor: Option<TodoCursor>,
+    limit: u32,
+}
+
+impl TodoQuery {
+    pub const DEFAULT_LIMIT: u32 = 50;
+    pub const MAX_LIMIT: u32 = 100;
+
+    pub fn new(user_id: UserId) -> Self {
+        Self {
+            user_id,
+            todo_list_id: None,
+            status: None,
+            due_before: None,
+            due_after: None,
+            text: None,
+            tag_ids: vec![],
+            sort: TodoSort::default(),
+            cursor: None,
+            limit: Self::DEFAULT_LIMIT,
+        }
+    }
+
+    pub fn with_todo_list_id(self, todo_list_id: Option<TodoListId>) -> Self {
+        Self {
+            todo_list_id,
+            ..self
+        }
+    }
+
+    pub fn with_status(self, status: Option<TodoStatus>) -> Self {
+        Self { status, ..self }
+    }
+
+    /// Keeps todos due strictly before the date.
+    pub fn with_due_before(self, due_before: Option<NaiveDate>) -> Self {
+        Self { due_before, ..self }
+    }
+
+    /// Keeps todos due strictly after the date.
+    pub fn with_due_after(self, due_after: Option<NaiveDate>) -> Self {
+        Self { due_after, ..self }
+    }
+
+    /// Keeps todos whose title or description contains the text, ignoring case.
+    pub fn with_text(self, text: Option<String>) -> Self {
+        let text = text.filter(|text| !text.is_empty());
+        Self { text, ..self }
+    }
+
+    /// Keeps todos having all the tags.
+    pub fn with_tag_ids(self, tag_ids: Vec<TagId>) -> Self {
+        Self { tag_ids, ..self }
+    }
+
+    pub fn with_sort(self, sort: TodoSort) -> Self {
+        Self { sort, ..self }
+    }
+
+    /// Starts after the cursor, which must have been created for the same sort.
+    pub fn with_cursor(self, cursor: Option<TodoCursor>) -> Result<Self, ValidationError> {
+        if matches!(&cursor, Some(cursor) if cursor.sort() != self.sort) {
+            return Err(ValidationError::Invalid);
+        }
+        Ok(Self { cursor, ..self })
+    }
+
+    pub fn with_limit(self, limit: u32) -> Result<Self, ValidationError> {
+        if !(1..=Self::MAX_LIMIT).contains(&limit) {
+            return Err(ValidationError::Invalid);
+        }
+        Ok(Self { limit, ..self })
+    }
+
+    pub fn user_id(&self) -> &UserId {
+        &self.user_id
+    }
+
+    pub fn todo_list_id(&self) -> Option<&TodoListId> {
+        self.todo_list_id.as_ref()
+    }
+
+    pub fn status(&self) -> Option<TodoStatus> {
+        self.status
+    }
+
+    pub fn due_before(&self) -> Option<NaiveDate> {
+        self.due_before
+    }
+
+    pub fn due_after(&self) -> Option<NaiveDate> {
+        self.due_after
+    }
+
+    pub fn text(&self) -> Option<&str> {
+        self.text.as_deref()
+    }
+
+    pub fn tag_ids(&self) -> &[TagId] {
+        &self.tag_ids
+    }
+
+    pub fn sort(&self) -> TodoSort {
+        self.sort
+    }
+
+    pub fn cursor(&self) -> Option<&TodoCursor> {
+        self.cursor.as_ref()
+    }
+
+    pub fn limit(&self) -> u32 {
+        self.limit
+    }
+
+    /// Tells whether the todo passes the filters and comes after the cursor; for repositories
+    /// that cannot evaluate the query themselves.
+    pub fn matches(&self, todo: &Todo) -> bool {
+        if todo.user_id() != &self.user_id {
+            return false;
+        }
+        if matches!(&self.todo_list_id, Some(todo_list_id) if todo.todo_list_id() != todo_list_id) {
+            return false;
+        }
+        match self.status {
+            Some(TodoStatus::Open) if todo.is_completed() => return false,
+            Some(TodoStatus::Completed) if !todo.is_completed() => return false,
+            _ => {}
+        }
+        if let Some(due_before) = self.due_before {
+            if !matches!(todo.due_date(), Some(due_date) if due_date < due_before) {
+                return false;
+            }
+        }
+        if let Some(due_after) = self.due_after {
+            if !matches!(todo.due_date(), Some(due_date) if due_date > due_after) {
+                return false;
+            }
+        }
+        if let Some(text) = &self.text {
+            let text = text.to_lowercase();
+            let contains = |s: &str| s.to_lowercase().contains(&text);
+            let matched = contains(todo.title().as_str())
+                || todo
+                    .description()
+                    .as_ref()
+                    .is_some_and(|description| contains(description.as_str()));
+            if !matched {
+                return false;
+            }
+        }
+        if !self.tag_ids.iter().all(|tag_id| todo.has_tag(tag_id)) {
+            return false;
+        }
+        if let Some(cursor) = &self.cursor {
+            let value = self.sort.key().value_of(todo);
+            let ordering = value
+                .partial_cmp(cursor.value())
+                .unwrap_or(std::cmp::Ordering::Equal)
+                .then_with(|| todo.id().as_uuid().cmp(cursor.id().as_uuid()));
+            if self.sort.direction().apply(ordering) != std::cmp::Ordering::Greater {
+                return false;
+            }
+        }
+
+        true
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use chrono::Utc;
+
+    use crate::aggregate_root::{
+        todo::{
+            repository::{SortDirection, TodoSortKey},
+            value_object::{TodoDescription, TodoTitle},
+        },
+        user::value_object::UserId,
+    };
+
+    use super::*;
+
+    fn tag_id(n: u128) -> TagId {
+        TagId::from(uuid::Uuid::from_u128(n))
+    }
+
+    fn todo_list_id(n: u128) -> TodoListId {
+        TodoListId::from(uuid::Uuid::from_u128(n))
+    }
+
+    fn todos(user_id: &UserId) -> Vec<Todo> {
+        let date = |day| Some(NaiveDate::from_ymd(2022, 9, day));
+        let todo = |title: &str, description: Option<&str>, due_date| {
+            Todo::new(
+                user_id.clone(),
+                todo_list_id(1),
+                TodoTitle::try_from(title.to_owned()).unwrap(),
+                description.map(|d| TodoDescription::try_from(d.to_owned()).unwrap()),
+                due_date,
+                Utc::now(),
+            )
+        };
+        let mut completed = todo("Buy milk", None, date(1));
+        completed.complete(Utc::now());
+        completed.retag(vec![tag_id(1), tag_id(2)], Utc::now());
+        let mut report = todo("Write report", Some("quarterly MILK numbers"), date(3));
+        report.retag(vec![tag_id(1)], Utc::now());
+        report.move_to(todo_list_id(2), Utc::now());
+
+        vec![
+            completed,
+            report,
+            todo("Call mom", None, None),
+            todo("Pay rent", None, date(2)),
+        ]
+    }
+
+    fn titles(query: &TodoQuery, todos: &[Todo]) -> Vec<String> {
+        let mut todos = todos
+            .iter()
+            .filter(|todo| query.matches(todo))
+            .collect::<Vec<_>>();
+        todos.sort_by(|a, b| query.sort().compare(a, b));
+        todos
+            .into_iter()
+            .map(|todo| todo.title().as_str().to_owned())
+            .collect()
+    }
+
+    #[test]
+    fn todo_query_matches() {
+        let user_id = UserId::new();
+        let todos = todos(&user_id);
+        let date = |day| Some(NaiveDate::from_ymd(2022, 9, day));
+        let by_due_date = TodoSort::new(TodoSortKey::DueDate, SortDirection::Asc);
+        let query = || TodoQuery::new(user_id.clone()).with_sort(by_due_date);
+
+        let tests = vec![
+            (
+                query(),
+                vec!["Buy milk", "Pay rent", "Write report", "Call mom"],
+            ),
+            (
+                query().with_sort(TodoSort::new(TodoSortKey::DueDate, SortDirection::Desc)),
+                vec!["Call mom", "Write report", "Pay rent", "Buy milk"],
+            ),
+            (
+                query().with_status(Some(TodoStatus::Open)),
+                vec!["Pay rent", "Write report", "Call mom"],
+            ),
+            (
+                query().with_status(Some(TodoStatus::Completed)),
+                vec!["Buy milk"],
+            ),
+            (
+                query().with_due_before(date(3)).with_due_after(date(1)),
+                vec!["Pay rent"],
+            ),
+            (
+                query().with_text(Some("milk".to_owned())),
+                vec!["Buy milk", "Write report"],
+            ),
+            (
+                query().with_tag_ids(vec![tag_id(1)]),
+                vec!["Buy milk", "Write report"],
+            ),
+            (
+                query().with_tag_ids(vec![tag_id(1), tag_id(2)]),
+                vec!["Buy milk"],
+            ),
+            (query().with_tag_ids(vec![tag_id(3)]), vec![]),
+            (
+                query().with_todo_list_id(Some(todo_list_id(1))),
+                vec!["Buy milk", "Pay rent", "Call mom"],
+            ),
+            (
+                query().with_todo_list_id(Some(todo_list_id(2))),
+                vec!["Write report"],
+            ),
+            (TodoQuery::new(UserId::new()), vec![]),
+        ];
+
+        for (query, expected) in tests {
+            assert_eq!(titles(&query, &todos), expected, "query: {query:?}");
+        }
+    }
+
+    #[test]
+    fn todo_query_cursor() {
+        let user_id = UserId::new();
+        let todos = todos(&user_id);
+        let sort = TodoSort::new(TodoSortKey::Title, SortDirection::Desc);
+        let pay_rent = todos
+            .iter()
+            .find(|todo| todo.title().as_str() == "Pay rent")
+            .unwrap();
+
+        let query = TodoQuery::new(user_id)
+            .with_sort(sort)
+            .with_cursor(Some(TodoCursor::after(pay_rent, sort)))
+            .unwrap();
+        assert_eq!(titles(&query, &todos), vec!["Call mom", "Buy milk"]);
+
+        let other_sort = TodoSort::new(TodoSortKey::Title, SortDirection::Asc);
+        assert_eq!(
+            query
+                .with_sort(other_sort)
+                .with_cursor(Some(TodoCursor::after(pay_rent, sort))),
+            Err(ValidationError::Invalid)
+        );
+    }
+}
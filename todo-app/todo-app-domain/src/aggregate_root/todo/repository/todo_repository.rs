@@ -1,23 +1,55 @@
 use std::fmt::Debug;
 
 use async_trait::async_trait;
+use chrono::{DateTime, Utc};
 use mockall::automock;
 
-use crate::aggregate_root::{
-    todo::{entity::Todo, value_object::TodoId},
-    user::value_object::UserId,
+use crate::{
+    aggregate_root::{
+        todo::{
+            entity::Todo,
+            repository::{TodoPage, TodoQuery, TodoSearchHit},
+            value_object::TodoId,
+        },
+        todo_list::value_object::TodoListId,
+        user::value_object::UserId,
+    },
+    error::RepositoryError,
 };
 
 #[async_trait]
 #[automock]
 pub trait TodoRepository: Debug + Send + Sync {
-    async fn find(&self, todo_id: &TodoId) -> Result<Option<Todo>, ()>;
+    async fn find(&self, todo_id: &TodoId) -> Result<Option<Todo>, RepositoryError>;
 
-    async fn find_by_user_id(&self, user_id: &UserId) -> Result<Vec<Todo>, ()>;
+    async fn find_page(&self, query: &TodoQuery) -> Result<TodoPage, RepositoryError>;
 
-    async fn insert(&self, todo: &Todo) -> Result<(), ()>;
+    /// Finds the user's todos containing every word of the text, most relevant first.
+    async fn search(
+        &self,
+        user_id: &UserId,
+        text: &str,
+        limit: u32,
+    ) -> Result<Vec<TodoSearchHit>, RepositoryError>;
 
-    async fn update(&self, todo: &Todo) -> Result<(), ()>;
+    async fn insert(&self, todo: &Todo) -> Result<(), RepositoryError>;
 
-    async fn delete(&self, todo_id: &TodoId) -> Result<(), ()>;
+    async fn update(&self, todo: &Todo) -> Result<(), RepositoryError>;
+
+    async fn delete(&self, todo_id: &TodoId) -> Result<(), RepositoryError>;
+
+    /// Moves every todo of a list to another one, as if by `Todo::move_to`.
+    async fn move_by_todo_list_id(
+        &self,
+        from: &TodoListId,
+        to: &TodoListId,
+        now: DateTime<Utc>,
+    ) -> Result<(), RepositoryError>;
+
+    async fn delete_by_todo_list_id(
+        &self,
+        todo_list_id: &TodoListId,
+    ) -> Result<(), RepositoryError>;
+
+    async fn delete_by_user_id(&self, user_id: &UserId) -> Result<(), RepositoryError>;
 }
@@ -0,0 +1,41 @@
+use crate::aggregate_root::todo::entity::Todo;
+
+/// A todo found by a full-text search.
+#[derive(Clone, Debug, PartialEq)]
+pub struct TodoSearchHit {
+    todo: Todo,
+    rank: f32,
+    snippet: String,
+}
+
+impl TodoSearchHit {
+    pub const HIGHLIGHT_START: &'static str = "<mark>";
+    pub const HIGHLIGHT_END: &'static str = "</mark>";
+
+    pub fn new(todo: Todo, rank: f32, snippet: String) -> Self {
+        Self {
+            todo,
+            rank,
+            snippet,
+        }
+    }
+
+    pub fn todo(&self) -> &Todo {
+        &self.todo
+    }
+
+    /// Relevance of the todo to the search; higher is better, matches in the title weigh more.
+    pub fn rank(&self) -> f32 {
+        self.rank
+    }
+
+    /// HTML-escaped excerpt of the title and description, with the matched words wrapped in
+    /// [`Self::HIGHLIGHT_START`] and [`Self::HIGHLIGHT_END`].
+    pub fn snippet(&self) -> &str {
+        &self.snippet
+    }
+
+    pub fn into_inner(self) -> (Todo, f32, String) {
+        (self.todo, self.rank, self.snippet)
+    }
+}
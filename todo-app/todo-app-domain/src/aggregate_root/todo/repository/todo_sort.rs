@@ -0,0 +1,178 @@
+use std::{cmp::Ordering, str::FromStr};
+
+use chrono::{DateTime, NaiveDate, Utc};
+
+use crate::{aggregate_root::todo::entity::Todo, error::ValidationError};
+
+#[derive(Clone, Copy, Debug, Default, Eq, PartialEq)]
+pub enum TodoSortKey {
+    #[default]
+    CreatedAt,
+    UpdatedAt,
+    DueDate,
+    Title,
+}
+
+impl TodoSortKey {
+    pub fn as_str(&self) -> &'static str {
+        match self {
+            Self::CreatedAt => "created_at",
+            Self::UpdatedAt => "updated_at",
+            Self::DueDate => "due_date",
+            Self::Title => "title",
+        }
+    }
+
+    pub fn value_of(&self, todo: &Todo) -> TodoSortValue {
+        match self {
+            Self::CreatedAt => TodoSortValue::CreatedAt(todo.created_at()),
+            Self::UpdatedAt => TodoSortValue::UpdatedAt(todo.updated_at()),
+            Self::DueDate => TodoSortValue::DueDate(todo.due_date()),
+            Self::Title => TodoSortValue::Title(todo.title().as_str().to_owned()),
+        }
+    }
+}
+
+impl FromStr for TodoSortKey {
+    type Err = ValidationError;
+
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        match s {
+            "created_at" => Ok(Self::CreatedAt),
+            "updated_at" => Ok(Self::UpdatedAt),
+            "due_date" => Ok(Self::DueDate),
+            "title" => Ok(Self::Title),
+            _ => Err(ValidationError::Invalid),
+        }
+    }
+}
+
+#[derive(Clone, Copy, Debug, Default, Eq, PartialEq)]
+pub enum SortDirection {
+    #[default]
+    Asc,
+    Desc,
+}
+
+impl SortDirection {
+    pub fn as_str(&self) -> &'static str {
+        match self {
+            Self::Asc => "asc",
+            Self::Desc => "desc",
+        }
+    }
+
+    /// Applies the direction to an ascending ordering.
+    pub fn apply(&self, ordering: Ordering) -> Ordering {
+        match self {
+            Self::Asc => ordering,
+            Self::Desc => ordering.reverse(),
+        }
+    }
+}
+
+impl FromStr for SortDirection {
+    type Err = ValidationError;
+
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        match s {
+            "asc" => Ok(Self::Asc),
+            "desc" => Ok(Self::Desc),
+            _ => Err(ValidationError::Invalid),
+        }
+    }
+}
+
+/// The order of a todo list; ties are broken by todo id in the same direction.
+#[derive(Clone, Copy, Debug, Default, Eq, PartialEq)]
+pub struct TodoSort {
+    key: TodoSortKey,
+    direction: SortDirection,
+}
+
+impl TodoSort {
+    pub fn new(key: TodoSortKey, direction: SortDirection) -> Self {
+        Self { key, direction }
+    }
+
+    pub fn key(&self) -> TodoSortKey {
+        self.key
+    }
+
+    pub fn direction(&self) -> SortDirection {
+        self.direction
+    }
+
+    pub fn compare(&self, a: &Todo, b: &Todo) -> Ordering {
+        let ordering = self
+            .key
+            .value_of(a)
+            .partial_cmp(&self.key.value_of(b))
+            .unwrap_or(Ordering::Equal)
+            .then_with(|| a.id().as_uuid().cmp(b.id().as_uuid()));
+        self.direction.apply(ordering)
+    }
+}
+
+/// The value a todo is sorted by.
+#[derive(Clone, Debug, Eq, PartialEq)]
+pub enum TodoSortValue {
+    CreatedAt(DateTime<Utc>),
+    UpdatedAt(DateTime<Utc>),
+    /// Todos without a due date sort after all others.
+    DueDate(Option<NaiveDate>),
+    Title(String),
+}
+
+impl TodoSortValue {
+    pub fn key(&self) -> TodoSortKey {
+        match self {
+            Self::CreatedAt(_) => TodoSortKey::CreatedAt,
+            Self::UpdatedAt(_) => TodoSortKey::UpdatedAt,
+            Self::DueDate(_) => TodoSortKey::DueDate,
+            Self::Title(_) => TodoSortKey::Title,
+        }
+    }
+}
+
+/// Values of different keys are not comparable.
+impl PartialOrd for TodoSortValue {
+    fn partial_cmp(&self, other: &Self) -> Option<Ordering> {
+        match (self, other) {
+            (Self::CreatedAt(a), Self::CreatedAt(b)) => a.partial_cmp(b),
+            (Self::UpdatedAt(a), Self::UpdatedAt(b)) => a.partial_cmp(b),
+            (Self::DueDate(a), Self::DueDate(b)) => (a.is_none(), a).partial_cmp(&(b.is_none(), b)),
+            (Self::Title(a), Self::Title(b)) => a.partial_cmp(b),
+            _ => None,
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn todo_sort_value_due_date_order() {
+        let date = |day| NaiveDate::from_ymd(2022, 9, day);
+        let mut values = vec![
+            TodoSortValue::DueDate(None),
+            TodoSortValue::DueDate(Some(date(2))),
+            TodoSortValue::DueDate(Some(date(1))),
+        ];
+        values.sort_by(|a, b| a.partial_cmp(b).unwrap());
+
+        assert_eq!(
+            values,
+            vec![
+                TodoSortValue::DueDate(Some(date(1))),
+                TodoSortValue::DueDate(Some(date(2))),
+                TodoSortValue::DueDate(None),
+            ]
+        );
+        assert_eq!(
+            TodoSortValue::DueDate(None).partial_cmp(&TodoSortValue::Title("".to_owned())),
+            None
+        );
+    }
+}
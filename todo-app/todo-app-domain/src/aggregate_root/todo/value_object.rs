@@ -1,5 +1,7 @@
+mod todo_description;
 mod todo_id;
 mod todo_title;
 
+pub use todo_description::TodoDescription;
 pub use todo_id::TodoId;
 pub use todo_title::TodoTitle;
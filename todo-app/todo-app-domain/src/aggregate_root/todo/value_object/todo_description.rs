@@ -0,0 +1,84 @@
+use crate::error::ValidationError;
+
+const TODO_DESCRIPTION_MAX_LENGTH: usize = 1000;
+
+#[derive(Clone, Debug, Eq, PartialEq)]
+pub struct TodoDescription(String);
+
+impl TodoDescription {
+    pub fn as_str(&self) -> &str {
+        AsRef::as_ref(self)
+    }
+
+    pub fn into_string(self) -> String {
+        Into::into(self)
+    }
+}
+
+impl AsRef<str> for TodoDescription {
+    fn as_ref(&self) -> &str {
+        &self.0
+    }
+}
+
+impl TryFrom<String> for TodoDescription {
+    type Error = ValidationError;
+
+    fn try_from(value: String) -> Result<Self, Self::Error> {
+        if value.is_empty() {
+            return Err(Self::Error::Required);
+        }
+
+        if value.len() > TODO_DESCRIPTION_MAX_LENGTH {
+            return Err(Self::Error::Length {
+                min: None,
+                max: Some(TODO_DESCRIPTION_MAX_LENGTH),
+            });
+        }
+
+        Ok(Self(value))
+    }
+}
+
+impl From<TodoDescription> for String {
+    fn from(value: TodoDescription) -> Self {
+        value.0
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::error::ValidationError;
+
+    use super::*;
+
+    #[test]
+    fn todo_description_try_from() {
+        let tests = vec![
+            ("".to_owned(), Err(ValidationError::Required)),
+            (
+                "buy milk".to_owned(),
+                Ok(TodoDescription("buy milk".to_owned())),
+            ),
+            (
+                "x".repeat(TODO_DESCRIPTION_MAX_LENGTH),
+                Ok(TodoDescription("x".repeat(TODO_DESCRIPTION_MAX_LENGTH))),
+            ),
+            (
+                "x".repeat(TODO_DESCRIPTION_MAX_LENGTH + 1),
+                Err(ValidationError::Length {
+                    min: None,
+                    max: Some(TODO_DESCRIPTION_MAX_LENGTH),
+                }),
+            ),
+        ];
+
+        for (input, expected) in tests {
+            assert_eq!(
+                TodoDescription::try_from(input.clone()),
+                expected,
+                "input: {input}"
+            );
+        }
+    }
+}
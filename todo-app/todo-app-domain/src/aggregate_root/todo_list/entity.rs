@@ -0,0 +1,3 @@
+mod todo_list;
+
+pub use todo_list::TodoList;
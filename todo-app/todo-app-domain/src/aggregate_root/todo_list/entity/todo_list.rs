@@ -0,0 +1,94 @@
+use getset::{CopyGetters, Getters};
+
+use crate::aggregate_root::{
+    todo_list::value_object::{TodoListId, TodoListName},
+    user::value_object::UserId,
+};
+
+const INBOX_NAME: &str = "Inbox";
+
+#[derive(Clone, CopyGetters, Debug, Eq, Getters, PartialEq)]
+pub struct TodoList {
+    #[getset(get = "pub")]
+    id: TodoListId,
+    #[getset(get = "pub")]
+    user_id: UserId,
+    #[getset(get = "pub")]
+    name: TodoListName,
+    #[getset(get_copy = "pub")]
+    position: i32,
+    #[getset(get_copy = "pub")]
+    archived: bool,
+    /// Every user has exactly one inbox, which can be neither archived nor deleted.
+    #[getset(get_copy = "pub")]
+    inbox: bool,
+}
+
+pub type TodoListParts = (TodoListId, UserId, TodoListName, i32, bool, bool);
+
+impl TodoList {
+    pub const MAX_POSITION: i32 = 10_000;
+
+    pub fn new(user_id: UserId, name: TodoListName, position: i32) -> Self {
+        Self {
+            id: TodoListId::new(),
+            user_id,
+            name,
+            position,
+            archived: false,
+            inbox: false,
+        }
+    }
+
+    /// Creates the list new todos go to when no other list is given.
+    pub fn new_inbox(user_id: UserId) -> Self {
+        Self {
+            inbox: true,
+            ..Self::new(
+                user_id,
+                TodoListName::try_from(INBOX_NAME.to_owned()).unwrap(),
+                0,
+            )
+        }
+    }
+
+    pub fn rename(&mut self, name: TodoListName) {
+        self.name = name;
+    }
+
+    pub fn reposition(&mut self, position: i32) {
+        self.position = position;
+    }
+
+    pub fn archive(&mut self) {
+        self.archived = true;
+    }
+
+    pub fn unarchive(&mut self) {
+        self.archived = false;
+    }
+
+    pub fn into_inner(self) -> TodoListParts {
+        (
+            self.id,
+            self.user_id,
+            self.name,
+            self.position,
+            self.archived,
+            self.inbox,
+        )
+    }
+}
+
+impl From<TodoListParts> for TodoList {
+    fn from((id, user_id, name, position, archived, inbox): TodoListParts) -> Self {
+        Self {
+            id,
+            user_id,
+            name,
+            position,
+            archived,
+            inbox,
+        }
+    }
+}
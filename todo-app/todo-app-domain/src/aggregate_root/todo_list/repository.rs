@@ -0,0 +1,3 @@
+mod todo_list_repository;
+
+pub use todo_list_repository::*;
@@ -0,0 +1,32 @@
+use std::fmt::Debug;
+
+use async_trait::async_trait;
+use mockall::automock;
+
+use crate::{
+    aggregate_root::{
+        todo_list::{entity::TodoList, value_object::TodoListId},
+        user::value_object::UserId,
+    },
+    error::RepositoryError,
+};
+
+#[async_trait]
+#[automock]
+pub trait TodoListRepository: Debug + Send + Sync {
+    async fn find(&self, todo_list_id: &TodoListId) -> Result<Option<TodoList>, RepositoryError>;
+
+    async fn find_inbox(&self, user_id: &UserId) -> Result<Option<TodoList>, RepositoryError>;
+
+    /// Returns the user's lists, archived ones included, ordered by position.
+    async fn find_by_user_id(&self, user_id: &UserId) -> Result<Vec<TodoList>, RepositoryError>;
+
+    async fn insert(&self, todo_list: &TodoList) -> Result<(), RepositoryError>;
+
+    async fn update(&self, todo_list: &TodoList) -> Result<(), RepositoryError>;
+
+    /// Fails with `RepositoryError::Conflict` while the list still has todos.
+    async fn delete(&self, todo_list_id: &TodoListId) -> Result<(), RepositoryError>;
+
+    async fn delete_by_user_id(&self, user_id: &UserId) -> Result<(), RepositoryError>;
+}
@@ -0,0 +1,5 @@
+mod todo_list_id;
+mod todo_list_name;
+
+pub use todo_list_id::TodoListId;
+pub use todo_list_name::TodoListName;
@@ -0,0 +1,36 @@
+use uuid::Uuid;
+
+#[derive(Clone, Debug, Default, Eq, Hash, Ord, PartialEq, PartialOrd)]
+pub struct TodoListId(Uuid);
+
+impl TodoListId {
+    pub fn new() -> Self {
+        Self(Uuid::new_v4())
+    }
+
+    pub fn as_uuid(&self) -> &Uuid {
+        AsRef::as_ref(self)
+    }
+
+    pub fn into_uuid(self) -> Uuid {
+        Into::into(self)
+    }
+}
+
+impl AsRef<Uuid> for TodoListId {
+    fn as_ref(&self) -> &Uuid {
+        &self.0
+    }
+}
+
+impl From<Uuid> for TodoListId {
+    fn from(value: Uuid) -> Self {
+        Self(value)
+    }
+}
+
+impl From<TodoListId> for Uuid {
+    fn from(value: TodoListId) -> Self {
+        value.0
+    }
+}
@@ -0,0 +1,81 @@
+use crate::error::ValidationError;
+
+const TODO_LIST_NAME_MAX_LENGTH: usize = 100;
+
+#[derive(Clone, Debug, Eq, PartialEq)]
+pub struct TodoListName(String);
+
+impl TodoListName {
+    pub fn as_str(&self) -> &str {
+        AsRef::as_ref(self)
+    }
+
+    pub fn into_string(self) -> String {
+        Into::into(self)
+    }
+}
+
+impl AsRef<str> for TodoListName {
+    fn as_ref(&self) -> &str {
+        &self.0
+    }
+}
+
+impl TryFrom<String> for TodoListName {
+    type Error = ValidationError;
+
+    fn try_from(value: String) -> Result<Self, Self::Error> {
+        if value.is_empty() {
+            return Err(Self::Error::Required);
+        }
+
+        if value.len() > TODO_LIST_NAME_MAX_LENGTH {
+            return Err(Self::Error::Length {
+                min: None,
+                max: Some(TODO_LIST_NAME_MAX_LENGTH),
+            });
+        }
+
+        Ok(Self(value))
+    }
+}
+
+impl From<TodoListName> for String {
+    fn from(value: TodoListName) -> Self {
+        value.0
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::error::ValidationError;
+
+    use super::*;
+
+    #[test]
+    fn todo_list_name_try_from() {
+        let tests = vec![
+            ("", Err(ValidationError::Required)),
+            ("Groceries", Ok(TodoListName("Groceries".to_owned()))),
+            (
+                "xxxxxxxxxxxxxxxxxxxxxxxxxxxxxxxxxxxxxxxxxxxxxxxxxxxxxxxxxxxxxxxxxxxxxxxxxxxxxxxxxxxxxxxxxxxxxxxxxxxx",
+                Ok(TodoListName("xxxxxxxxxxxxxxxxxxxxxxxxxxxxxxxxxxxxxxxxxxxxxxxxxxxxxxxxxxxxxxxxxxxxxxxxxxxxxxxxxxxxxxxxxxxxxxxxxxxx".to_owned())),
+            ),
+            (
+                "xxxxxxxxxxxxxxxxxxxxxxxxxxxxxxxxxxxxxxxxxxxxxxxxxxxxxxxxxxxxxxxxxxxxxxxxxxxxxxxxxxxxxxxxxxxxxxxxxxxxx",
+                Err(ValidationError::Length {
+                    min: None,
+                    max: Some(TODO_LIST_NAME_MAX_LENGTH),
+                }),
+            ),
+        ];
+
+        for (input, expected) in tests {
+            assert_eq!(
+                TodoListName::try_from(input.to_owned()),
+                expected,
+                "input: {input}"
+            );
+        }
+    }
+}
@@ -1,18 +1,21 @@
+use chrono::{DateTime, Utc};
 use getset::{Getters, Setters};
 
 use crate::aggregate_root::{
     user::value_object::UserId,
-    user_credential::value_object::{Email, Password, PasswordHash},
+    user_credential::value_object::{Email, Password, PasswordHash, PasswordHasher},
 };
 
 #[derive(Clone, Debug, Eq, Getters, PartialEq, Setters)]
 pub struct UserCredential {
     #[getset(get = "pub")]
     user_id: UserId,
-    #[getset(get = "pub", set = "pub")]
+    #[getset(get = "pub")]
     email: Email,
     #[getset(get = "pub", set = "pub")]
     password_hash: PasswordHash,
+    #[getset(get = "pub")]
+    email_verified_at: Option<DateTime<Utc>>,
 }
 
 impl UserCredential {
@@ -21,24 +24,94 @@ impl UserCredential {
             user_id,
             email,
             password_hash,
+            email_verified_at: None,
+        }
+    }
+
+    pub fn set_password(&mut self, password: Password, password_hasher: &PasswordHasher) {
+        self.password_hash = password.to_hash(password_hasher)
+    }
+
+    /// Changes the email address, which then needs to be verified again.
+    pub fn set_email(&mut self, email: Email) {
+        if email != self.email {
+            self.email = email;
+            self.email_verified_at = None;
         }
     }
 
-    pub fn set_password(&mut self, password: Password) {
-        self.password_hash = password.to_hash()
+    pub fn is_email_verified(&self) -> bool {
+        self.email_verified_at.is_some()
+    }
+
+    pub fn verify_email(&mut self, now: DateTime<Utc>) {
+        self.email_verified_at.get_or_insert(now);
     }
 
-    pub fn into_inner(self) -> (UserId, Email, PasswordHash) {
-        (self.user_id, self.email, self.password_hash)
+    pub fn into_inner(self) -> (UserId, Email, PasswordHash, Option<DateTime<Utc>>) {
+        (
+            self.user_id,
+            self.email,
+            self.password_hash,
+            self.email_verified_at,
+        )
     }
 }
 
-impl From<(UserId, Email, PasswordHash)> for UserCredential {
-    fn from((user_id, email, password_hash): (UserId, Email, PasswordHash)) -> Self {
+impl From<(UserId, Email, PasswordHash, Option<DateTime<Utc>>)> for UserCredential {
+    fn from(
+        (user_id, email, password_hash, email_verified_at): (
+            UserId,
+            Email,
+            PasswordHash,
+            Option<DateTime<Utc>>,
+        ),
+    ) -> Self {
         Self {
             user_id,
             email,
             password_hash,
+            email_verified_at,
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn email(value: &str) -> Email {
+        Email::try_from(value.to_owned()).unwrap()
+    }
+
+    #[test]
+    fn user_credential_set_email_test() {
+        let now = Utc::now();
+        let password_hash = Password::try_from("password".to_owned())
+            .unwrap()
+            .to_hash(&PasswordHasher::default());
+
+        let tests = vec![
+            ("user@example.com", true),
+            ("USER@example.com", false),
+            ("other@example.com", false),
+        ];
+
+        for (input, expected) in tests {
+            let mut user_credential = UserCredential::new(
+                UserId::new(),
+                email("user@example.com"),
+                password_hash.clone(),
+            );
+            user_credential.verify_email(now);
+            user_credential.set_email(email(input));
+
+            assert_eq!(user_credential.email(), &email(input), "input: {input}");
+            assert_eq!(
+                user_credential.is_email_verified(),
+                expected,
+                "input: {input}"
+            );
         }
     }
 }
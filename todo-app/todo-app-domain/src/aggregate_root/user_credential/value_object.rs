@@ -1,7 +1,9 @@
 mod email;
 mod password;
 mod password_hash;
+mod password_hasher;
 
 pub use email::Email;
 pub use password::Password;
 pub use password_hash::PasswordHash;
+pub use password_hasher::PasswordHasher;
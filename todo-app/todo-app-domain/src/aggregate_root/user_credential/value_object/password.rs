@@ -1,6 +1,9 @@
 use regex::Regex;
 
-use crate::{aggregate_root::user_credential::value_object::PasswordHash, error::ValidationError};
+use crate::{
+    aggregate_root::user_credential::value_object::{PasswordHash, PasswordHasher},
+    error::ValidationError,
+};
 
 #[derive(Clone, Eq, PartialEq)]
 pub struct Password(String);
@@ -14,8 +17,8 @@ impl Password {
         Into::into(self)
     }
 
-    pub fn to_hash(&self) -> PasswordHash {
-        PasswordHash::new(self)
+    pub fn to_hash(&self, password_hasher: &PasswordHasher) -> PasswordHash {
+        password_hasher.hash(self)
     }
 }
 
@@ -66,7 +69,7 @@ mod tests {
     fn password_try_from_test() {
         let tests = vec![
             ("", Err(ValidationError::Required)),
-            ("passwor", Err(ValidationError::Required)),
+            ("passwor", Err(ValidationError::Password)),
             (
                 "abcdefghijklmnopqrstuvwxyz",
                 Ok(password("abcdefghijklmnopqrstuvwxyz")),
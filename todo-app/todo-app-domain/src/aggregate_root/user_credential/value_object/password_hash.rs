@@ -1,26 +1,14 @@
 use argon2::{
-    password_hash::{
-        rand_core::OsRng, PasswordHash as Argon2PasswordHash, PasswordHasher, PasswordVerifier,
-        SaltString,
-    },
+    password_hash::{PasswordHash as Argon2PasswordHash, PasswordVerifier},
     Argon2,
 };
 
-use crate::{aggregate_root::user_credential::value_object::Password, error::ValidationError};
+use crate::error::ValidationError;
 
 #[derive(Clone, Eq, PartialEq)]
 pub struct PasswordHash(String);
 
 impl PasswordHash {
-    pub fn new(password: &Password) -> Self {
-        let salt = SaltString::generate(&mut OsRng);
-        let password_hash = Argon2::default()
-            .hash_password(password.as_str().as_bytes(), &salt)
-            .unwrap()
-            .to_string();
-        Self(password_hash)
-    }
-
     pub fn as_str(&self) -> &str {
         AsRef::as_ref(self)
     }
@@ -53,10 +41,7 @@ impl TryFrom<String> for PasswordHash {
     type Error = ValidationError;
 
     fn try_from(value: String) -> Result<Self, Self::Error> {
-        let salt = SaltString::generate(&mut OsRng);
-        Argon2::default()
-            .hash_password(value.as_bytes(), &salt)
-            .map_err(Self::Error::PasswordHash)?;
+        Argon2PasswordHash::new(&value).map_err(Self::Error::PasswordHash)?;
         Ok(Self(value))
     }
 }
@@ -70,6 +55,7 @@ impl From<PasswordHash> for String {
 #[cfg(test)]
 mod tests {
     use super::*;
+    use crate::aggregate_root::user_credential::value_object::{Password, PasswordHasher};
 
     #[test]
     fn password_hash_from_str() {
@@ -87,6 +73,19 @@ mod tests {
         }
     }
 
+    #[test]
+    fn password_hash_from_invalid_str() {
+        let tests = vec!["", "password", "$argon2id$v=19$m=4096,t=3,p=1$", "$$$"];
+
+        for input in tests {
+            let result = PasswordHash::try_from(input.to_owned());
+            assert!(
+                matches!(result, Err(ValidationError::PasswordHash(_))),
+                "input: {input}, result: {result:?}",
+            );
+        }
+    }
+
     #[test]
     fn password_hash_test() {
         let tests = vec![
@@ -99,7 +98,7 @@ mod tests {
 
         for input in tests {
             let password = Password::try_from(input.to_owned()).unwrap();
-            let password_hash = password.to_hash();
+            let password_hash = password.to_hash(&PasswordHasher::default());
             assert!(
                 password_hash.verify(password.as_str()),
                 "input: {input}, password: {password:?}, password_hash: {password_hash:?}",
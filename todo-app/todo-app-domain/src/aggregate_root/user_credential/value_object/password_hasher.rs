@@ -0,0 +1,98 @@
+use std::sync::{Arc, OnceLock};
+
+use argon2::{
+    password_hash::{rand_core::OsRng, PasswordHasher as _, SaltString},
+    Algorithm, Argon2, Params, Version,
+};
+
+use crate::{
+    aggregate_root::user_credential::value_object::{Password, PasswordHash},
+    error::ValidationError,
+};
+
+/// Hashes new passwords with the argon2 cost parameters it is created with.
+#[derive(Clone, Debug, Default)]
+pub struct PasswordHasher {
+    params: Params,
+    dummy: Arc<OnceLock<PasswordHash>>,
+}
+
+impl PasswordHasher {
+    /// See the `argon2` crate for the meaning of the parameters.
+    pub fn new(
+        memory_cost: u32,
+        time_cost: u32,
+        parallelism: u32,
+    ) -> Result<Self, ValidationError> {
+        let params = Params::new(memory_cost, time_cost, parallelism, None)
+            .map_err(|_| ValidationError::Invalid)?;
+        Ok(Self {
+            params,
+            dummy: Default::default(),
+        })
+    }
+
+    pub fn hash(&self, password: &Password) -> PasswordHash {
+        let salt = SaltString::generate(&mut OsRng);
+        let password_hash = Argon2::new(
+            Algorithm::default(),
+            Version::default(),
+            self.params.clone(),
+        )
+        .hash_password(password.as_str().as_bytes(), &salt)
+        .unwrap()
+        .to_string();
+        PasswordHash::try_from(password_hash).unwrap()
+    }
+
+    /// A hash of a fixed password with the same cost, to verify against when there is no real
+    /// hash, so that the check takes as long either way.
+    pub fn dummy_hash(&self) -> &PasswordHash {
+        self.dummy.get_or_init(|| {
+            let password = Password::try_from("dummy-password".to_owned()).unwrap();
+            self.hash(&password)
+        })
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn password_hasher_new() {
+        let tests = vec![
+            ((4096, 3, 1), true),
+            ((19456, 2, 1), true),
+            ((1, 3, 1), false),
+            ((4096, 0, 1), false),
+            ((4096, 3, 0), false),
+        ];
+
+        for ((memory_cost, time_cost, parallelism), expected) in tests {
+            let result = PasswordHasher::new(memory_cost, time_cost, parallelism);
+            assert_eq!(
+                result.is_ok(),
+                expected,
+                "input: {:?}, result: {result:?}",
+                (memory_cost, time_cost, parallelism)
+            );
+        }
+    }
+
+    #[test]
+    fn password_hasher_hash() {
+        let password_hasher = PasswordHasher::new(8, 1, 1).unwrap();
+        let password = Password::try_from("5626739865258883".to_owned()).unwrap();
+
+        let password_hash = password_hasher.hash(&password);
+
+        assert!(password_hash.as_str().contains("m=8,t=1,p=1"));
+        assert!(password_hash.verify(password.as_str()));
+        assert!(!password_hasher.dummy_hash().verify(password.as_str()));
+        assert!(password_hasher
+            .dummy_hash()
+            .as_str()
+            .contains("m=8,t=1,p=1"));
+    }
+}
@@ -1,3 +1,7 @@
+mod domain_error;
+mod repository_error;
 mod validation_error;
 
+pub use domain_error::*;
+pub use repository_error::*;
 pub use validation_error::*;
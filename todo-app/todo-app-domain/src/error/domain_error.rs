@@ -0,0 +1,9 @@
+use thiserror::Error;
+
+#[derive(Clone, Debug, Error, Eq, PartialEq)]
+pub enum DomainError {
+    #[error("email already taken")]
+    EmailAlreadyTaken,
+    #[error("tag name already taken")]
+    TagNameAlreadyTaken,
+}
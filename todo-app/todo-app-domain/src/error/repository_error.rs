@@ -0,0 +1,21 @@
+use thiserror::Error;
+
+use crate::error::DomainError;
+
+#[derive(Debug, Error)]
+pub enum RepositoryError {
+    #[error("entity not found")]
+    NotFound,
+    #[error(transparent)]
+    Domain(#[from] DomainError),
+    /// A uniqueness or reference constraint was violated.
+    #[error("conflict: {0}")]
+    Conflict(String),
+    /// The operation lost a race against a concurrent transaction and may be retried.
+    #[error("concurrent modification")]
+    ConcurrentModification,
+    #[error("storage unavailable: {0}")]
+    Unavailable(#[source] anyhow::Error),
+    #[error(transparent)]
+    Unexpected(#[from] anyhow::Error),
+}
@@ -16,6 +16,20 @@ pub enum ValidationError {
     Invalid,
 }
 
+impl ValidationError {
+    /// Returns a machine-readable identifier of the error kind.
+    pub fn code(&self) -> &'static str {
+        match self {
+            Self::Required => "required",
+            Self::Length { .. } => "length",
+            Self::Email => "email",
+            Self::Password => "password",
+            Self::PasswordHash(_) => "password_hash",
+            Self::Invalid => "invalid",
+        }
+    }
+}
+
 #[derive(Debug, Default, Error)]
 #[error("validation error")]
 pub struct ValidationErrors(HashMap<&'static str, ValidationError>);
@@ -1,2 +1,3 @@
 pub mod aggregate_root;
 pub mod error;
+pub mod value_object;
@@ -0,0 +1,5 @@
+mod secret_token;
+mod secret_token_hash;
+
+pub use secret_token::SecretToken;
+pub use secret_token_hash::SecretTokenHash;
@@ -0,0 +1,102 @@
+use rand_core::{OsRng, RngCore};
+use sha2::{Digest, Sha256};
+
+use crate::{error::ValidationError, value_object::SecretTokenHash};
+
+const SECRET_TOKEN_BYTES: usize = 32;
+
+/// A random token handed to a user out of band, e.g. by mail.
+///
+/// Only its hash is stored, so a leaked database does not reveal usable tokens.
+#[derive(Clone, Eq, PartialEq)]
+pub struct SecretToken(String);
+
+impl SecretToken {
+    pub fn generate() -> Self {
+        let mut bytes = [0u8; SECRET_TOKEN_BYTES];
+        OsRng.fill_bytes(&mut bytes);
+        Self(hex::encode(bytes))
+    }
+
+    pub fn as_str(&self) -> &str {
+        AsRef::as_ref(self)
+    }
+
+    pub fn into_string(self) -> String {
+        Into::into(self)
+    }
+
+    pub fn to_hash(&self) -> SecretTokenHash {
+        let digest = Sha256::digest(self.0.as_bytes());
+        SecretTokenHash::try_from(hex::encode(digest)).unwrap()
+    }
+}
+
+impl AsRef<str> for SecretToken {
+    fn as_ref(&self) -> &str {
+        &self.0
+    }
+}
+
+impl std::fmt::Debug for SecretToken {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        f.debug_tuple("SecretToken").field(&"********").finish()
+    }
+}
+
+impl TryFrom<String> for SecretToken {
+    type Error = ValidationError;
+
+    fn try_from(value: String) -> Result<Self, Self::Error> {
+        if value.is_empty() {
+            return Err(Self::Error::Required);
+        }
+
+        if value.len() != SECRET_TOKEN_BYTES * 2 || !value.bytes().all(|b| b.is_ascii_hexdigit()) {
+            return Err(Self::Error::Invalid);
+        }
+
+        Ok(Self(value.to_ascii_lowercase()))
+    }
+}
+
+impl From<SecretToken> for String {
+    fn from(value: SecretToken) -> Self {
+        value.0
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn secret_token_try_from_test() {
+        let token = SecretToken::generate();
+
+        let tests = vec![
+            ("".to_owned(), Err(ValidationError::Required)),
+            ("invalid".to_owned(), Err(ValidationError::Invalid)),
+            ("g".repeat(64), Err(ValidationError::Invalid)),
+            (token.as_str().to_owned(), Ok(token.clone())),
+            (token.as_str().to_ascii_uppercase(), Ok(token.clone())),
+        ];
+
+        for (input, expected) in tests {
+            assert_eq!(
+                SecretToken::try_from(input.clone()),
+                expected,
+                "input: `{input}`"
+            )
+        }
+    }
+
+    #[test]
+    fn secret_token_to_hash_test() {
+        let token = SecretToken::generate();
+
+        assert_eq!(token.to_hash(), token.clone().to_hash());
+        assert_ne!(token.to_hash(), SecretToken::generate().to_hash());
+        assert_ne!(token.to_hash().as_str(), token.as_str());
+    }
+}
@@ -0,0 +1,45 @@
+use crate::error::ValidationError;
+
+const SECRET_TOKEN_HASH_LENGTH: usize = 64;
+
+/// The SHA-256 digest of a `SecretToken`, hex-encoded.
+#[derive(Clone, Debug, Eq, Hash, PartialEq)]
+pub struct SecretTokenHash(String);
+
+impl SecretTokenHash {
+    pub fn as_str(&self) -> &str {
+        AsRef::as_ref(self)
+    }
+
+    pub fn into_string(self) -> String {
+        Into::into(self)
+    }
+}
+
+impl AsRef<str> for SecretTokenHash {
+    fn as_ref(&self) -> &str {
+        &self.0
+    }
+}
+
+impl TryFrom<String> for SecretTokenHash {
+    type Error = ValidationError;
+
+    fn try_from(value: String) -> Result<Self, Self::Error> {
+        if value.len() != SECRET_TOKEN_HASH_LENGTH
+            || !value
+                .bytes()
+                .all(|b| b.is_ascii_digit() || (b'a'..=b'f').contains(&b))
+        {
+            return Err(Self::Error::Invalid);
+        }
+
+        Ok(Self(value))
+    }
+}
+
+impl From<SecretTokenHash> for String {
+    fn from(value: SecretTokenHash) -> Self {
+        value.0
+    }
+}
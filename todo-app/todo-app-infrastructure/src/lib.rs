@@ -1,2 +1,7 @@
+pub mod mail;
+pub mod memory;
 pub mod postgres;
 pub mod redis;
+
+#[cfg(test)]
+mod session_store_contract;
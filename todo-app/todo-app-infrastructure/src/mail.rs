@@ -0,0 +1,5 @@
+mod file_mailer;
+mod log_mailer;
+
+pub use file_mailer::FileMailer;
+pub use log_mailer::LogMailer;
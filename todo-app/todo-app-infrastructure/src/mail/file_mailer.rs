@@ -0,0 +1,71 @@
+use std::path::PathBuf;
+
+use async_trait::async_trait;
+use chrono::Utc;
+use tokio::{fs::OpenOptions, io::AsyncWriteExt, sync::Mutex};
+
+use todo_app_application::mail::{MailMessage, Mailer};
+
+/// Appends mail to a local file instead of delivering it, for local development.
+#[derive(Debug)]
+pub struct FileMailer {
+    path: PathBuf,
+    lock: Mutex<()>,
+}
+
+impl FileMailer {
+    pub fn new(path: PathBuf) -> Self {
+        Self {
+            path,
+            lock: Default::default(),
+        }
+    }
+}
+
+#[async_trait]
+impl Mailer for FileMailer {
+    async fn send(&self, message: &MailMessage) -> Result<(), anyhow::Error> {
+        let content = format!(
+            "Date: {}\nTo: {}\nSubject: {}\n\n{}\n\n",
+            Utc::now().to_rfc2822(),
+            message.to().as_str(),
+            message.subject(),
+            message.body()
+        );
+
+        let _lock = self.lock.lock().await;
+        let mut file = OpenOptions::new()
+            .create(true)
+            .append(true)
+            .open(&self.path)
+            .await?;
+        file.write_all(content.as_bytes()).await?;
+
+        Ok(())
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use todo_app_domain::aggregate_root::user_credential::value_object::Email;
+
+    use super::*;
+
+    #[tokio::test]
+    async fn file_mailer_send() {
+        let path = std::env::temp_dir().join(format!("file_mailer_{}.txt", uuid::Uuid::new_v4()));
+        let mailer = FileMailer::new(path.clone());
+        let to = Email::try_from("user@example.com".to_owned()).unwrap();
+
+        for body in ["first body", "second body"] {
+            let message = MailMessage::new(to.clone(), "subject".to_owned(), body.to_owned());
+            mailer.send(&message).await.unwrap();
+        }
+
+        let content = tokio::fs::read_to_string(&path).await.unwrap();
+        tokio::fs::remove_file(&path).await.unwrap();
+        assert_eq!(content.matches("To: user@example.com\n").count(), 2);
+        assert!(content.contains("Subject: subject\n\nfirst body\n"));
+        assert!(content.contains("Subject: subject\n\nsecond body\n"));
+    }
+}
@@ -0,0 +1,27 @@
+use async_trait::async_trait;
+use todo_app_application::mail::{MailMessage, Mailer};
+
+/// Writes mail to the log instead of delivering it, for local development.
+///
+/// Mail may carry secrets such as password reset tokens; do not use it in production.
+#[derive(Debug, Default)]
+pub struct LogMailer;
+
+impl LogMailer {
+    pub fn new() -> Self {
+        Self
+    }
+}
+
+#[async_trait]
+impl Mailer for LogMailer {
+    async fn send(&self, message: &MailMessage) -> Result<(), anyhow::Error> {
+        tracing::info!(
+            to = message.to().as_str(),
+            subject = message.subject().as_str(),
+            "mail:\n{}",
+            message.body()
+        );
+        Ok(())
+    }
+}
@@ -0,0 +1,5 @@
+pub mod database;
+pub mod mail;
+pub mod repository;
+pub mod session;
+pub mod throttle;
@@ -0,0 +1,7 @@
+mod memory_db;
+mod memory_storage;
+mod memory_transaction;
+
+pub use memory_db::MemoryDB;
+pub use memory_storage::MemoryStorage;
+pub use memory_transaction::MemoryTransaction;
@@ -0,0 +1,300 @@
+use std::sync::{Arc, Mutex};
+
+use async_trait::async_trait;
+
+use todo_app_application::database::{IsolationLevel, Repositories, Transaction, DB};
+use todo_app_domain::{
+    aggregate_root::{
+        email_verification_token::repository::EmailVerificationTokenRepository,
+        password_reset_token::repository::PasswordResetTokenRepository,
+        tag::repository::TagRepository, todo::repository::TodoRepository,
+        todo_list::repository::TodoListRepository, user::repository::UserRepository,
+        user_credential::repository::UserCredentialRepository,
+    },
+    error::RepositoryError,
+};
+
+use crate::memory::{
+    database::{MemoryStorage, MemoryTransaction},
+    repository::{
+        MemoryEmailVerificationTokenRepository, MemoryPasswordResetTokenRepository,
+        MemoryTagRepository, MemoryTodoListRepository, MemoryTodoRepository,
+        MemoryUserCredentialRepository, MemoryUserRepository,
+    },
+};
+
+#[derive(Clone, Debug, Default)]
+pub struct MemoryDB {
+    storage: Arc<Mutex<MemoryStorage>>,
+}
+
+impl MemoryDB {
+    pub fn new() -> Self {
+        Default::default()
+    }
+}
+
+impl Repositories for MemoryDB {
+    fn user_repository(&self) -> Arc<dyn UserRepository> {
+        Arc::new(MemoryUserRepository::new(self.storage.clone()))
+    }
+
+    fn user_credential_repository(&self) -> Arc<dyn UserCredentialRepository> {
+        Arc::new(MemoryUserCredentialRepository::new(self.storage.clone()))
+    }
+
+    fn todo_list_repository(&self) -> Arc<dyn TodoListRepository> {
+        Arc::new(MemoryTodoListRepository::new(self.storage.clone()))
+    }
+
+    fn todo_repository(&self) -> Arc<dyn TodoRepository> {
+        Arc::new(MemoryTodoRepository::new(self.storage.clone()))
+    }
+
+    fn tag_repository(&self) -> Arc<dyn TagRepository> {
+        Arc::new(MemoryTagRepository::new(self.storage.clone()))
+    }
+
+    fn password_reset_token_repository(&self) -> Arc<dyn PasswordResetTokenRepository> {
+        Arc::new(MemoryPasswordResetTokenRepository::new(
+            self.storage.clone(),
+        ))
+    }
+
+    fn email_verification_token_repository(&self) -> Arc<dyn EmailVerificationTokenRepository> {
+        Arc::new(MemoryEmailVerificationTokenRepository::new(
+            self.storage.clone(),
+        ))
+    }
+}
+
+#[async_trait]
+impl DB for MemoryDB {
+    /// Every isolation level behaves as described on `MemoryTransaction`.
+    async fn begin_with(
+        &self,
+        _isolation_level: IsolationLevel,
+    ) -> Result<Box<dyn Transaction>, RepositoryError> {
+        Ok(Box::new(MemoryTransaction::new(self.storage.clone())))
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use std::{
+        sync::atomic::{AtomicU32, Ordering},
+        time::Duration,
+    };
+
+    use todo_app_application::database::{in_transaction, RetryPolicy, UnitOfWork};
+    use todo_app_domain::aggregate_root::user::{entity::User, value_object::UserName};
+
+    use super::*;
+
+    fn user() -> User {
+        User::new(UserName::try_from("user name".to_owned()).unwrap())
+    }
+
+    #[tokio::test]
+    async fn memory_db_commit() {
+        let db = MemoryDB::new();
+        let user = user();
+
+        let tx = db.begin().await.unwrap();
+        tx.user_repository().insert(&user).await.unwrap();
+        assert_eq!(db.user_repository().find(user.id()).await.unwrap(), None);
+
+        tx.commit().await.unwrap();
+        assert_eq!(
+            db.user_repository().find(user.id()).await.unwrap(),
+            Some(user)
+        );
+    }
+
+    #[tokio::test]
+    async fn memory_db_rollback() {
+        let db = MemoryDB::new();
+        let user = user();
+
+        let tx = db.begin().await.unwrap();
+        tx.user_repository().insert(&user).await.unwrap();
+        assert_eq!(
+            tx.user_repository().find(user.id()).await.unwrap(),
+            Some(user.clone())
+        );
+
+        tx.rollback().await.unwrap();
+        assert_eq!(db.user_repository().find(user.id()).await.unwrap(), None);
+    }
+
+    #[tokio::test]
+    async fn memory_db_commit_keeps_concurrent_changes() {
+        let db = MemoryDB::new();
+        let (user1, user2) = (user(), user());
+
+        let tx1 = db.begin().await.unwrap();
+        let tx2 = db.begin().await.unwrap();
+        tx1.user_repository().insert(&user1).await.unwrap();
+        tx2.user_repository().insert(&user2).await.unwrap();
+        tx1.commit().await.unwrap();
+        tx2.commit().await.unwrap();
+
+        assert_eq!(
+            db.user_repository().find(user1.id()).await.unwrap(),
+            Some(user1)
+        );
+        assert_eq!(
+            db.user_repository().find(user2.id()).await.unwrap(),
+            Some(user2)
+        );
+    }
+
+    #[tokio::test]
+    async fn memory_db_commit_conflict() {
+        let db = MemoryDB::new();
+        let user = user();
+        db.user_repository().insert(&user).await.unwrap();
+        let other = self::user();
+
+        let tx1 = db.begin().await.unwrap();
+        let tx2 = db.begin().await.unwrap();
+        let mut user1 = user.clone();
+        user1.set_name(UserName::try_from("name 1".to_owned()).unwrap());
+        tx1.user_repository().update(&user1).await.unwrap();
+        let mut user2 = user.clone();
+        user2.set_name(UserName::try_from("name 2".to_owned()).unwrap());
+        tx2.user_repository().update(&user2).await.unwrap();
+        tx2.user_repository().insert(&other).await.unwrap();
+        tx1.commit().await.unwrap();
+
+        let result = tx2.commit().await;
+        assert!(
+            matches!(result, Err(RepositoryError::ConcurrentModification)),
+            "{result:?}"
+        );
+        assert_eq!(
+            db.user_repository().find(user.id()).await.unwrap(),
+            Some(user1)
+        );
+        assert_eq!(db.user_repository().find(other.id()).await.unwrap(), None);
+    }
+
+    #[tokio::test]
+    async fn memory_db_in_transaction() {
+        let db = MemoryDB::new();
+        let user = user();
+
+        let tests = vec![(false, None), (true, Some(user.clone()))];
+        for (ok, expected) in tests {
+            let u = user.clone();
+            let result = in_transaction(&db, move |tx| {
+                Box::pin(async move {
+                    tx.user_repository().insert(&u).await?;
+                    if ok {
+                        Ok(())
+                    } else {
+                        Err(RepositoryError::ConcurrentModification)
+                    }
+                })
+            })
+            .await;
+
+            assert_eq!(result.is_ok(), ok, "ok: {ok}");
+            assert_eq!(
+                db.user_repository().find(user.id()).await.unwrap(),
+                expected,
+                "ok: {ok}"
+            );
+        }
+    }
+
+    #[tokio::test]
+    async fn memory_db_unit_of_work() {
+        let retry_policy = RetryPolicy::new(3, Duration::from_millis(1), Duration::from_millis(1));
+        let unit_of_work = UnitOfWork::new(IsolationLevel::Serializable, retry_policy);
+
+        fn concurrent_modification() -> RepositoryError {
+            RepositoryError::ConcurrentModification
+        }
+        fn conflict() -> RepositoryError {
+            RepositoryError::Conflict("conflict".to_owned())
+        }
+
+        // (error of the failing attempts, failing attempts, expected attempts, expected ok)
+        let tests = vec![
+            (concurrent_modification as fn() -> _, 0, 1, true),
+            (concurrent_modification, 2, 3, true),
+            (concurrent_modification, 3, 3, false),
+            (conflict, 1, 1, false),
+        ];
+        for (error, failures, expected_attempts, expected_ok) in tests {
+            let db = MemoryDB::new();
+            let user = user();
+            let attempts = AtomicU32::new(0);
+
+            let result = unit_of_work
+                .run(&db, |tx| {
+                    let attempt = attempts.fetch_add(1, Ordering::SeqCst) + 1;
+                    let user = user.clone();
+                    Box::pin(async move {
+                        tx.user_repository().insert(&user).await?;
+                        if attempt <= failures {
+                            return Err(error());
+                        }
+                        Ok(())
+                    })
+                })
+                .await;
+
+            let message = format!("failures: {failures}, result: {result:?}");
+            assert_eq!(result.is_ok(), expected_ok, "{message}");
+            assert_eq!(attempts.into_inner(), expected_attempts, "{message}");
+            assert_eq!(
+                db.user_repository()
+                    .find(user.id())
+                    .await
+                    .unwrap()
+                    .is_some(),
+                expected_ok,
+                "{message}"
+            );
+        }
+    }
+
+    #[tokio::test]
+    async fn memory_db_unit_of_work_retries_concurrent_transaction() {
+        let retry_policy = RetryPolicy::new(3, Duration::from_millis(1), Duration::from_millis(1));
+        let unit_of_work = UnitOfWork::new(IsolationLevel::RepeatableRead, retry_policy);
+        let db = MemoryDB::new();
+        let user = user();
+        db.user_repository().insert(&user).await.unwrap();
+        let attempts = AtomicU32::new(0);
+
+        let result = unit_of_work
+            .run(&db, |tx| {
+                let attempt = attempts.fetch_add(1, Ordering::SeqCst) + 1;
+                let db = db.clone();
+                let user_id = user.id().clone();
+                Box::pin(async move {
+                    let mut user = tx.user_repository().find(&user_id).await?.unwrap();
+                    if attempt == 1 {
+                        // Another transaction renames the user after it has been read.
+                        let other = db.begin().await?;
+                        let mut renamed = user.clone();
+                        renamed.set_name(UserName::try_from("renamed".to_owned()).unwrap());
+                        other.user_repository().update(&renamed).await?;
+                        other.commit().await?;
+                    }
+                    let name = format!("{} ({attempt})", user.name().as_str());
+                    user.set_name(UserName::try_from(name).unwrap());
+                    tx.user_repository().update(&user).await
+                })
+            })
+            .await;
+
+        assert!(result.is_ok(), "{result:?}");
+        assert_eq!(attempts.into_inner(), 2);
+        let user = db.user_repository().find(user.id()).await.unwrap().unwrap();
+        assert_eq!(user.name().as_str(), "renamed (2)");
+    }
+}
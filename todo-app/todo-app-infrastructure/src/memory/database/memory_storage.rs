@@ -0,0 +1,23 @@
+use std::collections::HashMap;
+
+use todo_app_domain::{
+    aggregate_root::{
+        email_verification_token::entity::EmailVerificationToken,
+        password_reset_token::entity::PasswordResetToken, tag::entity::Tag, todo::entity::Todo,
+        todo_list::entity::TodoList, user::entity::User, user_credential::entity::UserCredential,
+    },
+    value_object::SecretTokenHash,
+};
+use uuid::Uuid;
+
+/// In-memory counterpart of the database tables, keyed by primary key.
+#[derive(Clone, Debug, Default)]
+pub struct MemoryStorage {
+    pub(crate) users: HashMap<Uuid, User>,
+    pub(crate) user_credentials: HashMap<Uuid, UserCredential>,
+    pub(crate) todo_lists: HashMap<Uuid, TodoList>,
+    pub(crate) todos: HashMap<Uuid, Todo>,
+    pub(crate) tags: HashMap<Uuid, Tag>,
+    pub(crate) password_reset_tokens: HashMap<SecretTokenHash, PasswordResetToken>,
+    pub(crate) email_verification_tokens: HashMap<SecretTokenHash, EmailVerificationToken>,
+}
@@ -0,0 +1,178 @@
+use std::{
+    collections::HashMap,
+    hash::Hash,
+    sync::{Arc, Mutex},
+};
+
+use async_trait::async_trait;
+
+use todo_app_application::database::{Repositories, Transaction};
+use todo_app_domain::{
+    aggregate_root::{
+        email_verification_token::repository::EmailVerificationTokenRepository,
+        password_reset_token::repository::PasswordResetTokenRepository,
+        tag::repository::TagRepository, todo::repository::TodoRepository,
+        todo_list::repository::TodoListRepository, user::repository::UserRepository,
+        user_credential::repository::UserCredentialRepository,
+    },
+    error::RepositoryError,
+};
+
+use crate::memory::{
+    database::MemoryStorage,
+    repository::{
+        MemoryEmailVerificationTokenRepository, MemoryPasswordResetTokenRepository,
+        MemoryTagRepository, MemoryTodoListRepository, MemoryTodoRepository,
+        MemoryUserCredentialRepository, MemoryUserRepository,
+    },
+};
+
+/// Works on a private copy of the storage, taken when the transaction begins.
+///
+/// On commit only the rows the transaction inserted, updated or deleted are written back.
+/// If another transaction has committed a change to any of those rows in the meantime,
+/// nothing is written and the commit fails with `RepositoryError::ConcurrentModification`,
+/// like a write conflict under repeatable read in Postgres.
+#[derive(Debug)]
+pub struct MemoryTransaction {
+    storage: Arc<Mutex<MemoryStorage>>,
+    snapshot: MemoryStorage,
+    tx: Arc<Mutex<MemoryStorage>>,
+}
+
+impl MemoryTransaction {
+    pub fn new(storage: Arc<Mutex<MemoryStorage>>) -> Self {
+        let snapshot = storage.lock().unwrap().clone();
+        Self {
+            storage,
+            tx: Arc::new(Mutex::new(snapshot.clone())),
+            snapshot,
+        }
+    }
+}
+
+/// Rows of a table the transaction has written, with `None` for deleted rows.
+struct Changes<K, V>(HashMap<K, Option<V>>);
+
+impl<K, V> Changes<K, V>
+where
+    K: Clone + Eq + Hash,
+    V: Clone + PartialEq,
+{
+    fn new(snapshot: &HashMap<K, V>, tx: &HashMap<K, V>) -> Self {
+        let upserted = tx
+            .iter()
+            .filter(|(key, value)| snapshot.get(key) != Some(value))
+            .map(|(key, value)| (key.clone(), Some(value.clone())));
+        let deleted = snapshot
+            .keys()
+            .filter(|key| !tx.contains_key(key))
+            .map(|key| (key.clone(), None));
+
+        Self(upserted.chain(deleted).collect())
+    }
+
+    fn check(
+        &self,
+        snapshot: &HashMap<K, V>,
+        storage: &HashMap<K, V>,
+    ) -> Result<(), RepositoryError> {
+        if self
+            .0
+            .keys()
+            .any(|key| snapshot.get(key) != storage.get(key))
+        {
+            return Err(RepositoryError::ConcurrentModification);
+        }
+
+        Ok(())
+    }
+
+    fn apply(self, storage: &mut HashMap<K, V>) {
+        for (key, value) in self.0 {
+            match value {
+                Some(value) => storage.insert(key, value),
+                None => storage.remove(&key),
+            };
+        }
+    }
+}
+
+impl Repositories for MemoryTransaction {
+    fn user_repository(&self) -> Arc<dyn UserRepository> {
+        Arc::new(MemoryUserRepository::new(self.tx.clone()))
+    }
+
+    fn user_credential_repository(&self) -> Arc<dyn UserCredentialRepository> {
+        Arc::new(MemoryUserCredentialRepository::new(self.tx.clone()))
+    }
+
+    fn todo_list_repository(&self) -> Arc<dyn TodoListRepository> {
+        Arc::new(MemoryTodoListRepository::new(self.tx.clone()))
+    }
+
+    fn todo_repository(&self) -> Arc<dyn TodoRepository> {
+        Arc::new(MemoryTodoRepository::new(self.tx.clone()))
+    }
+
+    fn tag_repository(&self) -> Arc<dyn TagRepository> {
+        Arc::new(MemoryTagRepository::new(self.tx.clone()))
+    }
+
+    fn password_reset_token_repository(&self) -> Arc<dyn PasswordResetTokenRepository> {
+        Arc::new(MemoryPasswordResetTokenRepository::new(self.tx.clone()))
+    }
+
+    fn email_verification_token_repository(&self) -> Arc<dyn EmailVerificationTokenRepository> {
+        Arc::new(MemoryEmailVerificationTokenRepository::new(self.tx.clone()))
+    }
+}
+
+#[async_trait]
+impl Transaction for MemoryTransaction {
+    async fn commit(self: Box<Self>) -> Result<(), RepositoryError> {
+        let tx = self.tx.lock().unwrap();
+        let snapshot = &self.snapshot;
+        let mut storage = self.storage.lock().unwrap();
+
+        let users = Changes::new(&snapshot.users, &tx.users);
+        let user_credentials = Changes::new(&snapshot.user_credentials, &tx.user_credentials);
+        let todo_lists = Changes::new(&snapshot.todo_lists, &tx.todo_lists);
+        let todos = Changes::new(&snapshot.todos, &tx.todos);
+        let tags = Changes::new(&snapshot.tags, &tx.tags);
+        let password_reset_tokens =
+            Changes::new(&snapshot.password_reset_tokens, &tx.password_reset_tokens);
+        let email_verification_tokens = Changes::new(
+            &snapshot.email_verification_tokens,
+            &tx.email_verification_tokens,
+        );
+
+        users.check(&snapshot.users, &storage.users)?;
+        user_credentials.check(&snapshot.user_credentials, &storage.user_credentials)?;
+        todo_lists.check(&snapshot.todo_lists, &storage.todo_lists)?;
+        todos.check(&snapshot.todos, &storage.todos)?;
+        tags.check(&snapshot.tags, &storage.tags)?;
+        password_reset_tokens.check(
+            &snapshot.password_reset_tokens,
+            &storage.password_reset_tokens,
+        )?;
+        email_verification_tokens.check(
+            &snapshot.email_verification_tokens,
+            &storage.email_verification_tokens,
+        )?;
+
+        users.apply(&mut storage.users);
+        user_credentials.apply(&mut storage.user_credentials);
+        todo_lists.apply(&mut storage.todo_lists);
+        todos.apply(&mut storage.todos);
+        tags.apply(&mut storage.tags);
+        password_reset_tokens.apply(&mut storage.password_reset_tokens);
+        email_verification_tokens.apply(&mut storage.email_verification_tokens);
+
+        Ok(())
+    }
+
+    async fn rollback(self: Box<Self>) -> Result<(), RepositoryError> {
+        Ok(())
+    }
+}
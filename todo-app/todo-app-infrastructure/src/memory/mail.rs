@@ -0,0 +1,3 @@
+mod memory_mailer;
+
+pub use memory_mailer::MemoryMailer;
@@ -0,0 +1,28 @@
+use std::sync::Mutex;
+
+use async_trait::async_trait;
+use todo_app_application::mail::{MailMessage, Mailer};
+
+/// Keeps sent mail in memory, so that tests can read it back.
+#[derive(Debug, Default)]
+pub struct MemoryMailer {
+    messages: Mutex<Vec<MailMessage>>,
+}
+
+impl MemoryMailer {
+    pub fn new() -> Self {
+        Default::default()
+    }
+
+    pub fn messages(&self) -> Vec<MailMessage> {
+        self.messages.lock().unwrap().clone()
+    }
+}
+
+#[async_trait]
+impl Mailer for MemoryMailer {
+    async fn send(&self, message: &MailMessage) -> Result<(), anyhow::Error> {
+        self.messages.lock().unwrap().push(message.clone());
+        Ok(())
+    }
+}
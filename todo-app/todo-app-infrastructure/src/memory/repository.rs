@@ -0,0 +1,15 @@
+mod memory_email_verification_token_repository;
+mod memory_password_reset_token_repository;
+mod memory_tag_repository;
+mod memory_todo_list_repository;
+mod memory_todo_repository;
+mod memory_user_credential_repository;
+mod memory_user_repository;
+
+pub use memory_email_verification_token_repository::MemoryEmailVerificationTokenRepository;
+pub use memory_password_reset_token_repository::MemoryPasswordResetTokenRepository;
+pub use memory_tag_repository::MemoryTagRepository;
+pub use memory_todo_list_repository::MemoryTodoListRepository;
+pub use memory_todo_repository::MemoryTodoRepository;
+pub use memory_user_credential_repository::MemoryUserCredentialRepository;
+pub use memory_user_repository::MemoryUserRepository;
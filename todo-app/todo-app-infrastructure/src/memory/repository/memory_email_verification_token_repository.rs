@@ -0,0 +1,81 @@
+use std::sync::{Arc, Mutex};
+
+use async_trait::async_trait;
+use todo_app_domain::{
+    aggregate_root::{
+        email_verification_token::{
+            entity::EmailVerificationToken, repository::EmailVerificationTokenRepository,
+        },
+        user::value_object::UserId,
+    },
+    error::RepositoryError,
+    value_object::SecretTokenHash,
+};
+
+use crate::memory::database::MemoryStorage;
+
+#[derive(Debug)]
+pub struct MemoryEmailVerificationTokenRepository {
+    storage: Arc<Mutex<MemoryStorage>>,
+}
+
+impl MemoryEmailVerificationTokenRepository {
+    pub fn new(storage: Arc<Mutex<MemoryStorage>>) -> Self {
+        Self { storage }
+    }
+}
+
+#[async_trait]
+impl EmailVerificationTokenRepository for MemoryEmailVerificationTokenRepository {
+    async fn find(
+        &self,
+        token_hash: &SecretTokenHash,
+    ) -> Result<Option<EmailVerificationToken>, RepositoryError> {
+        let storage = self.storage.lock().unwrap();
+        Ok(storage.email_verification_tokens.get(token_hash).cloned())
+    }
+
+    async fn insert(
+        &self,
+        email_verification_token: &EmailVerificationToken,
+    ) -> Result<(), RepositoryError> {
+        let mut storage = self.storage.lock().unwrap();
+        let user_id = email_verification_token.user_id();
+        if !storage.users.contains_key(user_id.as_uuid()) {
+            return Err(RepositoryError::Conflict(format!(
+                "user not found: {user_id:?}"
+            )));
+        }
+        let token_hash = email_verification_token.token_hash();
+        if storage.email_verification_tokens.contains_key(token_hash) {
+            return Err(RepositoryError::Conflict(
+                "duplicate email verification token".to_owned(),
+            ));
+        }
+
+        storage
+            .email_verification_tokens
+            .insert(token_hash.clone(), email_verification_token.clone());
+
+        Ok(())
+    }
+
+    async fn delete(&self, token_hash: &SecretTokenHash) -> Result<(), RepositoryError> {
+        let mut storage = self.storage.lock().unwrap();
+        storage
+            .email_verification_tokens
+            .remove(token_hash)
+            .ok_or(RepositoryError::NotFound)?;
+
+        Ok(())
+    }
+
+    async fn delete_by_user_id(&self, user_id: &UserId) -> Result<(), RepositoryError> {
+        let mut storage = self.storage.lock().unwrap();
+        storage
+            .email_verification_tokens
+            .retain(|_, email_verification_token| email_verification_token.user_id() != user_id);
+
+        Ok(())
+    }
+}
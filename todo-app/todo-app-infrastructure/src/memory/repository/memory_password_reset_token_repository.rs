@@ -0,0 +1,81 @@
+use std::sync::{Arc, Mutex};
+
+use async_trait::async_trait;
+use todo_app_domain::{
+    aggregate_root::{
+        password_reset_token::{
+            entity::PasswordResetToken, repository::PasswordResetTokenRepository,
+        },
+        user::value_object::UserId,
+    },
+    error::RepositoryError,
+    value_object::SecretTokenHash,
+};
+
+use crate::memory::database::MemoryStorage;
+
+#[derive(Debug)]
+pub struct MemoryPasswordResetTokenRepository {
+    storage: Arc<Mutex<MemoryStorage>>,
+}
+
+impl MemoryPasswordResetTokenRepository {
+    pub fn new(storage: Arc<Mutex<MemoryStorage>>) -> Self {
+        Self { storage }
+    }
+}
+
+#[async_trait]
+impl PasswordResetTokenRepository for MemoryPasswordResetTokenRepository {
+    async fn find(
+        &self,
+        token_hash: &SecretTokenHash,
+    ) -> Result<Option<PasswordResetToken>, RepositoryError> {
+        let storage = self.storage.lock().unwrap();
+        Ok(storage.password_reset_tokens.get(token_hash).cloned())
+    }
+
+    async fn insert(
+        &self,
+        password_reset_token: &PasswordResetToken,
+    ) -> Result<(), RepositoryError> {
+        let mut storage = self.storage.lock().unwrap();
+        let user_id = password_reset_token.user_id();
+        if !storage.users.contains_key(user_id.as_uuid()) {
+            return Err(RepositoryError::Conflict(format!(
+                "user not found: {user_id:?}"
+            )));
+        }
+        let token_hash = password_reset_token.token_hash();
+        if storage.password_reset_tokens.contains_key(token_hash) {
+            return Err(RepositoryError::Conflict(
+                "duplicate password reset token".to_owned(),
+            ));
+        }
+
+        storage
+            .password_reset_tokens
+            .insert(token_hash.clone(), password_reset_token.clone());
+
+        Ok(())
+    }
+
+    async fn delete(&self, token_hash: &SecretTokenHash) -> Result<(), RepositoryError> {
+        let mut storage = self.storage.lock().unwrap();
+        storage
+            .password_reset_tokens
+            .remove(token_hash)
+            .ok_or(RepositoryError::NotFound)?;
+
+        Ok(())
+    }
+
+    async fn delete_by_user_id(&self, user_id: &UserId) -> Result<(), RepositoryError> {
+        let mut storage = self.storage.lock().unwrap();
+        storage
+            .password_reset_tokens
+            .retain(|_, password_reset_token| password_reset_token.user_id() != user_id);
+
+        Ok(())
+    }
+}
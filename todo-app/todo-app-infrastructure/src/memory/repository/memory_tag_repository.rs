@@ -0,0 +1,138 @@
+use std::sync::{Arc, Mutex};
+
+use async_trait::async_trait;
+use todo_app_domain::{
+    aggregate_root::{
+        tag::{entity::Tag, repository::TagRepository, value_object::TagId},
+        user::value_object::UserId,
+    },
+    error::{DomainError, RepositoryError},
+};
+
+use crate::memory::database::MemoryStorage;
+
+#[derive(Debug)]
+pub struct MemoryTagRepository {
+    storage: Arc<Mutex<MemoryStorage>>,
+}
+
+impl MemoryTagRepository {
+    pub fn new(storage: Arc<Mutex<MemoryStorage>>) -> Self {
+        Self { storage }
+    }
+}
+
+#[async_trait]
+impl TagRepository for MemoryTagRepository {
+    async fn find(&self, tag_id: &TagId) -> Result<Option<Tag>, RepositoryError> {
+        let storage = self.storage.lock().unwrap();
+        Ok(storage.tags.get(tag_id.as_uuid()).cloned())
+    }
+
+    async fn find_by_user_id(&self, user_id: &UserId) -> Result<Vec<Tag>, RepositoryError> {
+        let storage = self.storage.lock().unwrap();
+        let mut tags = storage
+            .tags
+            .values()
+            .filter(|tag| tag.user_id() == user_id)
+            .cloned()
+            .collect::<Vec<_>>();
+        tags.sort_by(|a, b| {
+            (a.name().as_str(), a.id().as_uuid()).cmp(&(b.name().as_str(), b.id().as_uuid()))
+        });
+
+        Ok(tags)
+    }
+
+    async fn insert(&self, tag: &Tag) -> Result<(), RepositoryError> {
+        let mut storage = self.storage.lock().unwrap();
+        if !storage.users.contains_key(tag.user_id().as_uuid()) {
+            return Err(RepositoryError::Conflict(format!(
+                "user not found: {:?}",
+                tag.user_id()
+            )));
+        }
+        if storage.tags.contains_key(tag.id().as_uuid()) {
+            return Err(RepositoryError::Conflict(format!(
+                "duplicate tag: {:?}",
+                tag.id()
+            )));
+        }
+        check_name_unique(&storage, tag)?;
+
+        storage.tags.insert(*tag.id().as_uuid(), tag.clone());
+
+        Ok(())
+    }
+
+    async fn update(&self, tag: &Tag) -> Result<(), RepositoryError> {
+        let mut storage = self.storage.lock().unwrap();
+        check_name_unique(&storage, tag)?;
+
+        let t = storage
+            .tags
+            .get_mut(tag.id().as_uuid())
+            .ok_or(RepositoryError::NotFound)?;
+        *t = tag.clone();
+
+        Ok(())
+    }
+
+    async fn delete(&self, tag_id: &TagId) -> Result<(), RepositoryError> {
+        let mut storage = self.storage.lock().unwrap();
+        storage
+            .tags
+            .remove(tag_id.as_uuid())
+            .ok_or(RepositoryError::NotFound)?;
+
+        unassign(&mut storage, tag_id);
+
+        Ok(())
+    }
+
+    async fn delete_by_user_id(&self, user_id: &UserId) -> Result<(), RepositoryError> {
+        let mut storage = self.storage.lock().unwrap();
+        let tag_ids = storage
+            .tags
+            .values()
+            .filter(|tag| tag.user_id() == user_id)
+            .map(|tag| tag.id().clone())
+            .collect::<Vec<_>>();
+        for tag_id in &tag_ids {
+            storage.tags.remove(tag_id.as_uuid());
+            unassign(&mut storage, tag_id);
+        }
+
+        Ok(())
+    }
+}
+
+/// Removes the tag from the todos, like the cascading foreign key of the join table.
+fn unassign(storage: &mut MemoryStorage, tag_id: &TagId) {
+    for todo in storage.todos.values_mut() {
+        if todo.has_tag(tag_id) {
+            let tag_ids = todo
+                .tag_ids()
+                .iter()
+                .filter(|t| *t != tag_id)
+                .cloned()
+                .collect();
+            let updated_at = todo.updated_at();
+            todo.retag(tag_ids, updated_at);
+        }
+    }
+}
+
+fn check_name_unique(storage: &MemoryStorage, tag: &Tag) -> Result<(), DomainError> {
+    let name = tag.name().as_str().to_lowercase();
+    let taken = storage.tags.values().any(|t| {
+        t.id() != tag.id()
+            && t.user_id() == tag.user_id()
+            && t.name().as_str().to_lowercase() == name
+    });
+    if taken {
+        return Err(DomainError::TagNameAlreadyTaken);
+    }
+
+    Ok(())
+}
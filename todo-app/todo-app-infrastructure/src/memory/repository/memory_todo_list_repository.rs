@@ -0,0 +1,134 @@
+use std::sync::{Arc, Mutex};
+
+use async_trait::async_trait;
+use todo_app_domain::{
+    aggregate_root::{
+        todo_list::{entity::TodoList, repository::TodoListRepository, value_object::TodoListId},
+        user::value_object::UserId,
+    },
+    error::RepositoryError,
+};
+
+use crate::memory::database::MemoryStorage;
+
+#[derive(Debug)]
+pub struct MemoryTodoListRepository {
+    storage: Arc<Mutex<MemoryStorage>>,
+}
+
+impl MemoryTodoListRepository {
+    pub fn new(storage: Arc<Mutex<MemoryStorage>>) -> Self {
+        Self { storage }
+    }
+}
+
+#[async_trait]
+impl TodoListRepository for MemoryTodoListRepository {
+    async fn find(&self, todo_list_id: &TodoListId) -> Result<Option<TodoList>, RepositoryError> {
+        let storage = self.storage.lock().unwrap();
+        Ok(storage.todo_lists.get(todo_list_id.as_uuid()).cloned())
+    }
+
+    async fn find_inbox(&self, user_id: &UserId) -> Result<Option<TodoList>, RepositoryError> {
+        let storage = self.storage.lock().unwrap();
+        Ok(storage
+            .todo_lists
+            .values()
+            .find(|todo_list| todo_list.user_id() == user_id && todo_list.inbox())
+            .cloned())
+    }
+
+    async fn find_by_user_id(&self, user_id: &UserId) -> Result<Vec<TodoList>, RepositoryError> {
+        let storage = self.storage.lock().unwrap();
+        let mut todo_lists = storage
+            .todo_lists
+            .values()
+            .filter(|todo_list| todo_list.user_id() == user_id)
+            .cloned()
+            .collect::<Vec<_>>();
+        todo_lists.sort_by(|a, b| {
+            (a.position(), a.id().as_uuid()).cmp(&(b.position(), b.id().as_uuid()))
+        });
+
+        Ok(todo_lists)
+    }
+
+    async fn insert(&self, todo_list: &TodoList) -> Result<(), RepositoryError> {
+        let mut storage = self.storage.lock().unwrap();
+        if !storage.users.contains_key(todo_list.user_id().as_uuid()) {
+            return Err(RepositoryError::Conflict(format!(
+                "user not found: {:?}",
+                todo_list.user_id()
+            )));
+        }
+        if storage.todo_lists.contains_key(todo_list.id().as_uuid()) {
+            return Err(RepositoryError::Conflict(format!(
+                "duplicate todo list: {:?}",
+                todo_list.id()
+            )));
+        }
+        let has_inbox = storage
+            .todo_lists
+            .values()
+            .any(|t| t.user_id() == todo_list.user_id() && t.inbox());
+        if todo_list.inbox() && has_inbox {
+            return Err(RepositoryError::Conflict(format!(
+                "duplicate inbox: {:?}",
+                todo_list.user_id()
+            )));
+        }
+
+        storage
+            .todo_lists
+            .insert(*todo_list.id().as_uuid(), todo_list.clone());
+
+        Ok(())
+    }
+
+    async fn update(&self, todo_list: &TodoList) -> Result<(), RepositoryError> {
+        let mut storage = self.storage.lock().unwrap();
+        let t = storage
+            .todo_lists
+            .get_mut(todo_list.id().as_uuid())
+            .ok_or(RepositoryError::NotFound)?;
+        *t = todo_list.clone();
+
+        Ok(())
+    }
+
+    async fn delete(&self, todo_list_id: &TodoListId) -> Result<(), RepositoryError> {
+        let mut storage = self.storage.lock().unwrap();
+        let referenced = storage
+            .todos
+            .values()
+            .any(|todo| todo.todo_list_id() == todo_list_id);
+        if referenced {
+            return Err(RepositoryError::Conflict(format!(
+                "todo list is still referenced: {todo_list_id:?}"
+            )));
+        }
+
+        storage
+            .todo_lists
+            .remove(todo_list_id.as_uuid())
+            .ok_or(RepositoryError::NotFound)?;
+
+        Ok(())
+    }
+
+    async fn delete_by_user_id(&self, user_id: &UserId) -> Result<(), RepositoryError> {
+        let mut storage = self.storage.lock().unwrap();
+        let referenced = storage.todos.values().any(|todo| todo.user_id() == user_id);
+        if referenced {
+            return Err(RepositoryError::Conflict(format!(
+                "todo lists are still referenced: {user_id:?}"
+            )));
+        }
+
+        storage
+            .todo_lists
+            .retain(|_, todo_list| todo_list.user_id() != user_id);
+
+        Ok(())
+    }
+}
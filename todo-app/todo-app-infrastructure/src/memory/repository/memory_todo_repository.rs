@@ -0,0 +1,326 @@
+use std::{
+    collections::HashSet,
+    sync::{Arc, Mutex},
+};
+
+use async_trait::async_trait;
+use chrono::{DateTime, Utc};
+use todo_app_domain::{
+    aggregate_root::{
+        todo::{
+            entity::Todo,
+            repository::{TodoPage, TodoQuery, TodoRepository, TodoSearchHit},
+            value_object::TodoId,
+        },
+        todo_list::value_object::TodoListId,
+        user::value_object::UserId,
+    },
+    error::RepositoryError,
+};
+
+use crate::memory::database::MemoryStorage;
+
+#[derive(Debug)]
+pub struct MemoryTodoRepository {
+    storage: Arc<Mutex<MemoryStorage>>,
+}
+
+impl MemoryTodoRepository {
+    pub fn new(storage: Arc<Mutex<MemoryStorage>>) -> Self {
+        Self { storage }
+    }
+}
+
+#[async_trait]
+impl TodoRepository for MemoryTodoRepository {
+    async fn find(&self, todo_id: &TodoId) -> Result<Option<Todo>, RepositoryError> {
+        let storage = self.storage.lock().unwrap();
+        Ok(storage.todos.get(todo_id.as_uuid()).cloned())
+    }
+
+    async fn find_page(&self, query: &TodoQuery) -> Result<TodoPage, RepositoryError> {
+        let storage = self.storage.lock().unwrap();
+        let mut todos = storage
+            .todos
+            .values()
+            .filter(|todo| query.matches(todo))
+            .cloned()
+            .collect::<Vec<_>>();
+        todos.sort_by(|a, b| query.sort().compare(a, b));
+        todos.truncate(query.limit() as usize + 1);
+
+        Ok(TodoPage::new(todos, query))
+    }
+
+    async fn search(
+        &self,
+        user_id: &UserId,
+        text: &str,
+        limit: u32,
+    ) -> Result<Vec<TodoSearchHit>, RepositoryError> {
+        let terms = words(text)
+            .filter(|(is_word, _)| *is_word)
+            .map(|(_, word)| word.to_lowercase())
+            .collect::<HashSet<_>>();
+        if terms.is_empty() {
+            return Ok(vec![]);
+        }
+
+        let storage = self.storage.lock().unwrap();
+        let mut hits = storage
+            .todos
+            .values()
+            .filter(|todo| todo.user_id() == user_id)
+            .filter_map(|todo| search_hit(todo, &terms))
+            .collect::<Vec<_>>();
+        hits.sort_by(|a, b| {
+            b.rank()
+                .total_cmp(&a.rank())
+                .then_with(|| a.todo().created_at().cmp(&b.todo().created_at()))
+                .then_with(|| a.todo().id().as_uuid().cmp(b.todo().id().as_uuid()))
+        });
+        hits.truncate(limit as usize);
+
+        Ok(hits)
+    }
+
+    async fn insert(&self, todo: &Todo) -> Result<(), RepositoryError> {
+        let mut storage = self.storage.lock().unwrap();
+        if !storage.users.contains_key(todo.user_id().as_uuid()) {
+            return Err(RepositoryError::Conflict(format!(
+                "user not found: {:?}",
+                todo.user_id()
+            )));
+        }
+        if storage.todos.contains_key(todo.id().as_uuid()) {
+            return Err(RepositoryError::Conflict(format!(
+                "duplicate todo: {:?}",
+                todo.id()
+            )));
+        }
+        check_references(&storage, todo)?;
+
+        storage.todos.insert(*todo.id().as_uuid(), todo.clone());
+
+        Ok(())
+    }
+
+    async fn update(&self, todo: &Todo) -> Result<(), RepositoryError> {
+        let mut storage = self.storage.lock().unwrap();
+        check_references(&storage, todo)?;
+        let t = storage
+            .todos
+            .get_mut(todo.id().as_uuid())
+            .ok_or(RepositoryError::NotFound)?;
+        *t = todo.clone();
+
+        Ok(())
+    }
+
+    async fn delete(&self, todo_id: &TodoId) -> Result<(), RepositoryError> {
+        let mut storage = self.storage.lock().unwrap();
+        storage
+            .todos
+            .remove(todo_id.as_uuid())
+            .ok_or(RepositoryError::NotFound)?;
+
+        Ok(())
+    }
+
+    async fn move_by_todo_list_id(
+        &self,
+        from: &TodoListId,
+        to: &TodoListId,
+        now: DateTime<Utc>,
+    ) -> Result<(), RepositoryError> {
+        let mut storage = self.storage.lock().unwrap();
+        if !storage.todo_lists.contains_key(to.as_uuid()) {
+            return Err(RepositoryError::Conflict(format!(
+                "todo list not found: {to:?}"
+            )));
+        }
+        for todo in storage.todos.values_mut() {
+            if todo.todo_list_id() == from {
+                todo.move_to(to.clone(), now);
+            }
+        }
+
+        Ok(())
+    }
+
+    async fn delete_by_todo_list_id(
+        &self,
+        todo_list_id: &TodoListId,
+    ) -> Result<(), RepositoryError> {
+        let mut storage = self.storage.lock().unwrap();
+        storage
+            .todos
+            .retain(|_, todo| todo.todo_list_id() != todo_list_id);
+
+        Ok(())
+    }
+
+    async fn delete_by_user_id(&self, user_id: &UserId) -> Result<(), RepositoryError> {
+        let mut storage = self.storage.lock().unwrap();
+        storage.todos.retain(|_, todo| todo.user_id() != user_id);
+
+        Ok(())
+    }
+}
+
+fn check_references(storage: &MemoryStorage, todo: &Todo) -> Result<(), RepositoryError> {
+    if !storage
+        .todo_lists
+        .contains_key(todo.todo_list_id().as_uuid())
+    {
+        return Err(RepositoryError::Conflict(format!(
+            "todo list not found: {:?}",
+            todo.todo_list_id()
+        )));
+    }
+    match todo
+        .tag_ids()
+        .iter()
+        .find(|tag_id| !storage.tags.contains_key(tag_id.as_uuid()))
+    {
+        Some(tag_id) => Err(RepositoryError::Conflict(format!(
+            "tag not found: {tag_id:?}"
+        ))),
+        None => Ok(()),
+    }
+}
+
+/// Splits the text into runs of alphanumeric characters, yielding whether each part is a word.
+fn words(text: &str) -> impl Iterator<Item = (bool, &str)> {
+    let mut rest = text;
+    std::iter::from_fn(move || {
+        let is_word = rest.chars().next()?.is_alphanumeric();
+        let end = rest
+            .find(|c: char| c.is_alphanumeric() != is_word)
+            .unwrap_or(rest.len());
+        let (part, tail) = rest.split_at(end);
+        rest = tail;
+        Some((is_word, part))
+    })
+}
+
+/// Naive stand-in for the Postgres full-text search: every term must be a word of the title or
+/// the description, and the rank counts the matches, title ones weighing more.
+fn search_hit(todo: &Todo, terms: &HashSet<String>) -> Option<TodoSearchHit> {
+    let mut text = todo.title().as_str().to_owned();
+    if let Some(description) = todo.description() {
+        text.push_str(": ");
+        text.push_str(description.as_str());
+    }
+    let title_len = todo.title().as_str().len();
+
+    let mut matched = HashSet::new();
+    let mut rank = 0.0;
+    let mut snippet = String::new();
+    let mut offset = 0;
+    for (is_word, part) in words(&text) {
+        let word = part.to_lowercase();
+        if is_word && terms.contains(&word) {
+            rank += if offset < title_len { 1.0 } else { 0.4 };
+            matched.insert(word);
+            snippet.push_str(TodoSearchHit::HIGHLIGHT_START);
+            snippet.push_str(&escape_html(part));
+            snippet.push_str(TodoSearchHit::HIGHLIGHT_END);
+        } else {
+            snippet.push_str(&escape_html(part));
+        }
+        offset += part.len();
+    }
+
+    (matched.len() == terms.len()).then(|| TodoSearchHit::new(todo.clone(), rank, snippet))
+}
+
+fn escape_html(text: &str) -> String {
+    text.replace('&', "&amp;")
+        .replace('<', "&lt;")
+        .replace('>', "&gt;")
+}
+
+#[cfg(test)]
+mod tests {
+    use todo_app_application::{database::Repositories, usecase::SearchTodosUsecase};
+    use todo_app_domain::aggregate_root::{
+        todo::value_object::{TodoDescription, TodoTitle},
+        todo_list::entity::TodoList,
+        user::{entity::User, value_object::UserName},
+    };
+
+    use super::*;
+    use crate::memory::database::MemoryDB;
+
+    #[tokio::test]
+    async fn memory_todo_repository_search() {
+        let db = Arc::new(MemoryDB::new());
+        let user = User::new(UserName::try_from("user name".to_owned()).unwrap());
+        db.user_repository().insert(&user).await.unwrap();
+        let inbox = TodoList::new_inbox(user.id().clone());
+        db.todo_list_repository().insert(&inbox).await.unwrap();
+        let todos = vec![
+            ("Buy milk", None),
+            ("Write report", Some("quarterly milk <numbers> & more")),
+            ("Call mom", Some("about the milk, the MILK!")),
+            ("Pay rent", None),
+        ];
+        for (title, description) in todos {
+            let todo = Todo::new(
+                user.id().clone(),
+                inbox.id().clone(),
+                TodoTitle::try_from(title.to_owned()).unwrap(),
+                description.map(|d: &str| TodoDescription::try_from(d.to_owned()).unwrap()),
+                None,
+                Utc::now(),
+            );
+            db.todo_repository().insert(&todo).await.unwrap();
+        }
+        let usecase = SearchTodosUsecase::new(db.clone());
+
+        let tests = vec![
+            (
+                "milk",
+                vec![
+                    ("Buy milk", "Buy <mark>milk</mark>"),
+                    (
+                        "Call mom",
+                        "Call mom: about the <mark>milk</mark>, the <mark>MILK</mark>!",
+                    ),
+                    (
+                        "Write report",
+                        "Write report: quarterly <mark>milk</mark> &lt;numbers&gt; &amp; more",
+                    ),
+                ],
+            ),
+            (
+                "Report, milk",
+                vec![(
+                    "Write report",
+                    "Write <mark>report</mark>: quarterly <mark>milk</mark> &lt;numbers&gt; &amp; more",
+                )],
+            ),
+            ("rent milk", vec![]),
+            ("mil", vec![]),
+        ];
+
+        for (text, expected) in tests {
+            let hits = usecase
+                .execute(user.id(), text.to_owned(), None)
+                .await
+                .unwrap();
+            let hits = hits
+                .iter()
+                .map(|hit| (hit.todo().title().as_str(), hit.snippet()))
+                .collect::<Vec<_>>();
+            assert_eq!(hits, expected, "text: {text}");
+        }
+
+        let hits = usecase
+            .execute(&UserId::new(), "milk".to_owned(), None)
+            .await
+            .unwrap();
+        assert_eq!(hits, vec![]);
+    }
+}
@@ -0,0 +1,105 @@
+use std::sync::{Arc, Mutex};
+
+use async_trait::async_trait;
+use todo_app_domain::{
+    aggregate_root::{
+        user::value_object::UserId,
+        user_credential::{entity::UserCredential, repository::UserCredentialRepository},
+    },
+    error::{DomainError, RepositoryError},
+};
+
+use crate::memory::database::MemoryStorage;
+
+#[derive(Debug)]
+pub struct MemoryUserCredentialRepository {
+    storage: Arc<Mutex<MemoryStorage>>,
+}
+
+impl MemoryUserCredentialRepository {
+    pub fn new(storage: Arc<Mutex<MemoryStorage>>) -> Self {
+        Self { storage }
+    }
+}
+
+#[async_trait]
+impl UserCredentialRepository for MemoryUserCredentialRepository {
+    async fn find(&self, user_id: &UserId) -> Result<Option<UserCredential>, RepositoryError> {
+        let storage = self.storage.lock().unwrap();
+        Ok(storage.user_credentials.get(user_id.as_uuid()).cloned())
+    }
+
+    async fn find_by_email(&self, email: &str) -> Result<Option<UserCredential>, RepositoryError> {
+        let storage = self.storage.lock().unwrap();
+        let user_credential = storage
+            .user_credentials
+            .values()
+            .find(|uc| uc.email().as_str().eq_ignore_ascii_case(email))
+            .cloned();
+
+        Ok(user_credential)
+    }
+
+    async fn insert(&self, user_credential: &UserCredential) -> Result<(), RepositoryError> {
+        let mut storage = self.storage.lock().unwrap();
+        let user_id = user_credential.user_id();
+        if !storage.users.contains_key(user_id.as_uuid()) {
+            return Err(RepositoryError::Conflict(format!(
+                "user not found: {user_id:?}"
+            )));
+        }
+        if storage.user_credentials.contains_key(user_id.as_uuid()) {
+            return Err(RepositoryError::Conflict(format!(
+                "duplicate user credential: {user_id:?}"
+            )));
+        }
+        check_email_unique(&storage, user_credential)?;
+
+        storage
+            .user_credentials
+            .insert(*user_id.as_uuid(), user_credential.clone());
+
+        Ok(())
+    }
+
+    async fn update(&self, user_credential: &UserCredential) -> Result<(), RepositoryError> {
+        let mut storage = self.storage.lock().unwrap();
+        check_email_unique(&storage, user_credential)?;
+
+        let uc = storage
+            .user_credentials
+            .get_mut(user_credential.user_id().as_uuid())
+            .ok_or(RepositoryError::NotFound)?;
+        *uc = user_credential.clone();
+
+        Ok(())
+    }
+
+    async fn delete(&self, user_id: &UserId) -> Result<(), RepositoryError> {
+        let mut storage = self.storage.lock().unwrap();
+        storage
+            .user_credentials
+            .remove(user_id.as_uuid())
+            .ok_or(RepositoryError::NotFound)?;
+
+        Ok(())
+    }
+}
+
+fn check_email_unique(
+    storage: &MemoryStorage,
+    user_credential: &UserCredential,
+) -> Result<(), DomainError> {
+    let taken = storage.user_credentials.values().any(|uc| {
+        uc.user_id() != user_credential.user_id()
+            && uc
+                .email()
+                .as_str()
+                .eq_ignore_ascii_case(user_credential.email().as_str())
+    });
+    if taken {
+        return Err(DomainError::EmailAlreadyTaken);
+    }
+
+    Ok(())
+}
@@ -0,0 +1,84 @@
+use std::sync::{Arc, Mutex};
+
+use async_trait::async_trait;
+use todo_app_domain::{
+    aggregate_root::user::{entity::User, repository::UserRepository, value_object::UserId},
+    error::RepositoryError,
+};
+
+use crate::memory::database::MemoryStorage;
+
+#[derive(Debug)]
+pub struct MemoryUserRepository {
+    storage: Arc<Mutex<MemoryStorage>>,
+}
+
+impl MemoryUserRepository {
+    pub fn new(storage: Arc<Mutex<MemoryStorage>>) -> Self {
+        Self { storage }
+    }
+}
+
+#[async_trait]
+impl UserRepository for MemoryUserRepository {
+    async fn find(&self, user_id: &UserId) -> Result<Option<User>, RepositoryError> {
+        let storage = self.storage.lock().unwrap();
+        Ok(storage.users.get(user_id.as_uuid()).cloned())
+    }
+
+    async fn insert(&self, user: &User) -> Result<(), RepositoryError> {
+        let mut storage = self.storage.lock().unwrap();
+        if storage.users.contains_key(user.id().as_uuid()) {
+            return Err(RepositoryError::Conflict(format!(
+                "duplicate user: {:?}",
+                user.id()
+            )));
+        }
+
+        storage.users.insert(*user.id().as_uuid(), user.clone());
+
+        Ok(())
+    }
+
+    async fn update(&self, user: &User) -> Result<(), RepositoryError> {
+        let mut storage = self.storage.lock().unwrap();
+        let u = storage
+            .users
+            .get_mut(user.id().as_uuid())
+            .ok_or(RepositoryError::NotFound)?;
+        *u = user.clone();
+
+        Ok(())
+    }
+
+    async fn delete(&self, user_id: &UserId) -> Result<(), RepositoryError> {
+        let mut storage = self.storage.lock().unwrap();
+        let referenced = storage.user_credentials.contains_key(user_id.as_uuid())
+            || storage.todos.values().any(|todo| todo.user_id() == user_id)
+            || storage
+                .todo_lists
+                .values()
+                .any(|todo_list| todo_list.user_id() == user_id)
+            || storage.tags.values().any(|tag| tag.user_id() == user_id)
+            || storage
+                .password_reset_tokens
+                .values()
+                .any(|password_reset_token| password_reset_token.user_id() == user_id)
+            || storage
+                .email_verification_tokens
+                .values()
+                .any(|email_verification_token| email_verification_token.user_id() == user_id);
+        if referenced {
+            return Err(RepositoryError::Conflict(format!(
+                "user is still referenced: {user_id:?}"
+            )));
+        }
+
+        storage
+            .users
+            .remove(user_id.as_uuid())
+            .ok_or(RepositoryError::NotFound)?;
+
+        Ok(())
+    }
+}
@@ -0,0 +1,3 @@
+mod memory_session_store;
+
+pub use memory_session_store::MemorySessionStore;
@@ -0,0 +1,111 @@
+use std::{collections::HashMap, sync::Mutex};
+
+use async_trait::async_trait;
+use chrono::{DateTime, Duration, Local};
+use todo_app_domain::aggregate_root::user::value_object::UserId;
+use todo_app_presentation::session::{Session, SessionExpiry, SessionStore};
+
+#[derive(Debug)]
+pub struct MemorySessionStore {
+    sessions: Mutex<HashMap<String, (Session, DateTime<Local>)>>,
+    session_expiry: SessionExpiry,
+}
+
+impl MemorySessionStore {
+    pub fn new(session_expiry: SessionExpiry) -> Self {
+        Self {
+            sessions: Default::default(),
+            session_expiry,
+        }
+    }
+
+    fn expires_at(&self, session: &Session, now: DateTime<Local>) -> Option<DateTime<Local>> {
+        let ttl = self.session_expiry.ttl(session, now)?;
+        Some(now + Duration::from_std(ttl).ok()?)
+    }
+}
+
+#[async_trait]
+impl SessionStore for MemorySessionStore {
+    async fn find(&self, session_id: &str) -> Result<Option<Session>, anyhow::Error> {
+        let mut sessions = self.sessions.lock().unwrap();
+        let now = Local::now();
+        let (session, expires_at) = match sessions.get(session_id) {
+            Some((session, expires_at)) if *expires_at > now => (session.clone(), *expires_at),
+            Some(_) => {
+                sessions.remove(session_id);
+                return Ok(None);
+            }
+            None => return Ok(None),
+        };
+
+        let expires_at = self.expires_at(&session, now).unwrap_or(expires_at);
+        sessions.insert(session_id.to_owned(), (session.clone(), expires_at));
+
+        Ok(Some(session))
+    }
+
+    async fn save(&self, session_id: &str, session: &Session) -> Result<(), anyhow::Error> {
+        if let Some(expires_at) = self.expires_at(session, Local::now()) {
+            let mut sessions = self.sessions.lock().unwrap();
+            sessions.insert(session_id.to_owned(), (session.clone(), expires_at));
+        }
+
+        Ok(())
+    }
+
+    async fn delete(&self, session_id: &str) -> Result<(), anyhow::Error> {
+        let mut sessions = self.sessions.lock().unwrap();
+        sessions.remove(session_id);
+
+        Ok(())
+    }
+
+    async fn delete_by_user_id(&self, user_id: &UserId) -> Result<(), anyhow::Error> {
+        let mut sessions = self.sessions.lock().unwrap();
+        sessions.retain(|_, (session, _)| session.user_id() != user_id.as_uuid());
+
+        Ok(())
+    }
+
+    async fn delete_by_user_id_except(
+        &self,
+        user_id: &UserId,
+        session_id: &str,
+    ) -> Result<(), anyhow::Error> {
+        let mut sessions = self.sessions.lock().unwrap();
+        sessions.retain(|id, (session, _)| {
+            session.user_id() != user_id.as_uuid() || id.as_str() == session_id
+        });
+
+        Ok(())
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::session_store_contract;
+
+    #[tokio::test]
+    async fn memory_session_store_round_trip() {
+        let session_store = MemorySessionStore::new(SessionExpiry::default());
+        session_store_contract::round_trip(&session_store).await;
+    }
+
+    #[tokio::test]
+    async fn memory_session_store_delete_by_user_id() {
+        let session_store = MemorySessionStore::new(SessionExpiry::default());
+        session_store_contract::delete_by_user_id(&session_store).await;
+    }
+
+    #[tokio::test]
+    async fn memory_session_store_expired() {
+        let session_store = MemorySessionStore::new(SessionExpiry::new(
+            Duration::milliseconds(50),
+            Duration::days(1),
+        ));
+        session_store_contract::expired(&session_store, std::time::Duration::from_millis(100))
+            .await;
+    }
+}
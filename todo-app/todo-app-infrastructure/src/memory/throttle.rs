@@ -0,0 +1,3 @@
+mod memory_login_attempt_store;
+
+pub use memory_login_attempt_store::MemoryLoginAttemptStore;
@@ -0,0 +1,120 @@
+use std::{collections::HashMap, sync::Mutex};
+
+use async_trait::async_trait;
+use chrono::{DateTime, Duration, Utc};
+use todo_app_application::throttle::LoginAttemptStore;
+
+#[derive(Debug, Default)]
+pub struct MemoryLoginAttemptStore {
+    entries: Mutex<HashMap<String, LoginAttempts>>,
+}
+
+#[derive(Debug)]
+struct LoginAttempts {
+    failures: u32,
+    expires_at: DateTime<Utc>,
+    locked_until: Option<DateTime<Utc>>,
+}
+
+impl MemoryLoginAttemptStore {
+    pub fn new() -> Self {
+        Default::default()
+    }
+}
+
+#[async_trait]
+impl LoginAttemptStore for MemoryLoginAttemptStore {
+    async fn record_failure(&self, key: &str, window: Duration) -> Result<u32, anyhow::Error> {
+        let mut entries = self.entries.lock().unwrap();
+        let now = Utc::now();
+        let attempts = entries
+            .entry(key.to_owned())
+            .and_modify(|attempts| {
+                if attempts.expires_at <= now {
+                    attempts.failures = 0;
+                }
+            })
+            .or_insert(LoginAttempts {
+                failures: 0,
+                expires_at: now,
+                locked_until: None,
+            });
+        attempts.failures += 1;
+        attempts.expires_at = attempts.expires_at.max(now + window);
+
+        Ok(attempts.failures)
+    }
+
+    async fn lock(
+        &self,
+        key: &str,
+        until: DateTime<Utc>,
+        window: Duration,
+    ) -> Result<(), anyhow::Error> {
+        let mut entries = self.entries.lock().unwrap();
+        if let Some(attempts) = entries.get_mut(key) {
+            attempts.locked_until = Some(until);
+            attempts.expires_at = attempts.expires_at.max(until + window);
+        }
+
+        Ok(())
+    }
+
+    async fn locked_until(&self, key: &str) -> Result<Option<DateTime<Utc>>, anyhow::Error> {
+        let entries = self.entries.lock().unwrap();
+        Ok(entries
+            .get(key)
+            .and_then(|attempts| attempts.locked_until)
+            .filter(|locked_until| *locked_until > Utc::now()))
+    }
+
+    async fn reset(&self, key: &str) -> Result<(), anyhow::Error> {
+        let mut entries = self.entries.lock().unwrap();
+        entries.remove(key);
+
+        Ok(())
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[tokio::test]
+    async fn memory_login_attempt_store_round_trip() {
+        let store = MemoryLoginAttemptStore::new();
+        let window = Duration::minutes(1);
+
+        for expected in 1..=3 {
+            assert_eq!(store.record_failure("key", window).await.unwrap(), expected);
+        }
+        assert_eq!(store.record_failure("other", window).await.unwrap(), 1);
+        assert_eq!(store.locked_until("key").await.unwrap(), None);
+
+        let until = Utc::now() + Duration::minutes(5);
+        store.lock("key", until, window).await.unwrap();
+        assert_eq!(store.locked_until("key").await.unwrap(), Some(until));
+        assert_eq!(store.locked_until("other").await.unwrap(), None);
+
+        store.reset("key").await.unwrap();
+        assert_eq!(store.locked_until("key").await.unwrap(), None);
+        assert_eq!(store.record_failure("key", window).await.unwrap(), 1);
+    }
+
+    #[tokio::test]
+    async fn memory_login_attempt_store_expiry() {
+        let store = MemoryLoginAttemptStore::new();
+
+        store.record_failure("key", Duration::zero()).await.unwrap();
+        assert_eq!(
+            store.record_failure("key", Duration::zero()).await.unwrap(),
+            1
+        );
+
+        store
+            .lock("key", Utc::now() - Duration::seconds(1), Duration::zero())
+            .await
+            .unwrap();
+        assert_eq!(store.locked_until("key").await.unwrap(), None);
+    }
+}
@@ -1,7 +1,9 @@
 mod pg_connection;
 mod pg_db;
+mod pg_error;
 mod pg_transaction;
 
 pub use pg_connection::PgConnection;
 pub use pg_db::PgDB;
+pub use pg_error::repository_error;
 pub use pg_transaction::PgTransaction;
@@ -3,15 +3,24 @@ use std::sync::Arc;
 use async_trait::async_trait;
 use sqlx::PgPool as SqlxPgPool;
 
-use todo_app_application::database::{Repositories, Transaction, DB};
-use todo_app_domain::aggregate_root::{
-    todo::repository::TodoRepository, user::repository::UserRepository,
-    user_credential::repository::UserCredentialRepository,
+use todo_app_application::database::{IsolationLevel, Repositories, Transaction, DB};
+use todo_app_domain::{
+    aggregate_root::{
+        email_verification_token::repository::EmailVerificationTokenRepository,
+        password_reset_token::repository::PasswordResetTokenRepository,
+        tag::repository::TagRepository, todo::repository::TodoRepository,
+        todo_list::repository::TodoListRepository, user::repository::UserRepository,
+        user_credential::repository::UserCredentialRepository,
+    },
+    error::RepositoryError,
 };
 
 use crate::postgres::{
-    database::{PgConnection, PgTransaction},
-    repository::{PgUserCredentialRepository, PgUserRepository},
+    database::{repository_error, PgConnection, PgTransaction},
+    repository::{
+        PgEmailVerificationTokenRepository, PgPasswordResetTokenRepository, PgTagRepository,
+        PgTodoListRepository, PgTodoRepository, PgUserCredentialRepository, PgUserRepository,
+    },
 };
 
 #[derive(Clone, Debug)]
@@ -36,15 +45,47 @@ impl Repositories for PgDB {
         )))
     }
 
+    fn todo_list_repository(&self) -> Arc<dyn TodoListRepository> {
+        Arc::new(PgTodoListRepository::new(PgConnection::Pool(
+            self.pool.clone(),
+        )))
+    }
+
     fn todo_repository(&self) -> Arc<dyn TodoRepository> {
-        // Arc::new(PgTodoRepository::new(self.conn.clone()))
-        todo!()
+        Arc::new(PgTodoRepository::new(PgConnection::Pool(self.pool.clone())))
+    }
+
+    fn tag_repository(&self) -> Arc<dyn TagRepository> {
+        Arc::new(PgTagRepository::new(PgConnection::Pool(self.pool.clone())))
+    }
+
+    fn password_reset_token_repository(&self) -> Arc<dyn PasswordResetTokenRepository> {
+        Arc::new(PgPasswordResetTokenRepository::new(PgConnection::Pool(
+            self.pool.clone(),
+        )))
+    }
+
+    fn email_verification_token_repository(&self) -> Arc<dyn EmailVerificationTokenRepository> {
+        Arc::new(PgEmailVerificationTokenRepository::new(PgConnection::Pool(
+            self.pool.clone(),
+        )))
     }
 }
 
 #[async_trait]
 impl DB for PgDB {
-    async fn begin(&self) -> Arc<dyn Transaction> {
-        Arc::new(PgTransaction::new(self.pool.begin().await.unwrap()))
+    async fn begin_with(
+        &self,
+        isolation_level: IsolationLevel,
+    ) -> Result<Box<dyn Transaction>, RepositoryError> {
+        let mut tx = self.pool.begin().await.map_err(repository_error)?;
+        sqlx::query(&format!(
+            "SET TRANSACTION ISOLATION LEVEL {isolation_level}"
+        ))
+        .execute(&mut tx)
+        .await
+        .map_err(repository_error)?;
+
+        Ok(Box::new(PgTransaction::new(tx)))
     }
 }
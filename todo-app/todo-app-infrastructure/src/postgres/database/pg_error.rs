@@ -0,0 +1,35 @@
+use todo_app_domain::error::RepositoryError;
+
+const UNIQUE_VIOLATION: &str = "23505";
+const FOREIGN_KEY_VIOLATION: &str = "23503";
+const SERIALIZATION_FAILURE: &str = "40001";
+const DEADLOCK_DETECTED: &str = "40P01";
+const CONNECTION_EXCEPTION_CLASS: &str = "08";
+const OPERATOR_INTERVENTION_CLASS: &str = "57";
+
+/// Classifies a sqlx error into the repository error reported to use cases.
+pub fn repository_error(e: sqlx::Error) -> RepositoryError {
+    match &e {
+        sqlx::Error::Database(db_error) => match db_error.code().as_deref() {
+            Some(UNIQUE_VIOLATION | FOREIGN_KEY_VIOLATION) => {
+                RepositoryError::Conflict(db_error.message().to_owned())
+            }
+            Some(SERIALIZATION_FAILURE | DEADLOCK_DETECTED) => {
+                RepositoryError::ConcurrentModification
+            }
+            Some(code)
+                if code.starts_with(CONNECTION_EXCEPTION_CLASS)
+                    || code.starts_with(OPERATOR_INTERVENTION_CLASS) =>
+            {
+                RepositoryError::Unavailable(e.into())
+            }
+            _ => RepositoryError::Unexpected(e.into()),
+        },
+        sqlx::Error::Io(_)
+        | sqlx::Error::Tls(_)
+        | sqlx::Error::PoolTimedOut
+        | sqlx::Error::PoolClosed
+        | sqlx::Error::WorkerCrashed => RepositoryError::Unavailable(e.into()),
+        _ => RepositoryError::Unexpected(e.into()),
+    }
+}
@@ -1,16 +1,29 @@
 use std::sync::Arc;
 
+use anyhow::anyhow;
 use async_trait::async_trait;
 use sqlx::{Postgres, Transaction as SqlxTransaction};
 use tokio::sync::Mutex;
 
 use todo_app_application::database::{Repositories, Transaction};
-use todo_app_domain::aggregate_root::{
-    todo::repository::TodoRepository, user::repository::UserRepository,
-    user_credential::repository::UserCredentialRepository,
+use todo_app_domain::{
+    aggregate_root::{
+        email_verification_token::repository::EmailVerificationTokenRepository,
+        password_reset_token::repository::PasswordResetTokenRepository,
+        tag::repository::TagRepository, todo::repository::TodoRepository,
+        todo_list::repository::TodoListRepository, user::repository::UserRepository,
+        user_credential::repository::UserCredentialRepository,
+    },
+    error::RepositoryError,
 };
 
-use crate::postgres::repository::{PgUserCredentialRepository, PgUserRepository};
+use crate::postgres::{
+    database::repository_error,
+    repository::{
+        PgEmailVerificationTokenRepository, PgPasswordResetTokenRepository, PgTagRepository,
+        PgTodoListRepository, PgTodoRepository, PgUserCredentialRepository, PgUserRepository,
+    },
+};
 
 #[derive(Debug)]
 pub struct PgTransaction {
@@ -23,6 +36,12 @@ impl PgTransaction {
             tx: Arc::new(Mutex::new(tx)),
         }
     }
+
+    fn into_inner(self) -> Result<SqlxTransaction<'static, Postgres>, RepositoryError> {
+        Arc::try_unwrap(self.tx)
+            .map(Mutex::into_inner)
+            .map_err(|_| anyhow!("transaction is still referenced by a repository").into())
+    }
 }
 
 impl Repositories for PgTransaction {
@@ -34,19 +53,39 @@ impl Repositories for PgTransaction {
         Arc::new(PgUserCredentialRepository::new(self.tx.clone().into()))
     }
 
+    fn todo_list_repository(&self) -> Arc<dyn TodoListRepository> {
+        Arc::new(PgTodoListRepository::new(self.tx.clone().into()))
+    }
+
     fn todo_repository(&self) -> Arc<dyn TodoRepository> {
-        // Arc::new(PgTodoRepository::new(self.conn.clone()))
-        todo!()
+        Arc::new(PgTodoRepository::new(self.tx.clone().into()))
+    }
+
+    fn tag_repository(&self) -> Arc<dyn TagRepository> {
+        Arc::new(PgTagRepository::new(self.tx.clone().into()))
+    }
+
+    fn password_reset_token_repository(&self) -> Arc<dyn PasswordResetTokenRepository> {
+        Arc::new(PgPasswordResetTokenRepository::new(self.tx.clone().into()))
+    }
+
+    fn email_verification_token_repository(&self) -> Arc<dyn EmailVerificationTokenRepository> {
+        Arc::new(PgEmailVerificationTokenRepository::new(
+            self.tx.clone().into(),
+        ))
     }
 }
 
 #[async_trait]
 impl Transaction for PgTransaction {
-    async fn commit(self: Box<Self>) {
-        self.commit().await;
+    async fn commit(self: Box<Self>) -> Result<(), RepositoryError> {
+        self.into_inner()?.commit().await.map_err(repository_error)
     }
 
-    async fn rollback(self: Box<Self>) {
-        self.rollback().await;
+    async fn rollback(self: Box<Self>) -> Result<(), RepositoryError> {
+        self.into_inner()?
+            .rollback()
+            .await
+            .map_err(repository_error)
     }
 }
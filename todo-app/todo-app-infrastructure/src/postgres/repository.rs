@@ -1,5 +1,15 @@
+mod pg_email_verification_token_repository;
+mod pg_password_reset_token_repository;
+mod pg_tag_repository;
+mod pg_todo_list_repository;
+mod pg_todo_repository;
 mod pg_user_credential_repository;
 mod pg_user_repository;
 
+pub use pg_email_verification_token_repository::PgEmailVerificationTokenRepository;
+pub use pg_password_reset_token_repository::PgPasswordResetTokenRepository;
+pub use pg_tag_repository::PgTagRepository;
+pub use pg_todo_list_repository::PgTodoListRepository;
+pub use pg_todo_repository::PgTodoRepository;
 pub use pg_user_credential_repository::PgUserCredentialRepository;
 pub use pg_user_repository::PgUserRepository;
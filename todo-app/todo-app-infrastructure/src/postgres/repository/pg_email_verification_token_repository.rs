@@ -0,0 +1,149 @@
+use async_trait::async_trait;
+
+use chrono::{DateTime, Utc};
+use todo_app_domain::{
+    aggregate_root::{
+        email_verification_token::{
+            entity::EmailVerificationToken, repository::EmailVerificationTokenRepository,
+        },
+        user::value_object::UserId,
+        user_credential::value_object::Email,
+    },
+    error::{RepositoryError, ValidationError},
+    value_object::SecretTokenHash,
+};
+use uuid::Uuid;
+
+use crate::postgres::database::{repository_error, PgConnection};
+
+#[derive(Debug)]
+pub struct PgEmailVerificationTokenRepository {
+    conn: PgConnection,
+}
+
+impl PgEmailVerificationTokenRepository {
+    pub fn new(conn: PgConnection) -> Self {
+        Self { conn }
+    }
+}
+
+#[async_trait]
+impl EmailVerificationTokenRepository for PgEmailVerificationTokenRepository {
+    async fn find(
+        &self,
+        token_hash: &SecretTokenHash,
+    ) -> Result<Option<EmailVerificationToken>, RepositoryError> {
+        let query = sqlx::query_as!(
+            EmailVerificationTokenRecord,
+            "
+            SELECT token_hash, user_id, email, expires_at
+            FROM email_verification_tokens
+            WHERE token_hash = $1
+            ",
+            token_hash.as_str()
+        );
+
+        let email_verification_token = match &self.conn {
+            PgConnection::Pool(p) => query.fetch_optional(p).await,
+            PgConnection::Transaction(tx) => query.fetch_optional(&mut *tx.lock().await).await,
+        }
+        .map_err(repository_error)?;
+
+        let email_verification_token = match email_verification_token {
+            Some(t) => EmailVerificationToken::try_from(t),
+            None => return Ok(None),
+        }
+        .map_err(anyhow::Error::new)?;
+
+        Ok(Some(email_verification_token))
+    }
+
+    async fn insert(
+        &self,
+        email_verification_token: &EmailVerificationToken,
+    ) -> Result<(), RepositoryError> {
+        let query = sqlx::query!(
+            "
+            INSERT INTO email_verification_tokens (token_hash, user_id, email, expires_at)
+            VALUES ($1, $2, $3, $4)
+            ",
+            email_verification_token.token_hash().as_str(),
+            email_verification_token.user_id().as_uuid(),
+            email_verification_token.email().as_str(),
+            email_verification_token.expires_at()
+        );
+
+        match &self.conn {
+            PgConnection::Pool(p) => query.execute(p).await,
+            PgConnection::Transaction(tx) => query.execute(&mut *tx.lock().await).await,
+        }
+        .map_err(repository_error)?;
+
+        Ok(())
+    }
+
+    async fn delete(&self, token_hash: &SecretTokenHash) -> Result<(), RepositoryError> {
+        let query = sqlx::query!(
+            "
+            DELETE FROM email_verification_tokens
+            WHERE token_hash = $1
+            ",
+            token_hash.as_str(),
+        );
+
+        let result = match &self.conn {
+            PgConnection::Pool(p) => query.execute(p).await,
+            PgConnection::Transaction(tx) => query.execute(&mut *tx.lock().await).await,
+        }
+        .map_err(repository_error)?;
+
+        if result.rows_affected() == 0 {
+            return Err(RepositoryError::NotFound);
+        }
+
+        Ok(())
+    }
+
+    async fn delete_by_user_id(&self, user_id: &UserId) -> Result<(), RepositoryError> {
+        let query = sqlx::query!(
+            "
+            DELETE FROM email_verification_tokens
+            WHERE user_id = $1
+            ",
+            user_id.as_uuid(),
+        );
+
+        match &self.conn {
+            PgConnection::Pool(p) => query.execute(p).await,
+            PgConnection::Transaction(tx) => query.execute(&mut *tx.lock().await).await,
+        }
+        .map_err(repository_error)?;
+
+        Ok(())
+    }
+}
+
+#[derive(Debug)]
+struct EmailVerificationTokenRecord {
+    token_hash: String,
+    user_id: Uuid,
+    email: String,
+    expires_at: DateTime<Utc>,
+}
+
+impl TryFrom<EmailVerificationTokenRecord> for EmailVerificationToken {
+    type Error = ValidationError;
+
+    fn try_from(value: EmailVerificationTokenRecord) -> Result<Self, Self::Error> {
+        let token_hash = SecretTokenHash::try_from(value.token_hash)?;
+        let user_id = UserId::from(value.user_id);
+        let email = Email::try_from(value.email)?;
+
+        Ok(EmailVerificationToken::from((
+            token_hash,
+            user_id,
+            email,
+            value.expires_at,
+        )))
+    }
+}
@@ -0,0 +1,144 @@
+use async_trait::async_trait;
+
+use chrono::{DateTime, Utc};
+use todo_app_domain::{
+    aggregate_root::{
+        password_reset_token::{
+            entity::PasswordResetToken, repository::PasswordResetTokenRepository,
+        },
+        user::value_object::UserId,
+    },
+    error::{RepositoryError, ValidationError},
+    value_object::SecretTokenHash,
+};
+use uuid::Uuid;
+
+use crate::postgres::database::{repository_error, PgConnection};
+
+#[derive(Debug)]
+pub struct PgPasswordResetTokenRepository {
+    conn: PgConnection,
+}
+
+impl PgPasswordResetTokenRepository {
+    pub fn new(conn: PgConnection) -> Self {
+        Self { conn }
+    }
+}
+
+#[async_trait]
+impl PasswordResetTokenRepository for PgPasswordResetTokenRepository {
+    async fn find(
+        &self,
+        token_hash: &SecretTokenHash,
+    ) -> Result<Option<PasswordResetToken>, RepositoryError> {
+        let query = sqlx::query_as!(
+            PasswordResetTokenRecord,
+            "
+            SELECT token_hash, user_id, expires_at
+            FROM password_reset_tokens
+            WHERE token_hash = $1
+            ",
+            token_hash.as_str()
+        );
+
+        let password_reset_token = match &self.conn {
+            PgConnection::Pool(p) => query.fetch_optional(p).await,
+            PgConnection::Transaction(tx) => query.fetch_optional(&mut *tx.lock().await).await,
+        }
+        .map_err(repository_error)?;
+
+        let password_reset_token = match password_reset_token {
+            Some(t) => PasswordResetToken::try_from(t),
+            None => return Ok(None),
+        }
+        .map_err(anyhow::Error::new)?;
+
+        Ok(Some(password_reset_token))
+    }
+
+    async fn insert(
+        &self,
+        password_reset_token: &PasswordResetToken,
+    ) -> Result<(), RepositoryError> {
+        let query = sqlx::query!(
+            "
+            INSERT INTO password_reset_tokens (token_hash, user_id, expires_at)
+            VALUES ($1, $2, $3)
+            ",
+            password_reset_token.token_hash().as_str(),
+            password_reset_token.user_id().as_uuid(),
+            password_reset_token.expires_at()
+        );
+
+        match &self.conn {
+            PgConnection::Pool(p) => query.execute(p).await,
+            PgConnection::Transaction(tx) => query.execute(&mut *tx.lock().await).await,
+        }
+        .map_err(repository_error)?;
+
+        Ok(())
+    }
+
+    async fn delete(&self, token_hash: &SecretTokenHash) -> Result<(), RepositoryError> {
+        let query = sqlx::query!(
+            "
+            DELETE FROM password_reset_tokens
+            WHERE token_hash = $1
+            ",
+            token_hash.as_str(),
+        );
+
+        let result = match &self.conn {
+            PgConnection::Pool(p) => query.execute(p).await,
+            PgConnection::Transaction(tx) => query.execute(&mut *tx.lock().await).await,
+        }
+        .map_err(repository_error)?;
+
+        if result.rows_affected() == 0 {
+            return Err(RepositoryError::NotFound);
+        }
+
+        Ok(())
+    }
+
+    async fn delete_by_user_id(&self, user_id: &UserId) -> Result<(), RepositoryError> {
+        let query = sqlx::query!(
+            "
+            DELETE FROM password_reset_tokens
+            WHERE user_id = $1
+            ",
+            user_id.as_uuid(),
+        );
+
+        match &self.conn {
+            PgConnection::Pool(p) => query.execute(p).await,
+            PgConnection::Transaction(tx) => query.execute(&mut *tx.lock().await).await,
+        }
+        .map_err(repository_error)?;
+
+        Ok(())
+    }
+}
+
+#[derive(Debug)]
+struct PasswordResetTokenRecord {
+    token_hash: String,
+    user_id: Uuid,
+    expires_at: DateTime<Utc>,
+}
+
+impl TryFrom<PasswordResetTokenRecord> for PasswordResetToken {
+    type Error = ValidationError;
+
+    fn try_from(value: PasswordResetTokenRecord) -> Result<Self, Self::Error> {
+        let token_hash = SecretTokenHash::try_from(value.token_hash)?;
+        let user_id = UserId::from(value.user_id);
+
+        Ok(PasswordResetToken::from((
+            token_hash,
+            user_id,
+            value.expires_at,
+        )))
+    }
+}
@@ -0,0 +1,205 @@
+use async_trait::async_trait;
+
+use nameof::name_of;
+use todo_app_domain::{
+    aggregate_root::{
+        tag::{
+            entity::Tag,
+            repository::TagRepository,
+            value_object::{TagColor, TagId, TagName},
+        },
+        user::value_object::UserId,
+    },
+    error::{DomainError, RepositoryError, ValidationErrors},
+};
+use uuid::Uuid;
+
+use crate::postgres::database::{repository_error, PgConnection};
+
+const NAME_UNIQUE_INDEX: &str = "tags_user_id_name_key";
+
+#[derive(Debug)]
+pub struct PgTagRepository {
+    conn: PgConnection,
+}
+
+impl PgTagRepository {
+    pub fn new(conn: PgConnection) -> Self {
+        Self { conn }
+    }
+}
+
+#[async_trait]
+impl TagRepository for PgTagRepository {
+    async fn find(&self, tag_id: &TagId) -> Result<Option<Tag>, RepositoryError> {
+        let query = sqlx::query_as!(
+            TagRecord,
+            "
+            SELECT id, user_id, name, color
+            FROM tags
+            WHERE id = $1
+            ",
+            tag_id.as_uuid()
+        );
+
+        let tag = match &self.conn {
+            PgConnection::Pool(p) => query.fetch_optional(p).await,
+            PgConnection::Transaction(tx) => query.fetch_optional(&mut *tx.lock().await).await,
+        }
+        .map_err(repository_error)?;
+
+        let tag = match tag {
+            Some(t) => Tag::try_from(t),
+            None => return Ok(None),
+        }
+        .map_err(anyhow::Error::new)?;
+
+        Ok(Some(tag))
+    }
+
+    async fn find_by_user_id(&self, user_id: &UserId) -> Result<Vec<Tag>, RepositoryError> {
+        let query = sqlx::query_as!(
+            TagRecord,
+            r#"
+            SELECT id, user_id, name, color
+            FROM tags
+            WHERE user_id = $1
+            ORDER BY name COLLATE "C", id
+            "#,
+            user_id.as_uuid()
+        );
+
+        let tags = match &self.conn {
+            PgConnection::Pool(p) => query.fetch_all(p).await,
+            PgConnection::Transaction(tx) => query.fetch_all(&mut *tx.lock().await).await,
+        }
+        .map_err(repository_error)?;
+
+        tags.into_iter()
+            .map(Tag::try_from)
+            .collect::<Result<Vec<_>, _>>()
+            .map_err(|e| anyhow::Error::new(e).into())
+    }
+
+    async fn insert(&self, tag: &Tag) -> Result<(), RepositoryError> {
+        let query = sqlx::query!(
+            "
+            INSERT INTO tags (id, user_id, name, color)
+            VALUES ($1, $2, $3, $4)
+            ",
+            tag.id().as_uuid(),
+            tag.user_id().as_uuid(),
+            tag.name().as_str(),
+            tag.color().as_str()
+        );
+
+        match &self.conn {
+            PgConnection::Pool(p) => query.execute(p).await,
+            PgConnection::Transaction(tx) => query.execute(&mut *tx.lock().await).await,
+        }
+        .map_err(map_unique_violation)?;
+
+        Ok(())
+    }
+
+    async fn update(&self, tag: &Tag) -> Result<(), RepositoryError> {
+        let query = sqlx::query!(
+            "
+            UPDATE tags
+            SET name = $1, color = $2
+            WHERE id = $3
+            ",
+            tag.name().as_str(),
+            tag.color().as_str(),
+            tag.id().as_uuid()
+        );
+
+        let result = match &self.conn {
+            PgConnection::Pool(p) => query.execute(p).await,
+            PgConnection::Transaction(tx) => query.execute(&mut *tx.lock().await).await,
+        }
+        .map_err(map_unique_violation)?;
+
+        if result.rows_affected() == 0 {
+            return Err(RepositoryError::NotFound);
+        }
+
+        Ok(())
+    }
+
+    async fn delete(&self, tag_id: &TagId) -> Result<(), RepositoryError> {
+        let query = sqlx::query!(
+            "
+            DELETE FROM tags
+            WHERE id = $1
+            ",
+            tag_id.as_uuid(),
+        );
+
+        let result = match &self.conn {
+            PgConnection::Pool(p) => query.execute(p).await,
+            PgConnection::Transaction(tx) => query.execute(&mut *tx.lock().await).await,
+        }
+        .map_err(repository_error)?;
+
+        if result.rows_affected() == 0 {
+            return Err(RepositoryError::NotFound);
+        }
+
+        Ok(())
+    }
+
+    async fn delete_by_user_id(&self, user_id: &UserId) -> Result<(), RepositoryError> {
+        let query = sqlx::query!(
+            "
+            DELETE FROM tags
+            WHERE user_id = $1
+            ",
+            user_id.as_uuid(),
+        );
+
+        match &self.conn {
+            PgConnection::Pool(p) => query.execute(p).await,
+            PgConnection::Transaction(tx) => query.execute(&mut *tx.lock().await).await,
+        }
+        .map_err(repository_error)?;
+
+        Ok(())
+    }
+}
+
+fn map_unique_violation(e: sqlx::Error) -> RepositoryError {
+    match &e {
+        sqlx::Error::Database(db_error) if db_error.constraint() == Some(NAME_UNIQUE_INDEX) => {
+            DomainError::TagNameAlreadyTaken.into()
+        }
+        _ => repository_error(e),
+    }
+}
+
+#[derive(Debug)]
+struct TagRecord {
+    id: Uuid,
+    user_id: Uuid,
+    name: String,
+    color: String,
+}
+
+impl TryFrom<TagRecord> for Tag {
+    type Error = ValidationErrors;
+
+    fn try_from(value: TagRecord) -> Result<Self, Self::Error> {
+        let id = TagId::from(value.id);
+        let user_id = UserId::from(value.user_id);
+        let name = TagName::try_from(value.name);
+        let color = TagColor::try_from(value.color);
+        match (name, color) {
+            (Ok(name), Ok(color)) => Ok(Tag::from((id, user_id, name, color))),
+            (name, color) => ValidationErrors::builder()
+                .result(name_of!(name), name)
+                .result(name_of!(color), color)
+                .build()
+                .into(),
+        }
+    }
+}
@@ -0,0 +1,224 @@
+use async_trait::async_trait;
+
+use todo_app_domain::{
+    aggregate_root::{
+        todo_list::{
+            entity::TodoList,
+            repository::TodoListRepository,
+            value_object::{TodoListId, TodoListName},
+        },
+        user::value_object::UserId,
+    },
+    error::{RepositoryError, ValidationError},
+};
+use uuid::Uuid;
+
+use crate::postgres::database::{repository_error, PgConnection};
+
+#[derive(Debug)]
+pub struct PgTodoListRepository {
+    conn: PgConnection,
+}
+
+impl PgTodoListRepository {
+    pub fn new(conn: PgConnection) -> Self {
+        Self { conn }
+    }
+}
+
+#[async_trait]
+impl TodoListRepository for PgTodoListRepository {
+    async fn find(&self, todo_list_id: &TodoListId) -> Result<Option<TodoList>, RepositoryError> {
+        let query = sqlx::query_as!(
+            TodoListRecord,
+            "
+            SELECT id, user_id, name, position, archived, inbox
+            FROM todo_lists
+            WHERE id = $1
+            ",
+            todo_list_id.as_uuid()
+        );
+
+        let todo_list = match &self.conn {
+            PgConnection::Pool(p) => query.fetch_optional(p).await,
+            PgConnection::Transaction(tx) => query.fetch_optional(&mut *tx.lock().await).await,
+        }
+        .map_err(repository_error)?;
+
+        let todo_list = match todo_list {
+            Some(t) => TodoList::try_from(t),
+            None => return Ok(None),
+        }
+        .map_err(anyhow::Error::new)?;
+
+        Ok(Some(todo_list))
+    }
+
+    async fn find_inbox(&self, user_id: &UserId) -> Result<Option<TodoList>, RepositoryError> {
+        let query = sqlx::query_as!(
+            TodoListRecord,
+            "
+            SELECT id, user_id, name, position, archived, inbox
+            FROM todo_lists
+            WHERE user_id = $1 AND inbox
+            ",
+            user_id.as_uuid()
+        );
+
+        let todo_list = match &self.conn {
+            PgConnection::Pool(p) => query.fetch_optional(p).await,
+            PgConnection::Transaction(tx) => query.fetch_optional(&mut *tx.lock().await).await,
+        }
+        .map_err(repository_error)?;
+
+        let todo_list = match todo_list {
+            Some(t) => TodoList::try_from(t),
+            None => return Ok(None),
+        }
+        .map_err(anyhow::Error::new)?;
+
+        Ok(Some(todo_list))
+    }
+
+    async fn find_by_user_id(&self, user_id: &UserId) -> Result<Vec<TodoList>, RepositoryError> {
+        let query = sqlx::query_as!(
+            TodoListRecord,
+            "
+            SELECT id, user_id, name, position, archived, inbox
+            FROM todo_lists
+            WHERE user_id = $1
+            ORDER BY position, id
+            ",
+            user_id.as_uuid()
+        );
+
+        let todo_lists = match &self.conn {
+            PgConnection::Pool(p) => query.fetch_all(p).await,
+            PgConnection::Transaction(tx) => query.fetch_all(&mut *tx.lock().await).await,
+        }
+        .map_err(repository_error)?;
+
+        todo_lists
+            .into_iter()
+            .map(TodoList::try_from)
+            .collect::<Result<Vec<_>, _>>()
+            .map_err(|e| anyhow::Error::new(e).into())
+    }
+
+    async fn insert(&self, todo_list: &TodoList) -> Result<(), RepositoryError> {
+        let query = sqlx::query!(
+            "
+            INSERT INTO todo_lists (id, user_id, name, position, archived, inbox)
+            VALUES ($1, $2, $3, $4, $5, $6)
+            ",
+            todo_list.id().as_uuid(),
+            todo_list.user_id().as_uuid(),
+            todo_list.name().as_str(),
+            todo_list.position(),
+            todo_list.archived(),
+            todo_list.inbox()
+        );
+
+        match &self.conn {
+            PgConnection::Pool(p) => query.execute(p).await,
+            PgConnection::Transaction(tx) => query.execute(&mut *tx.lock().await).await,
+        }
+        .map_err(repository_error)?;
+
+        Ok(())
+    }
+
+    async fn update(&self, todo_list: &TodoList) -> Result<(), RepositoryError> {
+        let query = sqlx::query!(
+            "
+            UPDATE todo_lists
+            SET name = $1, position = $2, archived = $3
+            WHERE id = $4
+            ",
+            todo_list.name().as_str(),
+            todo_list.position(),
+            todo_list.archived(),
+            todo_list.id().as_uuid()
+        );
+
+        let result = match &self.conn {
+            PgConnection::Pool(p) => query.execute(p).await,
+            PgConnection::Transaction(tx) => query.execute(&mut *tx.lock().await).await,
+        }
+        .map_err(repository_error)?;
+
+        if result.rows_affected() == 0 {
+            return Err(RepositoryError::NotFound);
+        }
+
+        Ok(())
+    }
+
+    async fn delete(&self, todo_list_id: &TodoListId) -> Result<(), RepositoryError> {
+        let query = sqlx::query!(
+            "
+            DELETE FROM todo_lists
+            WHERE id = $1
+            ",
+            todo_list_id.as_uuid(),
+        );
+
+        let result = match &self.conn {
+            PgConnection::Pool(p) => query.execute(p).await,
+            PgConnection::Transaction(tx) => query.execute(&mut *tx.lock().await).await,
+        }
+        .map_err(repository_error)?;
+
+        if result.rows_affected() == 0 {
+            return Err(RepositoryError::NotFound);
+        }
+
+        Ok(())
+    }
+
+    async fn delete_by_user_id(&self, user_id: &UserId) -> Result<(), RepositoryError> {
+        let query = sqlx::query!(
+            "
+            DELETE FROM todo_lists
+            WHERE user_id = $1
+            ",
+            user_id.as_uuid(),
+        );
+
+        match &self.conn {
+            PgConnection::Pool(p) => query.execute(p).await,
+            PgConnection::Transaction(tx) => query.execute(&mut *tx.lock().await).await,
+        }
+        .map_err(repository_error)?;
+
+        Ok(())
+    }
+}
+
+#[derive(Debug)]
+struct TodoListRecord {
+    id: Uuid,
+    user_id: Uuid,
+    name: String,
+    position: i32,
+    archived: bool,
+    inbox: bool,
+}
+
+impl TryFrom<TodoListRecord> for TodoList {
+    type Error = ValidationError;
+
+    fn try_from(value: TodoListRecord) -> Result<Self, Self::Error> {
+        let id = TodoListId::from(value.id);
+        let user_id = UserId::from(value.user_id);
+        let name = TodoListName::try_from(value.name)?;
+        Ok(TodoList::from((
+            id,
+            user_id,
+            name,
+            value.position,
+            value.archived,
+            value.inbox,
+        )))
+    }
+}
@@ -0,0 +1,486 @@
+use async_trait::async_trait;
+
+use chrono::{DateTime, NaiveDate, Utc};
+use nameof::name_of;
+use sqlx::{FromRow, Postgres, QueryBuilder};
+use todo_app_domain::{
+    aggregate_root::{
+        tag::value_object::TagId,
+        todo::{
+            entity::Todo,
+            repository::{
+                SortDirection, TodoPage, TodoQuery, TodoRepository, TodoSearchHit, TodoSortKey,
+                TodoSortValue, TodoStatus,
+            },
+            value_object::{TodoDescription, TodoId, TodoTitle},
+        },
+        todo_list::value_object::TodoListId,
+        user::value_object::UserId,
+    },
+    error::{RepositoryError, ValidationErrors},
+};
+use uuid::Uuid;
+
+use crate::postgres::database::{repository_error, PgConnection};
+
+#[derive(Debug)]
+pub struct PgTodoRepository {
+    conn: PgConnection,
+}
+
+impl PgTodoRepository {
+    pub fn new(conn: PgConnection) -> Self {
+        Self { conn }
+    }
+}
+
+#[async_trait]
+impl TodoRepository for PgTodoRepository {
+    async fn find(&self, todo_id: &TodoId) -> Result<Option<Todo>, RepositoryError> {
+        let query = sqlx::query_as!(
+            TodoRecord,
+            r#"
+            SELECT
+                id, user_id, todo_list_id, title, description, due_date, completed_at, created_at,
+                updated_at,
+                ARRAY(
+                    SELECT tag_id FROM todo_tags WHERE todo_id = todos.id ORDER BY tag_id
+                ) AS "tag_ids!"
+            FROM todos
+            WHERE id = $1
+            "#,
+            todo_id.as_uuid()
+        );
+
+        let todo = match &self.conn {
+            PgConnection::Pool(p) => query.fetch_optional(p).await,
+            PgConnection::Transaction(tx) => query.fetch_optional(&mut *tx.lock().await).await,
+        }
+        .map_err(repository_error)?;
+
+        let todo = match todo {
+            Some(t) => Todo::try_from(t),
+            None => return Ok(None),
+        }
+        .map_err(anyhow::Error::new)?;
+
+        Ok(Some(todo))
+    }
+
+    async fn find_page(&self, query: &TodoQuery) -> Result<TodoPage, RepositoryError> {
+        let mut builder = QueryBuilder::<Postgres>::new(
+            "
+            SELECT
+                id, user_id, todo_list_id, title, description, due_date, completed_at, created_at,
+                updated_at,
+                ARRAY(
+                    SELECT tag_id FROM todo_tags WHERE todo_id = todos.id ORDER BY tag_id
+                ) AS tag_ids
+            FROM todos
+            WHERE user_id = ",
+        );
+        builder.push_bind(query.user_id().as_uuid());
+
+        if let Some(todo_list_id) = query.todo_list_id() {
+            builder
+                .push(" AND todo_list_id = ")
+                .push_bind(todo_list_id.as_uuid());
+        }
+
+        match query.status() {
+            Some(TodoStatus::Open) => builder.push(" AND completed_at IS NULL"),
+            Some(TodoStatus::Completed) => builder.push(" AND completed_at IS NOT NULL"),
+            None => &mut builder,
+        };
+        if let Some(due_before) = query.due_before() {
+            builder.push(" AND due_date < ").push_bind(due_before);
+        }
+        if let Some(due_after) = query.due_after() {
+            builder.push(" AND due_date > ").push_bind(due_after);
+        }
+        if let Some(text) = query.text() {
+            let pattern = format!("%{}%", escape_like(text));
+            builder
+                .push(" AND (title ILIKE ")
+                .push_bind(pattern.clone())
+                .push(" OR description ILIKE ")
+                .push_bind(pattern)
+                .push(")");
+        }
+
+        if !query.tag_ids().is_empty() {
+            let tag_ids = query
+                .tag_ids()
+                .iter()
+                .map(|tag_id| *tag_id.as_uuid())
+                .collect::<Vec<_>>();
+            builder
+                .push(" AND ARRAY(SELECT tag_id FROM todo_tags WHERE todo_id = todos.id) @> ")
+                .push_bind(tag_ids)
+                .push("::UUID[]");
+        }
+
+        let sort = query.sort();
+        let (column, direction) = (sort_column(sort.key()), sort.direction());
+        if let Some(cursor) = query.cursor() {
+            let comparison = match direction {
+                SortDirection::Asc => ">",
+                SortDirection::Desc => "<",
+            };
+            builder.push(format_args!(" AND ({column}, id) {comparison} ("));
+            match cursor.value() {
+                TodoSortValue::CreatedAt(t) | TodoSortValue::UpdatedAt(t) => {
+                    builder.push_bind(*t);
+                }
+                TodoSortValue::DueDate(d) => {
+                    builder
+                        .push("COALESCE(")
+                        .push_bind(*d)
+                        .push("::DATE, 'infinity'::DATE)");
+                }
+                TodoSortValue::Title(t) => {
+                    builder.push_bind(t.clone()).push(" COLLATE \"C\"");
+                }
+            }
+            builder
+                .push(", ")
+                .push_bind(cursor.id().as_uuid())
+                .push(")");
+        }
+
+        let direction = direction.as_str().to_uppercase();
+        builder
+            .push(format_args!(
+                " ORDER BY {column} {direction}, id {direction} LIMIT "
+            ))
+            .push_bind(i64::from(query.limit()) + 1);
+
+        let rows = builder.build().try_map(|row| TodoRecord::from_row(&row));
+        let todos = match &self.conn {
+            PgConnection::Pool(p) => rows.fetch_all(p).await,
+            PgConnection::Transaction(tx) => rows.fetch_all(&mut *tx.lock().await).await,
+        }
+        .map_err(repository_error)?;
+
+        let todos = todos
+            .into_iter()
+            .map(Todo::try_from)
+            .collect::<Result<Vec<_>, _>>()
+            .map_err(anyhow::Error::new)?;
+
+        Ok(TodoPage::new(todos, query))
+    }
+
+    async fn search(
+        &self,
+        user_id: &UserId,
+        text: &str,
+        limit: u32,
+    ) -> Result<Vec<TodoSearchHit>, RepositoryError> {
+        // The snippet is escaped before highlighting, as ts_headline keeps markup as it is.
+        let query = sqlx::query_as!(
+            TodoSearchRecord,
+            r#"
+            SELECT
+                id, user_id, todo_list_id, title, description, due_date, completed_at, created_at,
+                updated_at,
+                ARRAY(
+                    SELECT tag_id FROM todo_tags WHERE todo_id = todos.id ORDER BY tag_id
+                ) AS "tag_ids!",
+                ts_rank(search_vector, query) AS "rank!",
+                ts_headline(
+                    'simple',
+                    replace(replace(replace(
+                        title || COALESCE(': ' || description, ''),
+                        '&', '&amp;'), '<', '&lt;'), '>', '&gt;'),
+                    query,
+                    'StartSel=<mark>, StopSel=</mark>'
+                ) AS "snippet!"
+            FROM todos, plainto_tsquery('simple', $2) AS query
+            WHERE user_id = $1 AND search_vector @@ query
+            ORDER BY ts_rank(search_vector, query) DESC, created_at, id
+            LIMIT $3
+            "#,
+            user_id.as_uuid(),
+            text,
+            i64::from(limit)
+        );
+
+        let records = match &self.conn {
+            PgConnection::Pool(p) => query.fetch_all(p).await,
+            PgConnection::Transaction(tx) => query.fetch_all(&mut *tx.lock().await).await,
+        }
+        .map_err(repository_error)?;
+
+        records
+            .into_iter()
+            .map(|record| {
+                let todo = Todo::try_from(TodoRecord {
+                    id: record.id,
+                    user_id: record.user_id,
+                    todo_list_id: record.todo_list_id,
+                    title: record.title,
+                    description: record.description,
+                    due_date: record.due_date,
+                    tag_ids: record.tag_ids,
+                    completed_at: record.completed_at,
+                    created_at: record.created_at,
+                    updated_at: record.updated_at,
+                })?;
+                Ok(TodoSearchHit::new(todo, record.rank, record.snippet))
+            })
+            .collect::<Result<Vec<_>, ValidationErrors>>()
+            .map_err(|e| anyhow::Error::new(e).into())
+    }
+
+    async fn insert(&self, todo: &Todo) -> Result<(), RepositoryError> {
+        let tag_ids = tag_ids(todo);
+        let query = sqlx::query!(
+            "
+            WITH todo AS (
+                INSERT INTO todos (
+                    id, user_id, todo_list_id, title, description, due_date, completed_at,
+                    created_at, updated_at
+                )
+                VALUES ($1, $2, $3, $4, $5, $6, $7, $8, $9)
+                RETURNING id
+            )
+            INSERT INTO todo_tags (todo_id, tag_id)
+            SELECT todo.id, tag_id FROM todo, UNNEST($10::UUID[]) AS tag_id
+            ",
+            todo.id().as_uuid(),
+            todo.user_id().as_uuid(),
+            todo.todo_list_id().as_uuid(),
+            todo.title().as_str(),
+            todo.description().as_ref().map(TodoDescription::as_str),
+            todo.due_date(),
+            todo.completed_at(),
+            todo.created_at(),
+            todo.updated_at(),
+            &tag_ids
+        );
+
+        match &self.conn {
+            PgConnection::Pool(p) => query.execute(p).await,
+            PgConnection::Transaction(tx) => query.execute(&mut *tx.lock().await).await,
+        }
+        .map_err(repository_error)?;
+
+        Ok(())
+    }
+
+    async fn update(&self, todo: &Todo) -> Result<(), RepositoryError> {
+        let tag_ids = tag_ids(todo);
+        let query = sqlx::query_scalar!(
+            r#"
+            WITH todo AS (
+                UPDATE todos
+                SET
+                    todo_list_id = $1, title = $2, description = $3, due_date = $4,
+                    completed_at = $5, updated_at = $6
+                WHERE id = $7
+                RETURNING id
+            ), removed AS (
+                DELETE FROM todo_tags
+                WHERE todo_id IN (SELECT id FROM todo) AND tag_id <> ALL($8::UUID[])
+            ), added AS (
+                INSERT INTO todo_tags (todo_id, tag_id)
+                SELECT todo.id, tag_id FROM todo, UNNEST($8::UUID[]) AS tag_id
+                ON CONFLICT DO NOTHING
+            )
+            SELECT COUNT(*) AS "count!" FROM todo
+            "#,
+            todo.todo_list_id().as_uuid(),
+            todo.title().as_str(),
+            todo.description().as_ref().map(TodoDescription::as_str),
+            todo.due_date(),
+            todo.completed_at(),
+            todo.updated_at(),
+            todo.id().as_uuid(),
+            &tag_ids
+        );
+
+        let count = match &self.conn {
+            PgConnection::Pool(p) => query.fetch_one(p).await,
+            PgConnection::Transaction(tx) => query.fetch_one(&mut *tx.lock().await).await,
+        }
+        .map_err(repository_error)?;
+
+        if count == 0 {
+            return Err(RepositoryError::NotFound);
+        }
+
+        Ok(())
+    }
+
+    async fn delete(&self, todo_id: &TodoId) -> Result<(), RepositoryError> {
+        let query = sqlx::query!(
+            "
+            DELETE FROM todos
+            WHERE id = $1
+            ",
+            todo_id.as_uuid(),
+        );
+
+        let result = match &self.conn {
+            PgConnection::Pool(p) => query.execute(p).await,
+            PgConnection::Transaction(tx) => query.execute(&mut *tx.lock().await).await,
+        }
+        .map_err(repository_error)?;
+
+        if result.rows_affected() == 0 {
+            return Err(RepositoryError::NotFound);
+        }
+
+        Ok(())
+    }
+
+    async fn move_by_todo_list_id(
+        &self,
+        from: &TodoListId,
+        to: &TodoListId,
+        now: DateTime<Utc>,
+    ) -> Result<(), RepositoryError> {
+        let query = sqlx::query!(
+            "
+            UPDATE todos
+            SET todo_list_id = $1, updated_at = $2
+            WHERE todo_list_id = $3
+            ",
+            to.as_uuid(),
+            now,
+            from.as_uuid(),
+        );
+
+        match &self.conn {
+            PgConnection::Pool(p) => query.execute(p).await,
+            PgConnection::Transaction(tx) => query.execute(&mut *tx.lock().await).await,
+        }
+        .map_err(repository_error)?;
+
+        Ok(())
+    }
+
+    async fn delete_by_todo_list_id(
+        &self,
+        todo_list_id: &TodoListId,
+    ) -> Result<(), RepositoryError> {
+        let query = sqlx::query!(
+            "
+            DELETE FROM todos
+            WHERE todo_list_id = $1
+            ",
+            todo_list_id.as_uuid(),
+        );
+
+        match &self.conn {
+            PgConnection::Pool(p) => query.execute(p).await,
+            PgConnection::Transaction(tx) => query.execute(&mut *tx.lock().await).await,
+        }
+        .map_err(repository_error)?;
+
+        Ok(())
+    }
+
+    async fn delete_by_user_id(&self, user_id: &UserId) -> Result<(), RepositoryError> {
+        let query = sqlx::query!(
+            "
+            DELETE FROM todos
+            WHERE user_id = $1
+            ",
+            user_id.as_uuid(),
+        );
+
+        match &self.conn {
+            PgConnection::Pool(p) => query.execute(p).await,
+            PgConnection::Transaction(tx) => query.execute(&mut *tx.lock().await).await,
+        }
+        .map_err(repository_error)?;
+
+        Ok(())
+    }
+}
+
+fn tag_ids(todo: &Todo) -> Vec<Uuid> {
+    todo.tag_ids()
+        .iter()
+        .map(|tag_id| *tag_id.as_uuid())
+        .collect()
+}
+
+/// Matches the expressions the todo list indexes are built on.
+fn sort_column(key: TodoSortKey) -> &'static str {
+    match key {
+        TodoSortKey::CreatedAt => "created_at",
+        TodoSortKey::UpdatedAt => "updated_at",
+        TodoSortKey::DueDate => "COALESCE(due_date, 'infinity'::DATE)",
+        TodoSortKey::Title => "title COLLATE \"C\"",
+    }
+}
+
+fn escape_like(text: &str) -> String {
+    text.replace('\\', "\\\\")
+        .replace('%', "\\%")
+        .replace('_', "\\_")
+}
+
+#[derive(Debug, FromRow)]
+struct TodoRecord {
+    id: Uuid,
+    user_id: Uuid,
+    todo_list_id: Uuid,
+    title: String,
+    description: Option<String>,
+    due_date: Option<NaiveDate>,
+    tag_ids: Vec<Uuid>,
+    completed_at: Option<DateTime<Utc>>,
+    created_at: DateTime<Utc>,
+    updated_at: DateTime<Utc>,
+}
+
+#[derive(Debug)]
+struct TodoSearchRecord {
+    id: Uuid,
+    user_id: Uuid,
+    todo_list_id: Uuid,
+    title: String,
+    description: Option<String>,
+    due_date: Option<NaiveDate>,
+    tag_ids: Vec<Uuid>,
+    completed_at: Option<DateTime<Utc>>,
+    created_at: DateTime<Utc>,
+    updated_at: DateTime<Utc>,
+    rank: f32,
+    snippet: String,
+}
+
+impl TryFrom<TodoRecord> for Todo {
+    type Error = ValidationErrors;
+
+    fn try_from(value: TodoRecord) -> Result<Self, Self::Error> {
+        let id = TodoId::from(value.id);
+        let user_id = UserId::from(value.user_id);
+        let todo_list_id = TodoListId::from(value.todo_list_id);
+        let title = TodoTitle::try_from(value.title);
+        let description = value.description.map(TodoDescription::try_from).transpose();
+        match (title, description) {
+            (Ok(title), Ok(description)) => Ok(Todo::from((
+                id,
+                user_id,
+                todo_list_id,
+                title,
+                description,
+                value.due_date,
+                value.tag_ids.into_iter().map(TagId::from).collect(),
+                value.completed_at,
+                value.created_at,
+                value.updated_at,
+            ))),
+            (title, description) => ValidationErrors::builder()
+                .result(name_of!(title), title)
+                .result(name_of!(description), description)
+                .build()
+                .into(),
+        }
+    }
+}
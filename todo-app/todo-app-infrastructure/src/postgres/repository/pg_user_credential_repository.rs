@@ -1,5 +1,6 @@
 use async_trait::async_trait;
 
+use chrono::{DateTime, Utc};
 use nameof::name_of;
 use todo_app_domain::{
     aggregate_root::{
@@ -10,30 +11,32 @@ use todo_app_domain::{
             value_object::{Email, PasswordHash},
         },
     },
-    error::ValidationErrors,
+    error::{DomainError, RepositoryError, ValidationErrors},
 };
 use uuid::Uuid;
 
-use crate::postgres::database::PgConnection;
+use crate::postgres::database::{repository_error, PgConnection};
+
+const EMAIL_UNIQUE_INDEX: &str = "user_credentials_email_key";
 
 #[derive(Debug)]
 pub struct PgUserCredentialRepository {
     conn: PgConnection,
 }
 
-impl<'a> PgUserCredentialRepository {
+impl PgUserCredentialRepository {
     pub fn new(conn: PgConnection) -> Self {
         Self { conn }
     }
 }
 
 #[async_trait]
-impl<'a> UserCredentialRepository for PgUserCredentialRepository {
-    async fn find(&self, user_id: &UserId) -> Result<Option<UserCredential>, anyhow::Error> {
+impl UserCredentialRepository for PgUserCredentialRepository {
+    async fn find(&self, user_id: &UserId) -> Result<Option<UserCredential>, RepositoryError> {
         let query = sqlx::query_as!(
             UserCredentialRecord,
             "
-            SELECT user_id, email, password_hash
+            SELECT user_id, email, password_hash, email_verified_at
             FROM user_credentials
             WHERE user_id = $1
             ",
@@ -43,23 +46,25 @@ impl<'a> UserCredentialRepository for PgUserCredentialRepository {
         let user_credential = match &self.conn {
             PgConnection::Pool(p) => query.fetch_optional(p).await,
             PgConnection::Transaction(tx) => query.fetch_optional(&mut *tx.lock().await).await,
-        }?;
+        }
+        .map_err(repository_error)?;
 
         let user_credential = match user_credential {
             Some(uc) => UserCredential::try_from(uc),
             None => return Ok(None),
-        }?;
+        }
+        .map_err(anyhow::Error::new)?;
 
         Ok(Some(user_credential))
     }
 
-    async fn find_by_email(&self, email: &str) -> Result<Option<UserCredential>, anyhow::Error> {
+    async fn find_by_email(&self, email: &str) -> Result<Option<UserCredential>, RepositoryError> {
         let query = sqlx::query_as!(
             UserCredentialRecord,
             "
-            SELECT user_id, email, password_hash
+            SELECT user_id, email, password_hash, email_verified_at
             FROM user_credentials
-            WHERE email = $1
+            WHERE LOWER(email) = LOWER($1)
             ",
             email
         );
@@ -67,56 +72,66 @@ impl<'a> UserCredentialRepository for PgUserCredentialRepository {
         let user = match &self.conn {
             PgConnection::Pool(p) => query.fetch_optional(p).await,
             PgConnection::Transaction(tx) => query.fetch_optional(&mut *tx.lock().await).await,
-        }?;
+        }
+        .map_err(repository_error)?;
 
         let user = match user {
             Some(uc) => UserCredential::try_from(uc),
             None => return Ok(None),
-        }?;
+        }
+        .map_err(anyhow::Error::new)?;
 
         Ok(Some(user))
     }
 
-    async fn insert(&self, user_credential: &UserCredential) -> Result<(), anyhow::Error> {
+    async fn insert(&self, user_credential: &UserCredential) -> Result<(), RepositoryError> {
         let query = sqlx::query!(
             "
-            INSERT INTO user_credentials (user_id, email, password_hash)
-            VALUES ($1, $2, $3)
+            INSERT INTO user_credentials (user_id, email, password_hash, email_verified_at)
+            VALUES ($1, $2, $3, $4)
             ",
             user_credential.user_id().as_uuid(),
             user_credential.email().as_str(),
-            user_credential.password_hash().as_str()
+            user_credential.password_hash().as_str(),
+            user_credential.email_verified_at().as_ref()
         );
 
         match &self.conn {
             PgConnection::Pool(p) => query.execute(p).await,
             PgConnection::Transaction(tx) => query.execute(&mut *tx.lock().await).await,
-        }?;
+        }
+        .map_err(map_unique_violation)?;
 
         Ok(())
     }
 
-    async fn update(&self, user_credential: &UserCredential) -> Result<(), anyhow::Error> {
+    async fn update(&self, user_credential: &UserCredential) -> Result<(), RepositoryError> {
         let query = sqlx::query!(
             "
             UPDATE user_credentials
-            SET email = $1, password_hash = $2
-            WHERE user_id = $3
+            SET email = $1, password_hash = $2, email_verified_at = $3
+            WHERE user_id = $4
             ",
             user_credential.email().as_str(),
             user_credential.password_hash().as_str(),
+            user_credential.email_verified_at().as_ref(),
             user_credential.user_id().as_uuid(),
         );
 
-        match &self.conn {
+        let result = match &self.conn {
             PgConnection::Pool(p) => query.execute(p).await,
             PgConnection::Transaction(tx) => query.execute(&mut *tx.lock().await).await,
-        }?;
+        }
+        .map_err(map_unique_violation)?;
+
+        if result.rows_affected() == 0 {
+            return Err(RepositoryError::NotFound);
+        }
 
         Ok(())
     }
 
-    async fn delete(&self, user_id: &UserId) -> Result<(), anyhow::Error> {
+    async fn delete(&self, user_id: &UserId) -> Result<(), RepositoryError> {
         let query = sqlx::query!(
             "
             DELETE FROM user_credentials
@@ -125,20 +140,35 @@ impl<'a> UserCredentialRepository for PgUserCredentialRepository {
             user_id.as_uuid(),
         );
 
-        match &self.conn {
+        let result = match &self.conn {
             PgConnection::Pool(p) => query.execute(p).await,
             PgConnection::Transaction(tx) => query.execute(&mut *tx.lock().await).await,
-        }?;
+        }
+        .map_err(repository_error)?;
+
+        if result.rows_affected() == 0 {
+            return Err(RepositoryError::NotFound);
+        }
 
         Ok(())
     }
 }
 
+fn map_unique_violation(e: sqlx::Error) -> RepositoryError {
+    match &e {
+        sqlx::Error::Database(db_error) if db_error.constraint() == Some(EMAIL_UNIQUE_INDEX) => {
+            DomainError::EmailAlreadyTaken.into()
+        }
+        _ => repository_error(e),
+    }
+}
+
 #[derive(Debug)]
 struct UserCredentialRecord {
     user_id: Uuid,
     email: String,
     password_hash: String,
+    email_verified_at: Option<DateTime<Utc>>,
 }
 
 impl TryFrom<UserCredentialRecord> for UserCredential {
@@ -149,9 +179,12 @@ impl TryFrom<UserCredentialRecord> for UserCredential {
         let email = Email::try_from(value.email);
         let password_hash = PasswordHash::try_from(value.password_hash);
         match (email, password_hash) {
-            (Ok(email), Ok(password_hash)) => {
-                Ok(UserCredential::from((user_id, email, password_hash)))
-            }
+            (Ok(email), Ok(password_hash)) => Ok(UserCredential::from((
+                user_id,
+                email,
+                password_hash,
+                value.email_verified_at,
+            ))),
             (email, password_hash) => Self::Error::builder()
                 .result(name_of!(email), email)
                 .result(name_of!(password_hash), password_hash)
@@ -160,3 +193,48 @@ impl TryFrom<UserCredentialRecord> for UserCredential {
         }
     }
 }
+
+#[cfg(test)]
+mod tests {
+    use todo_app_domain::{
+        aggregate_root::user_credential::value_object::{Password, PasswordHasher},
+        error::ValidationError,
+    };
+
+    use super::*;
+
+    fn record(password_hash: String) -> UserCredentialRecord {
+        UserCredentialRecord {
+            user_id: Uuid::new_v4(),
+            email: "user@example.com".to_owned(),
+            password_hash,
+            email_verified_at: None,
+        }
+    }
+
+    #[test]
+    fn user_credential_from_record() {
+        let password = Password::try_from("password".to_owned()).unwrap();
+        let password_hash = password.to_hash(&PasswordHasher::default());
+
+        let user_credential =
+            UserCredential::try_from(record(password_hash.clone().into_string())).unwrap();
+
+        assert_eq!(user_credential.password_hash(), &password_hash);
+        assert!(user_credential.password_hash().verify(password.as_str()));
+    }
+
+    #[test]
+    fn user_credential_from_record_with_invalid_password_hash() {
+        let result = UserCredential::try_from(record("password".to_owned()));
+
+        let errors = result.unwrap_err().into_hash_map();
+        assert!(
+            matches!(
+                errors.get("password_hash"),
+                Some(ValidationError::PasswordHash(_))
+            ),
+            "{errors:?}"
+        );
+    }
+}
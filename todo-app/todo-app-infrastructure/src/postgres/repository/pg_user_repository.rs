@@ -7,11 +7,11 @@ use todo_app_domain::{
         repository::UserRepository,
         value_object::{UserId, UserName},
     },
-    error::ValidationErrors,
+    error::{RepositoryError, ValidationErrors},
 };
 use uuid::Uuid;
 
-use crate::postgres::database::PgConnection;
+use crate::postgres::database::{repository_error, PgConnection};
 
 #[derive(Debug)]
 pub struct PgUserRepository {
@@ -26,7 +26,7 @@ impl PgUserRepository {
 
 #[async_trait]
 impl UserRepository for PgUserRepository {
-    async fn find(&self, user_id: &UserId) -> Result<Option<User>, anyhow::Error> {
+    async fn find(&self, user_id: &UserId) -> Result<Option<User>, RepositoryError> {
         let query = sqlx::query_as!(
             UserRecord,
             "
@@ -40,7 +40,8 @@ impl UserRepository for PgUserRepository {
         let user = match &self.conn {
             PgConnection::Pool(p) => query.fetch_optional(p).await,
             PgConnection::Transaction(tx) => query.fetch_optional(&mut *tx.lock().await).await,
-        }?;
+        }
+        .map_err(repository_error)?;
 
         let user = match user {
             Some(u) => User::try_from(u),
@@ -50,7 +51,7 @@ impl UserRepository for PgUserRepository {
         Ok(Some(user))
     }
 
-    async fn insert(&self, user: &User) -> Result<(), anyhow::Error> {
+    async fn insert(&self, user: &User) -> Result<(), RepositoryError> {
         let query = sqlx::query!(
             "
             INSERT INTO users (id, name)
@@ -63,12 +64,13 @@ impl UserRepository for PgUserRepository {
         match &self.conn {
             PgConnection::Pool(p) => query.execute(p).await,
             PgConnection::Transaction(tx) => query.execute(&mut *tx.lock().await).await,
-        }?;
+        }
+        .map_err(repository_error)?;
 
         Ok(())
     }
 
-    async fn update(&self, user: &User) -> Result<(), anyhow::Error> {
+    async fn update(&self, user: &User) -> Result<(), RepositoryError> {
         let query = sqlx::query!(
             "
             UPDATE users
@@ -79,15 +81,20 @@ impl UserRepository for PgUserRepository {
             user.id().as_uuid(),
         );
 
-        match &self.conn {
+        let result = match &self.conn {
             PgConnection::Pool(p) => query.execute(p).await,
             PgConnection::Transaction(tx) => query.execute(&mut *tx.lock().await).await,
-        }?;
+        }
+        .map_err(repository_error)?;
+
+        if result.rows_affected() == 0 {
+            return Err(RepositoryError::NotFound);
+        }
 
         Ok(())
     }
 
-    async fn delete(&self, user_id: &UserId) -> Result<(), anyhow::Error> {
+    async fn delete(&self, user_id: &UserId) -> Result<(), RepositoryError> {
         let query = sqlx::query!(
             "
             DELETE FROM users
@@ -96,10 +103,15 @@ impl UserRepository for PgUserRepository {
             user_id.as_uuid(),
         );
 
-        match &self.conn {
+        let result = match &self.conn {
             PgConnection::Pool(p) => query.execute(p).await,
             PgConnection::Transaction(tx) => query.execute(&mut *tx.lock().await).await,
-        }?;
+        }
+        .map_err(repository_error)?;
+
+        if result.rows_affected() == 0 {
+            return Err(RepositoryError::NotFound);
+        }
 
         Ok(())
     }
@@ -1,3 +1,4 @@
 mod redis_session_store;
+mod session_payload;
 
 pub use redis_session_store::RedisSessionStore;
@@ -1,42 +1,207 @@
 use async_trait::async_trait;
+use chrono::Local;
 use redis::{AsyncCommands, Client};
 use todo_app_domain::aggregate_root::user::value_object::UserId;
-use todo_app_presentation::session::{Session, SessionStore};
+use todo_app_presentation::session::{Session, SessionExpiry, SessionStore};
 use uuid::Uuid;
 
+use crate::redis::session::session_payload;
+
 #[derive(Clone, Debug)]
 pub struct RedisSessionStore {
     client: Client,
+    session_expiry: SessionExpiry,
 }
 
 impl RedisSessionStore {
-    pub fn new(client: Client) -> Self {
-        Self { client }
+    pub fn new(client: Client, session_expiry: SessionExpiry) -> Self {
+        Self {
+            client,
+            session_expiry,
+        }
     }
 }
 
 #[async_trait]
 impl SessionStore for RedisSessionStore {
-    async fn find(&self, session_id: &str) -> Result<Option<UserId>, anyhow::Error> {
+    async fn find(&self, session_id: &str) -> Result<Option<Session>, anyhow::Error> {
         let mut conn = self.client.get_async_connection().await?;
-        let session_string = conn.get::<&str, String>(session_id).await?;
-        let session = Uuid::parse_str(&session_string).ok().map(UserId::from);
+        // Reads and renews the session in one transaction, so that it cannot expire in between.
+        // The renewal is shortened below if the absolute timeout is closer than the idle one.
+        let idle_timeout = self.session_expiry.idle_timeout().num_milliseconds().max(1) as usize;
+        let (session_string, _) = redis::pipe()
+            .atomic()
+            .get(session_id)
+            .pexpire(session_id, idle_timeout)
+            .query_async::<_, (Option<String>, bool)>(&mut conn)
+            .await?;
+        let session = match session_string.as_deref().map(session_payload::decode) {
+            Some(Ok(session)) => session,
+            // A session that cannot be read, e.g. one written by a newer version, is dropped,
+            // so that the client is asked to log in again instead of failing on every request.
+            Some(Err(e)) => {
+                tracing::warn!("discarding undecodable session: {e:?}");
+                conn.del::<&str, ()>(session_id).await?;
+                return Ok(None);
+            }
+            None => return Ok(None),
+        };
+
+        let ttl = match self.session_expiry.ttl(&session, Local::now()) {
+            Some(ttl) => ttl,
+            None => {
+                self.delete(session_id).await?;
+                return Ok(None);
+            }
+        };
+        if ttl.as_millis() < idle_timeout as u128 {
+            conn.pexpire::<&str, ()>(session_id, ttl.as_millis() as usize)
+                .await?;
+        }
 
-        Ok(session)
+        Ok(Some(session))
     }
 
     async fn save(&self, session_id: &str, session: &Session) -> Result<(), anyhow::Error> {
+        let ttl = match self.session_expiry.ttl(session, Local::now()) {
+            Some(ttl) => ttl,
+            None => return Ok(()),
+        };
+
         let mut conn = self.client.get_async_connection().await?;
-        let session_string = serde_json::to_string(&session).map_err(anyhow::Error::new)?;
-        conn.set(session_id, &session_string).await?;
+        let session_string = session_payload::encode(session)?;
+        let user_sessions_key = user_sessions_key(session.user_id());
+        redis::pipe()
+            .atomic()
+            .pset_ex(session_id, &session_string, ttl.as_millis() as usize)
+            .sadd(&user_sessions_key, session_id)
+            .expire(
+                &user_sessions_key,
+                self.session_expiry.absolute_timeout().num_seconds() as usize,
+            )
+            .query_async::<_, ()>(&mut conn)
+            .await?;
 
         Ok(())
     }
 
     async fn delete(&self, session_id: &str) -> Result<(), anyhow::Error> {
         let mut conn = self.client.get_async_connection().await?;
-        conn.del(&session_id).await?;
+        let session_string = conn.get::<&str, Option<String>>(session_id).await?;
+        // An undecodable session is still deleted, only without knowing its user.
+        let session = session_string
+            .as_deref()
+            .and_then(|session_string| session_payload::decode(session_string).ok());
+
+        let mut pipe = redis::pipe();
+        pipe.atomic().del(session_id);
+        if let Some(session) = session {
+            pipe.srem(user_sessions_key(session.user_id()), session_id);
+        }
+        pipe.query_async::<_, ()>(&mut conn).await?;
 
         Ok(())
     }
+
+    async fn delete_by_user_id(&self, user_id: &UserId) -> Result<(), anyhow::Error> {
+        let mut conn = self.client.get_async_connection().await?;
+        let user_sessions_key = user_sessions_key(user_id.as_uuid());
+        let session_ids = conn
+            .smembers::<&str, Vec<String>>(&user_sessions_key)
+            .await?;
+
+        let mut pipe = redis::pipe();
+        pipe.atomic().del(&user_sessions_key);
+        if !session_ids.is_empty() {
+            pipe.del(session_ids);
+        }
+        pipe.query_async::<_, ()>(&mut conn).await?;
+
+        Ok(())
+    }
+
+    async fn delete_by_user_id_except(
+        &self,
+        user_id: &UserId,
+        session_id: &str,
+    ) -> Result<(), anyhow::Error> {
+        let mut conn = self.client.get_async_connection().await?;
+        let user_sessions_key = user_sessions_key(user_id.as_uuid());
+        let session_ids = conn
+            .smembers::<&str, Vec<String>>(&user_sessions_key)
+            .await?
+            .into_iter()
+            .filter(|id| id != session_id)
+            .collect::<Vec<_>>();
+        if session_ids.is_empty() {
+            return Ok(());
+        }
+
+        redis::pipe()
+            .atomic()
+            .srem(&user_sessions_key, &session_ids)
+            .del(&session_ids)
+            .query_async::<_, ()>(&mut conn)
+            .await?;
+
+        Ok(())
+    }
+}
+
+fn user_sessions_key(user_id: &Uuid) -> String {
+    format!("user_sessions:{user_id}")
+}
+
+#[cfg(all(test, feature = "redis-tests"))]
+mod tests {
+    use chrono::Duration;
+
+    use super::*;
+    use crate::session_store_contract;
+
+    fn session_store(session_expiry: SessionExpiry) -> RedisSessionStore {
+        let url = std::env::var("REDIS_URL").unwrap_or_else(|_| "redis://127.0.0.1/".to_owned());
+        RedisSessionStore::new(Client::open(url).unwrap(), session_expiry)
+    }
+
+    #[tokio::test]
+    async fn redis_session_store_round_trip() {
+        let session_store = session_store(SessionExpiry::default());
+        session_store_contract::round_trip(&session_store).await;
+    }
+
+    #[tokio::test]
+    async fn redis_session_store_delete_by_user_id() {
+        let session_store = session_store(SessionExpiry::default());
+        session_store_contract::delete_by_user_id(&session_store).await;
+    }
+
+    #[tokio::test]
+    async fn redis_session_store_undecodable() {
+        let session_store = session_store(SessionExpiry::default());
+        let mut conn = session_store.client.get_async_connection().await.unwrap();
+
+        for payload in ["not json", r#"{"version":999,"user_id":"unknown"}"#] {
+            let session_id = format!("test_session:{}", Uuid::new_v4());
+            conn.set::<&str, &str, ()>(&session_id, payload)
+                .await
+                .unwrap();
+
+            let result = session_store.find(&session_id).await;
+            assert!(
+                matches!(result, Ok(None)),
+                "payload: {payload}, result: {result:?}"
+            );
+            let stored = conn.get::<&str, Option<String>>(&session_id).await.unwrap();
+            assert_eq!(stored, None, "payload: {payload}");
+        }
+    }
+
+    #[tokio::test]
+    async fn redis_session_store_expired() {
+        let session_store =
+            session_store(SessionExpiry::new(Duration::seconds(1), Duration::days(1)));
+        session_store_contract::expired(&session_store, std::time::Duration::from_millis(1500))
+            .await;
+    }
 }
@@ -0,0 +1,96 @@
+use anyhow::anyhow;
+use serde::{Deserialize, Serialize};
+use todo_app_presentation::session::Session;
+
+/// Version of the JSON payload written by `encode`.
+///
+/// Bump it whenever the shape of `Session` changes and teach `decode` how to read older versions,
+/// so that sessions stored before a deploy keep working afterwards.
+const SESSION_PAYLOAD_VERSION: u32 = 1;
+
+#[derive(Debug, Deserialize, Serialize)]
+struct SessionPayload<T> {
+    // Payloads written before versioning was introduced have no `version` field.
+    #[serde(default)]
+    version: u32,
+    #[serde(flatten)]
+    session: T,
+}
+
+pub fn encode(session: &Session) -> Result<String, anyhow::Error> {
+    let payload = SessionPayload {
+        version: SESSION_PAYLOAD_VERSION,
+        session,
+    };
+    let payload = serde_json::to_string(&payload)?;
+
+    Ok(payload)
+}
+
+pub fn decode(payload: &str) -> Result<Session, anyhow::Error> {
+    let payload = serde_json::from_str::<SessionPayload<serde_json::Value>>(payload)?;
+    match payload.version {
+        // Unversioned payloads have the same shape as version 1.
+        0 | SESSION_PAYLOAD_VERSION => Ok(serde_json::from_value(payload.session)?),
+        version => Err(anyhow!("unsupported session payload version: {version}")),
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use chrono::DateTime;
+    use todo_app_domain::aggregate_root::user::value_object::UserId;
+
+    use super::*;
+
+    #[test]
+    fn session_payload_round_trip() {
+        let session = Session::new(UserId::new());
+
+        let payload = encode(&session).unwrap();
+        let decoded = decode(&payload).unwrap();
+
+        assert_eq!(decoded, session, "payload: {payload}");
+    }
+
+    #[test]
+    fn session_payload_decode() {
+        let user_id = "67e55044-10b1-426f-9247-bb680e5fe0c8";
+        let logged_in_at = "2022-07-20T12:34:56.789+09:00";
+
+        let tests = vec![
+            (
+                format!(r#"{{"version":1,"user_id":"{user_id}","logged_in_at":"{logged_in_at}"}}"#),
+                true,
+            ),
+            (
+                format!(r#"{{"user_id":"{user_id}","logged_in_at":"{logged_in_at}"}}"#),
+                true,
+            ),
+            (
+                format!(r#"{{"version":2,"user_id":"{user_id}","logged_in_at":"{logged_in_at}"}}"#),
+                false,
+            ),
+            (format!(r#""{user_id}""#), false),
+        ];
+
+        for (input, expected) in tests {
+            let result = decode(&input);
+            assert_eq!(
+                result.is_ok(),
+                expected,
+                "input: {input}, result: {result:?}"
+            );
+            if let Ok(session) = result {
+                assert_eq!(session.user_id().to_string(), user_id, "input: {input}");
+                assert_eq!(
+                    session.logged_in_at().timestamp_millis(),
+                    DateTime::parse_from_rfc3339(logged_in_at)
+                        .unwrap()
+                        .timestamp_millis(),
+                    "input: {input}"
+                );
+            }
+        }
+    }
+}
@@ -0,0 +1,3 @@
+mod redis_login_attempt_store;
+
+pub use redis_login_attempt_store::RedisLoginAttemptStore;
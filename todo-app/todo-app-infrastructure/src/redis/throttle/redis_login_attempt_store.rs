@@ -0,0 +1,92 @@
+use anyhow::anyhow;
+use async_trait::async_trait;
+use chrono::{DateTime, Duration, TimeZone, Utc};
+use redis::{AsyncCommands, Client};
+use todo_app_application::throttle::LoginAttemptStore;
+
+#[derive(Clone, Debug)]
+pub struct RedisLoginAttemptStore {
+    client: Client,
+}
+
+impl RedisLoginAttemptStore {
+    pub fn new(client: Client) -> Self {
+        Self { client }
+    }
+}
+
+#[async_trait]
+impl LoginAttemptStore for RedisLoginAttemptStore {
+    async fn record_failure(&self, key: &str, window: Duration) -> Result<u32, anyhow::Error> {
+        let mut conn = self.client.get_async_connection().await?;
+        let failures_key = failures_key(key);
+        let (failures, ttl) = redis::pipe()
+            .atomic()
+            .incr(&failures_key, 1)
+            .pttl(&failures_key)
+            .query_async::<_, (u32, i64)>(&mut conn)
+            .await?;
+        // Never shorten the expiry set by a lockout.
+        let window = window.num_milliseconds();
+        if ttl < window {
+            conn.pexpire::<_, ()>(&failures_key, window.max(1) as usize)
+                .await?;
+        }
+
+        Ok(failures)
+    }
+
+    async fn lock(
+        &self,
+        key: &str,
+        until: DateTime<Utc>,
+        window: Duration,
+    ) -> Result<(), anyhow::Error> {
+        let ttl = (until - Utc::now()).num_milliseconds();
+        if ttl <= 0 {
+            return Ok(());
+        }
+
+        let mut conn = self.client.get_async_connection().await?;
+        redis::pipe()
+            .atomic()
+            .pset_ex(lockout_key(key), until.timestamp_millis(), ttl as usize)
+            .pexpire(
+                failures_key(key),
+                (ttl + window.num_milliseconds().max(0)) as usize,
+            )
+            .query_async::<_, ()>(&mut conn)
+            .await?;
+
+        Ok(())
+    }
+
+    async fn locked_until(&self, key: &str) -> Result<Option<DateTime<Utc>>, anyhow::Error> {
+        let mut conn = self.client.get_async_connection().await?;
+        let locked_until = conn.get::<_, Option<i64>>(lockout_key(key)).await?;
+
+        locked_until
+            .map(|millis| {
+                Utc.timestamp_millis_opt(millis)
+                    .single()
+                    .ok_or_else(|| anyhow!("invalid lockout timestamp: {millis}"))
+            })
+            .transpose()
+    }
+
+    async fn reset(&self, key: &str) -> Result<(), anyhow::Error> {
+        let mut conn = self.client.get_async_connection().await?;
+        conn.del::<_, ()>(&[failures_key(key), lockout_key(key)])
+            .await?;
+
+        Ok(())
+    }
+}
+
+fn failures_key(key: &str) -> String {
+    format!("login_failures:{key}")
+}
+
+fn lockout_key(key: &str) -> String {
+    format!("login_lockout:{key}")
+}
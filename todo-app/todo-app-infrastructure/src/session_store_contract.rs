@@ -0,0 +1,89 @@
+//! Behavior every `SessionStore` backend must share, run against each of them by their tests.
+
+use std::time::Duration;
+
+use todo_app_domain::aggregate_root::user::value_object::UserId;
+use todo_app_presentation::session::{Session, SessionStore};
+
+/// `find` returns the saved session, and nothing for an unknown or deleted one.
+pub async fn round_trip(session_store: &dyn SessionStore) {
+    let session_id = unique_session_id();
+    let session = Session::new(UserId::new());
+
+    session_store.save(&session_id, &session).await.unwrap();
+    assert_eq!(
+        session_store.find(&session_id).await.unwrap(),
+        Some(session.clone())
+    );
+    assert_eq!(
+        session_store.find(&session_id).await.unwrap(),
+        Some(session),
+        "a found session is still there"
+    );
+    assert_eq!(
+        session_store.find(&unique_session_id()).await.unwrap(),
+        None
+    );
+
+    session_store.delete(&session_id).await.unwrap();
+    assert_eq!(session_store.find(&session_id).await.unwrap(), None);
+    session_store.delete(&session_id).await.unwrap();
+}
+
+pub async fn delete_by_user_id(session_store: &dyn SessionStore) {
+    let user_id = UserId::new();
+    let session = Session::new(user_id.clone());
+    let other_session = Session::new(UserId::new());
+    let (current, other, other_user) = (
+        unique_session_id(),
+        unique_session_id(),
+        unique_session_id(),
+    );
+
+    for session_id in [&current, &other] {
+        session_store.save(session_id, &session).await.unwrap();
+    }
+    session_store
+        .save(&other_user, &other_session)
+        .await
+        .unwrap();
+    session_store
+        .delete_by_user_id_except(&user_id, &current)
+        .await
+        .unwrap();
+
+    let tests = vec![
+        (&current, Some(session)),
+        (&other, None),
+        (&other_user, Some(other_session.clone())),
+    ];
+    for (session_id, expected) in tests {
+        assert_eq!(
+            session_store.find(session_id).await.unwrap(),
+            expected,
+            "session_id: {session_id}"
+        );
+    }
+
+    session_store.delete_by_user_id(&user_id).await.unwrap();
+    assert_eq!(session_store.find(&current).await.unwrap(), None);
+    assert_eq!(
+        session_store.find(&other_user).await.unwrap(),
+        Some(other_session)
+    );
+}
+
+/// Expects a store whose sessions expire in less than `wait`.
+pub async fn expired(session_store: &dyn SessionStore, wait: Duration) {
+    let session_id = unique_session_id();
+    let session = Session::new(UserId::new());
+
+    session_store.save(&session_id, &session).await.unwrap();
+    tokio::time::sleep(wait).await;
+
+    assert_eq!(session_store.find(&session_id).await.unwrap(), None);
+}
+
+fn unique_session_id() -> String {
+    format!("test_session:{}", uuid::Uuid::new_v4())
+}
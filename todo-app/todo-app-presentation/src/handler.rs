@@ -1,3 +1,11 @@
+pub mod credential_handler;
+pub mod email_verification_handler;
 pub mod error;
 pub mod login_handler;
+pub mod logout_handler;
+pub mod me_handler;
+pub mod password_reset_handler;
 pub mod signup_handler;
+pub mod tag_handler;
+pub mod todo_handler;
+pub mod todo_list_handler;
@@ -0,0 +1,78 @@
+use std::{net::SocketAddr, sync::Arc};
+
+use axum::{extract::ConnectInfo, Extension, Json};
+use serde::{Deserialize, Serialize};
+use todo_app_application::usecase::{ChangeEmailUsecase, ChangePasswordUsecase};
+
+use crate::{
+    handler::error::HandlerError,
+    session::{CurrentUser, SessionStore},
+};
+
+#[derive(Debug, Deserialize)]
+pub struct ChangePasswordRequest {
+    current_password: String,
+    new_password: String,
+}
+
+#[derive(Debug, Deserialize)]
+pub struct ChangeEmailRequest {
+    password: String,
+    email: String,
+}
+
+#[derive(Debug, Default, Serialize)]
+pub struct ChangePasswordResponse {
+    message: &'static str,
+}
+
+#[derive(Debug, Serialize)]
+pub struct ChangeEmailResponse {
+    message: &'static str,
+    email: String,
+}
+
+/// Changes the password and logs out every other session of the user.
+pub async fn change_password(
+    current_user: CurrentUser,
+    ConnectInfo(addr): ConnectInfo<SocketAddr>,
+    Json(request): Json<ChangePasswordRequest>,
+    Extension(change_password_usecase): Extension<ChangePasswordUsecase>,
+    Extension(session_store): Extension<Arc<dyn SessionStore>>,
+) -> Result<Json<ChangePasswordResponse>, HandlerError> {
+    change_password_usecase
+        .execute(
+            current_user.user_id(),
+            request.current_password,
+            request.new_password,
+            addr.ip(),
+        )
+        .await?;
+
+    session_store
+        .delete_by_user_id_except(current_user.user_id(), current_user.session_id())
+        .await?;
+
+    Ok(Json(ChangePasswordResponse { message: "ok" }))
+}
+
+pub async fn change_email(
+    current_user: CurrentUser,
+    ConnectInfo(addr): ConnectInfo<SocketAddr>,
+    Json(request): Json<ChangeEmailRequest>,
+    Extension(change_email_usecase): Extension<ChangeEmailUsecase>,
+) -> Result<Json<ChangeEmailResponse>, HandlerError> {
+    let email = change_email_usecase
+        .execute(
+            current_user.user_id(),
+            request.password,
+            request.email,
+            addr.ip(),
+        )
+        .await?;
+
+    Ok(Json(ChangeEmailResponse {
+        message: "ok",
+        email: email.into_string(),
+    }))
+}
@@ -0,0 +1,24 @@
+use axum::{Extension, Json};
+use serde::{Deserialize, Serialize};
+use todo_app_application::usecase::VerifyEmailUsecase;
+
+use crate::handler::error::HandlerError;
+
+#[derive(Debug, Deserialize)]
+pub struct VerifyEmailRequest {
+    token: String,
+}
+
+#[derive(Debug, Default, Serialize)]
+pub struct VerifyEmailResponse {
+    message: &'static str,
+}
+
+pub async fn verify_email(
+    Json(request): Json<VerifyEmailRequest>,
+    Extension(verify_email_usecase): Extension<VerifyEmailUsecase>,
+) -> Result<Json<VerifyEmailResponse>, HandlerError> {
+    verify_email_usecase.execute(request.token).await?;
+
+    Ok(Json(VerifyEmailResponse { message: "ok" }))
+}
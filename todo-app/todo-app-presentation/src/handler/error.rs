@@ -1,8 +1,9 @@
 use axum::response::IntoResponse;
 use thiserror::Error;
 use todo_app_application::usecase::error::UsecaseError;
+use todo_app_domain::error::{DomainError, RepositoryError};
 
-use crate::response::ErrorResponse;
+use crate::response::{ErrorDetail, ErrorResponse};
 
 #[derive(Debug, Error)]
 pub enum HandlerError {
@@ -17,9 +18,36 @@ pub enum HandlerError {
 impl IntoResponse for HandlerError {
     fn into_response(self) -> axum::response::Response {
         match self {
-            Self::Usecase(e) => ErrorResponse::bad_request(e.to_string(), Default::default()),
+            Self::Usecase(UsecaseError::Expected { message, errors }) => {
+                ErrorResponse::bad_request(message, ErrorDetail::from_validation_errors(errors))
+            }
+            Self::Usecase(UsecaseError::Forbidden { message }) => ErrorResponse::forbidden(message),
+            Self::Usecase(UsecaseError::TooManyRequests { retry_after }) => {
+                // Round up, so that a retry right after the wait is not rejected again.
+                let seconds = (retry_after.num_milliseconds() as u64).div_ceil(1000);
+                ErrorResponse::too_many_requests(seconds.max(1))
+            }
+            Self::Usecase(UsecaseError::NotFound { message }) => ErrorResponse::not_found(message),
+            Self::Usecase(UsecaseError::Domain(
+                e @ (DomainError::EmailAlreadyTaken | DomainError::TagNameAlreadyTaken),
+            )) => ErrorResponse::conflict(e.to_string()),
+            Self::Usecase(UsecaseError::Repository(RepositoryError::Conflict(e))) => {
+                tracing::warn!("{e}");
+                ErrorResponse::conflict("conflict")
+            }
+            Self::Usecase(UsecaseError::Repository(
+                e @ RepositoryError::ConcurrentModification,
+            )) => ErrorResponse::conflict(e.to_string()),
+            Self::Usecase(UsecaseError::Repository(e @ RepositoryError::Unavailable(_))) => {
+                tracing::error!("{e:?}");
+                ErrorResponse::service_unavailable()
+            }
+            Self::Usecase(UsecaseError::Repository(e)) => {
+                tracing::error!("{e:?}");
+                ErrorResponse::internal_server_error()
+            }
             Self::Authentication => ErrorResponse::unauthorized(),
-            Self::Unexpected(e) => {
+            Self::Usecase(UsecaseError::Unexpected(e)) | Self::Unexpected(e) => {
                 tracing::error!("{e:?}");
                 ErrorResponse::internal_server_error()
             }
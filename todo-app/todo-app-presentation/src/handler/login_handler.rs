@@ -1,16 +1,14 @@
-use std::sync::Arc;
+use std::{net::SocketAddr, sync::Arc};
 
-use axum::{Extension, Json};
-use cookie::{time::OffsetDateTime, SameSite};
+use axum::{extract::ConnectInfo, Extension, Json};
 use serde::{Deserialize, Serialize};
-use time::Duration;
 use todo_app_application::usecase::LoginUsecase;
-use tower_cookies::{Cookie, Cookies};
+use tower_cookies::Cookies;
 use uuid::Uuid;
 
 use crate::{
     handler::error::HandlerError,
-    session::{Session, SessionStore, SESSION_ID_HEADER},
+    session::{Session, SessionCookie, SessionStore},
 };
 
 #[derive(Debug, Deserialize)]
@@ -26,24 +24,21 @@ pub struct LoginResponse {
 
 pub async fn login(
     cookies: Cookies,
+    ConnectInfo(addr): ConnectInfo<SocketAddr>,
     Json(request): Json<LoginRequest>,
     Extension(login_usecase): Extension<LoginUsecase>,
     Extension(session_store): Extension<Arc<dyn SessionStore>>,
+    Extension(session_cookie): Extension<SessionCookie>,
 ) -> Result<Json<LoginResponse>, HandlerError> {
     let user_id = login_usecase
-        .execute(&request.email, &request.password)
+        .execute(&request.email, &request.password, addr.ip())
         .await?;
 
     let session_id = format!("{}{}", Uuid::new_v4().simple(), Uuid::new_v4().simple());
     let session = Session::new(user_id);
     session_store.save(&session_id, &session).await?;
 
-    let cookie = Cookie::build(SESSION_ID_HEADER, session_id)
-        .expires(OffsetDateTime::now_utc() + Duration::days(30))
-        .http_only(true)
-        .same_site(SameSite::Strict)
-        .finish();
-    cookies.add(cookie);
+    cookies.add(session_cookie.build(session_id));
 
     Ok(Json(LoginResponse { message: "ok" }))
 }
@@ -0,0 +1,41 @@
+use std::sync::Arc;
+
+use axum::{Extension, Json};
+use serde::Serialize;
+use tower_cookies::Cookies;
+
+use crate::{
+    handler::error::HandlerError,
+    session::{CurrentUser, SessionCookie, SessionStore},
+};
+
+#[derive(Debug, Default, Serialize)]
+pub struct LogoutResponse {
+    message: &'static str,
+}
+
+pub async fn logout(
+    cookies: Cookies,
+    current_user: CurrentUser,
+    Extension(session_store): Extension<Arc<dyn SessionStore>>,
+    Extension(session_cookie): Extension<SessionCookie>,
+) -> Result<Json<LogoutResponse>, HandlerError> {
+    session_store.delete(current_user.session_id()).await?;
+    cookies.remove(session_cookie.removal());
+
+    Ok(Json(LogoutResponse { message: "ok" }))
+}
+
+pub async fn logout_all(
+    cookies: Cookies,
+    current_user: CurrentUser,
+    Extension(session_store): Extension<Arc<dyn SessionStore>>,
+    Extension(session_cookie): Extension<SessionCookie>,
+) -> Result<Json<LogoutResponse>, HandlerError> {
+    session_store
+        .delete_by_user_id(current_user.user_id())
+        .await?;
+    cookies.remove(session_cookie.removal());
+
+    Ok(Json(LogoutResponse { message: "ok" }))
+}
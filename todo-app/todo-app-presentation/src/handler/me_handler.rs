@@ -0,0 +1,78 @@
+use std::sync::Arc;
+
+use axum::{Extension, Json};
+use serde::{Deserialize, Serialize};
+use todo_app_application::usecase::{DeleteUserUsecase, GetUserUsecase, UpdateUserUsecase};
+use tower_cookies::Cookies;
+use uuid::Uuid;
+
+use crate::{
+    handler::error::HandlerError,
+    session::{CurrentUser, SessionCookie, SessionStore},
+};
+
+#[derive(Debug, Deserialize)]
+pub struct UpdateMeRequest {
+    name: Option<String>,
+}
+
+#[derive(Debug, Serialize)]
+pub struct MeResponse {
+    id: Uuid,
+    name: String,
+    email: String,
+    email_verified: bool,
+}
+
+#[derive(Debug, Default, Serialize)]
+pub struct DeleteMeResponse {
+    message: &'static str,
+}
+
+pub async fn get_me(
+    current_user: CurrentUser,
+    Extension(get_user_usecase): Extension<GetUserUsecase>,
+) -> Result<Json<MeResponse>, HandlerError> {
+    let (user, user_credential) = get_user_usecase.execute(current_user.user_id()).await?;
+    let (id, name) = user.into_inner();
+    let email_verified = user_credential.is_email_verified();
+    let (_, email, _, _) = user_credential.into_inner();
+
+    Ok(Json(MeResponse {
+        id: id.into_uuid(),
+        name: name.into_string(),
+        email: email.into_string(),
+        email_verified,
+    }))
+}
+
+pub async fn update_me(
+    current_user: CurrentUser,
+    Json(request): Json<UpdateMeRequest>,
+    Extension(update_user_usecase): Extension<UpdateUserUsecase>,
+    Extension(get_user_usecase): Extension<GetUserUsecase>,
+) -> Result<Json<MeResponse>, HandlerError> {
+    update_user_usecase
+        .execute(current_user.user_id(), request.name)
+        .await?;
+
+    get_me(current_user, Extension(get_user_usecase)).await
+}
+
+/// Deletes the account with all its data and logs out every session of the user.
+pub async fn delete_me(
+    cookies: Cookies,
+    current_user: CurrentUser,
+    Extension(delete_user_usecase): Extension<DeleteUserUsecase>,
+    Extension(session_store): Extension<Arc<dyn SessionStore>>,
+    Extension(session_cookie): Extension<SessionCookie>,
+) -> Result<Json<DeleteMeResponse>, HandlerError> {
+    delete_user_usecase.execute(current_user.user_id()).await?;
+
+    session_store
+        .delete_by_user_id(current_user.user_id())
+        .await?;
+    cookies.remove(session_cookie.removal());
+
+    Ok(Json(DeleteMeResponse { message: "ok" }))
+}
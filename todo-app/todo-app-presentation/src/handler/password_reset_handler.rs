@@ -0,0 +1,51 @@
+use std::sync::Arc;
+
+use axum::{http::StatusCode, Extension, Json};
+use serde::{Deserialize, Serialize};
+use todo_app_application::usecase::{RequestPasswordResetUsecase, ResetPasswordUsecase};
+
+use crate::{handler::error::HandlerError, session::SessionStore};
+
+#[derive(Debug, Deserialize)]
+pub struct RequestPasswordResetRequest {
+    email: String,
+}
+
+#[derive(Debug, Deserialize)]
+pub struct ResetPasswordRequest {
+    token: String,
+    password: String,
+}
+
+#[derive(Debug, Default, Serialize)]
+pub struct PasswordResetResponse {
+    message: &'static str,
+}
+
+pub async fn request_password_reset(
+    Json(request): Json<RequestPasswordResetRequest>,
+    Extension(request_password_reset_usecase): Extension<RequestPasswordResetUsecase>,
+) -> (StatusCode, Json<PasswordResetResponse>) {
+    request_password_reset_usecase.execute(request.email);
+
+    (
+        StatusCode::ACCEPTED,
+        Json(PasswordResetResponse {
+            message: "accepted",
+        }),
+    )
+}
+
+pub async fn reset_password(
+    Json(request): Json<ResetPasswordRequest>,
+    Extension(reset_password_usecase): Extension<ResetPasswordUsecase>,
+    Extension(session_store): Extension<Arc<dyn SessionStore>>,
+) -> Result<Json<PasswordResetResponse>, HandlerError> {
+    let user_id = reset_password_usecase
+        .execute(request.token, request.password)
+        .await?;
+
+    session_store.delete_by_user_id(&user_id).await?;
+
+    Ok(Json(PasswordResetResponse { message: "ok" }))
+}
@@ -1,16 +1,33 @@
-use axum::{response::IntoResponse, Extension};
-
+use axum::{Extension, Json};
+use serde::{Deserialize, Serialize};
 use todo_app_application::usecase::SignupUsecase;
+use uuid::Uuid;
+
+use crate::handler::error::HandlerError;
+
+#[derive(Debug, Deserialize)]
+pub struct SignupRequest {
+    name: String,
+    email: String,
+    password: String,
+}
+
+#[derive(Debug, Serialize)]
+pub struct SignupResponse {
+    message: &'static str,
+    user_id: Uuid,
+}
 
-pub async fn signup(Extension(signup_usecase): Extension<SignupUsecase>) -> impl IntoResponse {
-    signup_usecase
-        .execute(
-            "user name".to_owned(),
-            "user@example.com".to_owned(),
-            "password".to_owned(),
-        )
-        .await
-        .unwrap();
+pub async fn signup(
+    Json(request): Json<SignupRequest>,
+    Extension(signup_usecase): Extension<SignupUsecase>,
+) -> Result<Json<SignupResponse>, HandlerError> {
+    let user_id = signup_usecase
+        .execute(request.name, request.email, request.password)
+        .await?;
 
-    "Hello, world!!!"
+    Ok(Json(SignupResponse {
+        message: "ok",
+        user_id: user_id.into_uuid(),
+    }))
 }
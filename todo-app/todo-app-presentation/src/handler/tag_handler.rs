@@ -0,0 +1,116 @@
+use axum::{extract::Path, Extension, Json};
+use serde::{Deserialize, Serialize};
+use todo_app_application::usecase::{
+    CreateTagUsecase, DeleteTagUsecase, GetTagUsecase, ListTagsUsecase, UpdateTagUsecase,
+};
+use todo_app_domain::aggregate_root::tag::{entity::Tag, value_object::TagId};
+use uuid::Uuid;
+
+use crate::{handler::error::HandlerError, session::CurrentUser};
+
+#[derive(Debug, Deserialize)]
+pub struct CreateTagRequest {
+    name: String,
+    /// `#rrggbb`
+    color: String,
+}
+
+/// Omitted fields are left as they are.
+#[derive(Debug, Deserialize)]
+pub struct UpdateTagRequest {
+    name: Option<String>,
+    color: Option<String>,
+}
+
+#[derive(Debug, Serialize)]
+pub struct TagResponse {
+    id: Uuid,
+    name: String,
+    color: String,
+}
+
+impl From<Tag> for TagResponse {
+    fn from(tag: Tag) -> Self {
+        let (id, _, name, color) = tag.into_inner();
+        Self {
+            id: id.into_uuid(),
+            name: name.into_string(),
+            color: color.into_string(),
+        }
+    }
+}
+
+#[derive(Debug, Serialize)]
+pub struct TagsResponse {
+    tags: Vec<TagResponse>,
+}
+
+#[derive(Debug, Default, Serialize)]
+pub struct DeleteTagResponse {
+    message: &'static str,
+}
+
+pub async fn list_tags(
+    current_user: CurrentUser,
+    Extension(list_tags_usecase): Extension<ListTagsUsecase>,
+) -> Result<Json<TagsResponse>, HandlerError> {
+    let tags = list_tags_usecase.execute(current_user.user_id()).await?;
+
+    Ok(Json(TagsResponse {
+        tags: tags.into_iter().map(TagResponse::from).collect(),
+    }))
+}
+
+pub async fn create_tag(
+    current_user: CurrentUser,
+    Json(request): Json<CreateTagRequest>,
+    Extension(create_tag_usecase): Extension<CreateTagUsecase>,
+) -> Result<Json<TagResponse>, HandlerError> {
+    let tag = create_tag_usecase
+        .execute(current_user.into_user_id(), request.name, request.color)
+        .await?;
+
+    Ok(Json(tag.into()))
+}
+
+pub async fn get_tag(
+    current_user: CurrentUser,
+    Path(tag_id): Path<Uuid>,
+    Extension(get_tag_usecase): Extension<GetTagUsecase>,
+) -> Result<Json<TagResponse>, HandlerError> {
+    let tag = get_tag_usecase
+        .execute(current_user.user_id(), &TagId::from(tag_id))
+        .await?;
+
+    Ok(Json(tag.into()))
+}
+
+pub async fn update_tag(
+    current_user: CurrentUser,
+    Path(tag_id): Path<Uuid>,
+    Json(request): Json<UpdateTagRequest>,
+    Extension(update_tag_usecase): Extension<UpdateTagUsecase>,
+) -> Result<Json<TagResponse>, HandlerError> {
+    let tag = update_tag_usecase
+        .execute(
+            current_user.user_id(),
+            &TagId::from(tag_id),
+            request.name,
+            request.color,
+        )
+        .await?;
+
+    Ok(Json(tag.into()))
+}
+
+pub async fn delete_tag(
+    current_user: CurrentUser,
+    Path(tag_id): Path<Uuid>,
+    Extension(delete_tag_usecase): Extension<DeleteTagUsecase>,
+) -> Result<Json<DeleteTagResponse>, HandlerError> {
+    delete_tag_usecase
+        .execute(current_user.user_id(), &TagId::from(tag_id))
+        .await?;
+
+    Ok(Json(DeleteTagResponse { message: "ok" }))
+}
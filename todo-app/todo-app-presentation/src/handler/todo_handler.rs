@@ -0,0 +1,312 @@
+use axum::{
+    extract::{Path, Query},
+    Extension, Json,
+};
+use chrono::{DateTime, NaiveDate, Utc};
+use serde::{Deserialize, Deserializer, Serialize};
+use todo_app_application::usecase::{
+    CreateTodoUsecase, DeleteTodoUsecase, GetTodoUsecase, ListTodosQuery, ListTodosUsecase,
+    MoveTodoUsecase, SearchTodosUsecase, SetTodoTagsUsecase, UpdateTodoUsecase,
+};
+use todo_app_domain::aggregate_root::{
+    tag::value_object::TagId,
+    todo::{
+        entity::Todo,
+        repository::TodoSearchHit,
+        value_object::{TodoDescription, TodoId},
+    },
+    todo_list::value_object::TodoListId,
+};
+use uuid::Uuid;
+
+use crate::{handler::error::HandlerError, session::CurrentUser};
+
+#[derive(Debug, Deserialize)]
+pub struct ListTodosRequest {
+    /// ID of the todo list the todos must belong to
+    list: Option<String>,
+    /// `open` or `completed`
+    status: Option<String>,
+    /// `YYYY-MM-DD`, exclusive
+    due_before: Option<String>,
+    /// `YYYY-MM-DD`, exclusive
+    due_after: Option<String>,
+    contains: Option<String>,
+    /// Comma-separated tag IDs, all of which a todo must have
+    tags: Option<String>,
+    /// `created_at` (default), `updated_at`, `due_date` or `title`
+    sort: Option<String>,
+    /// `asc` (default) or `desc`
+    order: Option<String>,
+    /// `next_cursor` of the previous page, with the same `sort` and `order`
+    cursor: Option<String>,
+    limit: Option<String>,
+}
+
+impl From<ListTodosRequest> for ListTodosQuery {
+    fn from(request: ListTodosRequest) -> Self {
+        Self {
+            list: request.list,
+            status: request.status,
+            due_before: request.due_before,
+            due_after: request.due_after,
+            contains: request.contains,
+            tags: request.tags,
+            sort: request.sort,
+            order: request.order,
+            cursor: request.cursor,
+            limit: request.limit,
+        }
+    }
+}
+
+#[derive(Debug, Deserialize)]
+pub struct SearchTodosRequest {
+    #[serde(default)]
+    q: String,
+    limit: Option<String>,
+}
+
+#[derive(Debug, Deserialize)]
+pub struct CreateTodoRequest {
+    title: String,
+    description: Option<String>,
+    /// `YYYY-MM-DD`
+    due_date: Option<String>,
+    /// The inbox if omitted
+    todo_list_id: Option<Uuid>,
+}
+
+/// Omitted fields are left as they are; `null` clears `description` and `due_date`.
+#[derive(Debug, Deserialize)]
+pub struct UpdateTodoRequest {
+    title: Option<String>,
+    #[serde(default, deserialize_with = "present")]
+    description: Option<Option<String>>,
+    #[serde(default, deserialize_with = "present")]
+    due_date: Option<Option<String>>,
+    completed: Option<bool>,
+}
+
+/// Tells a field set to `null` (`Some(None)`) apart from an omitted one (`None`).
+fn present<'de, D, T>(deserializer: D) -> Result<Option<Option<T>>, D::Error>
+where
+    D: Deserializer<'de>,
+    T: Deserialize<'de>,
+{
+    Option::deserialize(deserializer).map(Some)
+}
+
+#[derive(Debug, Deserialize)]
+pub struct SetTodoTagsRequest {
+    tag_ids: Vec<Uuid>,
+}
+
+#[derive(Debug, Deserialize)]
+pub struct MoveTodoRequest {
+    todo_list_id: Uuid,
+}
+
+#[derive(Debug, Serialize)]
+pub struct TodoResponse {
+    id: Uuid,
+    todo_list_id: Uuid,
+    title: String,
+    description: Option<String>,
+    due_date: Option<NaiveDate>,
+    tag_ids: Vec<Uuid>,
+    completed: bool,
+    completed_at: Option<DateTime<Utc>>,
+    created_at: DateTime<Utc>,
+    updated_at: DateTime<Utc>,
+}
+
+impl From<Todo> for TodoResponse {
+    fn from(todo: Todo) -> Self {
+        let (
+            id,
+            _,
+            todo_list_id,
+            title,
+            description,
+            due_date,
+            tag_ids,
+            completed_at,
+            created_at,
+            updated_at,
+        ) = todo.into_inner();
+        Self {
+            id: id.into_uuid(),
+            todo_list_id: todo_list_id.into_uuid(),
+            title: title.into_string(),
+            description: description.map(TodoDescription::into_string),
+            due_date,
+            tag_ids: tag_ids.into_iter().map(TagId::into_uuid).collect(),
+            completed: completed_at.is_some(),
+            completed_at,
+            created_at,
+            updated_at,
+        }
+    }
+}
+
+#[derive(Debug, Serialize)]
+pub struct TodosResponse {
+    todos: Vec<TodoResponse>,
+    next_cursor: Option<String>,
+}
+
+#[derive(Debug, Serialize)]
+pub struct TodoSearchHitResponse {
+    #[serde(flatten)]
+    todo: TodoResponse,
+    rank: f32,
+    snippet: String,
+}
+
+impl From<TodoSearchHit> for TodoSearchHitResponse {
+    fn from(hit: TodoSearchHit) -> Self {
+        let (todo, rank, snippet) = hit.into_inner();
+        Self {
+            todo: todo.into(),
+            rank,
+            snippet,
+        }
+    }
+}
+
+#[derive(Debug, Serialize)]
+pub struct SearchTodosResponse {
+    todos: Vec<TodoSearchHitResponse>,
+}
+
+#[derive(Debug, Default, Serialize)]
+pub struct DeleteTodoResponse {
+    message: &'static str,
+}
+
+pub async fn list_todos(
+    current_user: CurrentUser,
+    Query(request): Query<ListTodosRequest>,
+    Extension(list_todos_usecase): Extension<ListTodosUsecase>,
+) -> Result<Json<TodosResponse>, HandlerError> {
+    let page = list_todos_usecase
+        .execute(current_user.user_id(), request.into())
+        .await?;
+    let (todos, next_cursor) = page.into_inner();
+
+    Ok(Json(TodosResponse {
+        todos: todos.into_iter().map(TodoResponse::from).collect(),
+        next_cursor: next_cursor.map(|cursor| cursor.encode()),
+    }))
+}
+
+pub async fn search_todos(
+    current_user: CurrentUser,
+    Query(request): Query<SearchTodosRequest>,
+    Extension(search_todos_usecase): Extension<SearchTodosUsecase>,
+) -> Result<Json<SearchTodosResponse>, HandlerError> {
+    let hits = search_todos_usecase
+        .execute(current_user.user_id(), request.q, request.limit)
+        .await?;
+
+    Ok(Json(SearchTodosResponse {
+        todos: hits.into_iter().map(TodoSearchHitResponse::from).collect(),
+    }))
+}
+
+pub async fn create_todo(
+    current_user: CurrentUser,
+    Json(request): Json<CreateTodoRequest>,
+    Extension(create_todo_usecase): Extension<CreateTodoUsecase>,
+) -> Result<Json<TodoResponse>, HandlerError> {
+    let todo = create_todo_usecase
+        .execute(
+            current_user.into_user_id(),
+            request.title,
+            request.description,
+            request.due_date,
+            request.todo_list_id.map(TodoListId::from),
+        )
+        .await?;
+
+    Ok(Json(todo.into()))
+}
+
+pub async fn get_todo(
+    current_user: CurrentUser,
+    Path(todo_id): Path<Uuid>,
+    Extension(get_todo_usecase): Extension<GetTodoUsecase>,
+) -> Result<Json<TodoResponse>, HandlerError> {
+    let todo = get_todo_usecase
+        .execute(current_user.user_id(), &TodoId::from(todo_id))
+        .await?;
+
+    Ok(Json(todo.into()))
+}
+
+pub async fn update_todo(
+    current_user: CurrentUser,
+    Path(todo_id): Path<Uuid>,
+    Json(request): Json<UpdateTodoRequest>,
+    Extension(update_todo_usecase): Extension<UpdateTodoUsecase>,
+) -> Result<Json<TodoResponse>, HandlerError> {
+    let todo = update_todo_usecase
+        .execute(
+            current_user.user_id(),
+            &TodoId::from(todo_id),
+            request.title,
+            request.description,
+            request.due_date,
+            request.completed,
+        )
+        .await?;
+
+    Ok(Json(todo.into()))
+}
+
+pub async fn set_todo_tags(
+    current_user: CurrentUser,
+    Path(todo_id): Path<Uuid>,
+    Json(request): Json<SetTodoTagsRequest>,
+    Extension(set_todo_tags_usecase): Extension<SetTodoTagsUsecase>,
+) -> Result<Json<TodoResponse>, HandlerError> {
+    let todo = set_todo_tags_usecase
+        .execute(
+            current_user.user_id(),
+            &TodoId::from(todo_id),
+            request.tag_ids.into_iter().map(TagId::from).collect(),
+        )
+        .await?;
+
+    Ok(Json(todo.into()))
+}
+
+pub async fn move_todo(
+    current_user: CurrentUser,
+    Path(todo_id): Path<Uuid>,
+    Json(request): Json<MoveTodoRequest>,
+    Extension(move_todo_usecase): Extension<MoveTodoUsecase>,
+) -> Result<Json<TodoResponse>, HandlerError> {
+    let todo = move_todo_usecase
+        .execute(
+            current_user.user_id(),
+            &TodoId::from(todo_id),
+            &TodoListId::from(request.todo_list_id),
+        )
+        .await?;
+
+    Ok(Json(todo.into()))
+}
+
+pub async fn delete_todo(
+    current_user: CurrentUser,
+    Path(todo_id): Path<Uuid>,
+    Extension(delete_todo_usecase): Extension<DeleteTodoUsecase>,
+) -> Result<Json<DeleteTodoResponse>, HandlerError> {
+    delete_todo_usecase
+        .execute(current_user.user_id(), &TodoId::from(todo_id))
+        .await?;
+
+    Ok(Json(DeleteTodoResponse { message: "ok" }))
+}
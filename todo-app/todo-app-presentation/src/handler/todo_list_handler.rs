@@ -0,0 +1,137 @@
+use axum::{
+    extract::{Path, Query},
+    Extension, Json,
+};
+use serde::{Deserialize, Serialize};
+use todo_app_application::usecase::{
+    CreateTodoListUsecase, DeleteTodoListUsecase, GetTodoListUsecase, ListTodoListsUsecase,
+    UpdateTodoListUsecase,
+};
+use todo_app_domain::aggregate_root::todo_list::{entity::TodoList, value_object::TodoListId};
+use uuid::Uuid;
+
+use crate::{handler::error::HandlerError, session::CurrentUser};
+
+#[derive(Debug, Deserialize)]
+pub struct CreateTodoListRequest {
+    name: String,
+}
+
+/// Omitted fields are left as they are.
+#[derive(Debug, Deserialize)]
+pub struct UpdateTodoListRequest {
+    name: Option<String>,
+    position: Option<i32>,
+    archived: Option<bool>,
+}
+
+#[derive(Debug, Deserialize)]
+pub struct DeleteTodoListRequest {
+    /// `cascade` to delete the todos of the list, or `move_to_inbox`
+    todos: Option<String>,
+}
+
+#[derive(Debug, Serialize)]
+pub struct TodoListResponse {
+    id: Uuid,
+    name: String,
+    position: i32,
+    archived: bool,
+    inbox: bool,
+}
+
+impl From<TodoList> for TodoListResponse {
+    fn from(todo_list: TodoList) -> Self {
+        let (id, _, name, position, archived, inbox) = todo_list.into_inner();
+        Self {
+            id: id.into_uuid(),
+            name: name.into_string(),
+            position,
+            archived,
+            inbox,
+        }
+    }
+}
+
+#[derive(Debug, Serialize)]
+pub struct TodoListsResponse {
+    todo_lists: Vec<TodoListResponse>,
+}
+
+#[derive(Debug, Default, Serialize)]
+pub struct DeleteTodoListResponse {
+    message: &'static str,
+}
+
+pub async fn list_todo_lists(
+    current_user: CurrentUser,
+    Extension(list_todo_lists_usecase): Extension<ListTodoListsUsecase>,
+) -> Result<Json<TodoListsResponse>, HandlerError> {
+    let todo_lists = list_todo_lists_usecase
+        .execute(current_user.user_id())
+        .await?;
+
+    Ok(Json(TodoListsResponse {
+        todo_lists: todo_lists.into_iter().map(TodoListResponse::from).collect(),
+    }))
+}
+
+pub async fn create_todo_list(
+    current_user: CurrentUser,
+    Json(request): Json<CreateTodoListRequest>,
+    Extension(create_todo_list_usecase): Extension<CreateTodoListUsecase>,
+) -> Result<Json<TodoListResponse>, HandlerError> {
+    let todo_list = create_todo_list_usecase
+        .execute(current_user.into_user_id(), request.name)
+        .await?;
+
+    Ok(Json(todo_list.into()))
+}
+
+pub async fn get_todo_list(
+    current_user: CurrentUser,
+    Path(todo_list_id): Path<Uuid>,
+    Extension(get_todo_list_usecase): Extension<GetTodoListUsecase>,
+) -> Result<Json<TodoListResponse>, HandlerError> {
+    let todo_list = get_todo_list_usecase
+        .execute(current_user.user_id(), &TodoListId::from(todo_list_id))
+        .await?;
+
+    Ok(Json(todo_list.into()))
+}
+
+pub async fn update_todo_list(
+    current_user: CurrentUser,
+    Path(todo_list_id): Path<Uuid>,
+    Json(request): Json<UpdateTodoListRequest>,
+    Extension(update_todo_list_usecase): Extension<UpdateTodoListUsecase>,
+) -> Result<Json<TodoListResponse>, HandlerError> {
+    let todo_list = update_todo_list_usecase
+        .execute(
+            current_user.user_id(),
+            &TodoListId::from(todo_list_id),
+            request.name,
+            request.position,
+            request.archived,
+        )
+        .await?;
+
+    Ok(Json(todo_list.into()))
+}
+
+pub async fn delete_todo_list(
+    current_user: CurrentUser,
+    Path(todo_list_id): Path<Uuid>,
+    Query(request): Query<DeleteTodoListRequest>,
+    Extension(delete_todo_list_usecase): Extension<DeleteTodoListUsecase>,
+) -> Result<Json<DeleteTodoListResponse>, HandlerError> {
+    delete_todo_list_usecase
+        .execute(
+            current_user.user_id(),
+            &TodoListId::from(todo_list_id),
+            request.todos,
+        )
+        .await?;
+
+    Ok(Json(DeleteTodoListResponse { message: "ok" }))
+}
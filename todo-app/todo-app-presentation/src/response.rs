@@ -1,7 +1,12 @@
-use std::borrow::Cow;
+use std::{borrow::Cow, collections::BTreeMap};
 
-use axum::{http::StatusCode, response::IntoResponse};
+use axum::{
+    http::{header, StatusCode},
+    response::IntoResponse,
+    Json,
+};
 use serde::{Serialize, Serializer};
+use todo_app_domain::error::{ValidationError, ValidationErrors};
 
 #[derive(Debug, Serialize)]
 pub struct ErrorResponse {
@@ -9,6 +14,9 @@ pub struct ErrorResponse {
     status_code: StatusCode,
     message: Cow<'static, str>,
     errors: Vec<ErrorDetail>,
+    /// Seconds sent in the `Retry-After` header.
+    #[serde(skip)]
+    retry_after: Option<u64>,
 }
 
 impl ErrorResponse {
@@ -17,6 +25,7 @@ impl ErrorResponse {
             status_code: StatusCode::BAD_REQUEST,
             message: message.into(),
             errors,
+            retry_after: None,
         }
     }
 
@@ -25,6 +34,52 @@ impl ErrorResponse {
             status_code: StatusCode::UNAUTHORIZED,
             message: "unauthorized".into(),
             errors: Default::default(),
+            retry_after: None,
+        }
+    }
+
+    pub fn forbidden(message: impl Into<Cow<'static, str>>) -> Self {
+        Self {
+            status_code: StatusCode::FORBIDDEN,
+            message: message.into(),
+            errors: Default::default(),
+            retry_after: None,
+        }
+    }
+
+    pub fn not_found(message: impl Into<Cow<'static, str>>) -> Self {
+        Self {
+            status_code: StatusCode::NOT_FOUND,
+            message: message.into(),
+            errors: Default::default(),
+            retry_after: None,
+        }
+    }
+
+    pub fn conflict(message: impl Into<Cow<'static, str>>) -> Self {
+        Self {
+            status_code: StatusCode::CONFLICT,
+            message: message.into(),
+            errors: Default::default(),
+            retry_after: None,
+        }
+    }
+
+    pub fn too_many_requests(retry_after: u64) -> Self {
+        Self {
+            status_code: StatusCode::TOO_MANY_REQUESTS,
+            message: "too many requests".into(),
+            errors: Default::default(),
+            retry_after: Some(retry_after),
+        }
+    }
+
+    pub fn service_unavailable() -> Self {
+        Self {
+            status_code: StatusCode::SERVICE_UNAVAILABLE,
+            message: "Service unavailable".into(),
+            errors: Default::default(),
+            retry_after: None,
         }
     }
 
@@ -33,13 +88,17 @@ impl ErrorResponse {
             status_code: StatusCode::INTERNAL_SERVER_ERROR,
             message: "Internal server error".into(),
             errors: Default::default(),
+            retry_after: None,
         }
     }
 }
 
 impl IntoResponse for ErrorResponse {
     fn into_response(self) -> axum::response::Response {
-        (self.status_code, self).into_response()
+        let retry_after = self
+            .retry_after
+            .map(|retry_after| [(header::RETRY_AFTER, retry_after.to_string())]);
+        (self.status_code, retry_after, Json(self)).into_response()
     }
 }
 
@@ -53,4 +112,40 @@ where
 #[derive(Debug, Serialize)]
 pub struct ErrorDetail {
     field: &'static str,
+    code: &'static str,
+    #[serde(skip_serializing_if = "BTreeMap::is_empty")]
+    params: BTreeMap<&'static str, usize>,
+}
+
+impl ErrorDetail {
+    /// Converts validation errors to details sorted by field.
+    pub fn from_validation_errors(errors: ValidationErrors) -> Vec<Self> {
+        let mut details = errors
+            .into_hash_map()
+            .into_iter()
+            .map(Self::from)
+            .collect::<Vec<_>>();
+        details.sort_by_key(|detail| detail.field);
+        details
+    }
+}
+
+impl From<(&'static str, ValidationError)> for ErrorDetail {
+    fn from((field, error): (&'static str, ValidationError)) -> Self {
+        let mut params = BTreeMap::new();
+        if let ValidationError::Length { min, max } = error {
+            if let Some(min) = min {
+                params.insert("min", min);
+            }
+            if let Some(max) = max {
+                params.insert("max", max);
+            }
+        }
+
+        Self {
+            field,
+            code: error.code(),
+            params,
+        }
+    }
 }
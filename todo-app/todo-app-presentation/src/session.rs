@@ -1,3 +1,9 @@
+mod current_user;
+mod session_cookie;
+mod session_expiry;
 mod session_store;
 
+pub use current_user::CurrentUser;
+pub use session_cookie::SessionCookie;
+pub use session_expiry::SessionExpiry;
 pub use session_store::{Session, SessionStore, SESSION_ID_HEADER};
@@ -0,0 +1,63 @@
+use std::sync::Arc;
+
+use anyhow::anyhow;
+use async_trait::async_trait;
+use axum::{
+    extract::{FromRequest, RequestParts},
+    Extension,
+};
+use getset::Getters;
+use todo_app_domain::aggregate_root::user::value_object::UserId;
+use tower_cookies::Cookies;
+
+use crate::{
+    handler::error::HandlerError,
+    session::{SessionStore, SESSION_ID_HEADER},
+};
+
+#[derive(Clone, Debug, Getters)]
+pub struct CurrentUser {
+    #[getset(get = "pub")]
+    user_id: UserId,
+    #[getset(get = "pub")]
+    session_id: String,
+}
+
+impl CurrentUser {
+    pub fn into_user_id(self) -> UserId {
+        self.user_id
+    }
+}
+
+#[async_trait]
+impl<B> FromRequest<B> for CurrentUser
+where
+    B: Send,
+{
+    type Rejection = HandlerError;
+
+    async fn from_request(req: &mut RequestParts<B>) -> Result<Self, Self::Rejection> {
+        let cookies = Cookies::from_request(req)
+            .await
+            .map_err(|(_, message)| anyhow!(message))?;
+        let Extension(session_store) = Extension::<Arc<dyn SessionStore>>::from_request(req)
+            .await
+            .map_err(anyhow::Error::new)?;
+
+        let session_id = cookies
+            .get(SESSION_ID_HEADER)
+            .ok_or(HandlerError::Authentication)?
+            .value()
+            .to_owned();
+        let session = session_store
+            .find(&session_id)
+            .await?
+            .ok_or(HandlerError::Authentication)?;
+        let user_id = UserId::from(session.into_user_id());
+
+        Ok(Self {
+            user_id,
+            session_id,
+        })
+    }
+}
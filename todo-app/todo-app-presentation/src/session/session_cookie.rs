@@ -0,0 +1,51 @@
+use cookie::{time::OffsetDateTime, SameSite};
+use time::Duration;
+use tower_cookies::Cookie;
+
+use crate::session::SESSION_ID_HEADER;
+
+const DEFAULT_LIFETIME_DAYS: i64 = 30;
+
+#[derive(Clone, Debug, Eq, PartialEq)]
+pub struct SessionCookie {
+    secure: bool,
+    domain: Option<String>,
+    lifetime: Duration,
+}
+
+impl SessionCookie {
+    pub fn new(secure: bool, domain: Option<String>, lifetime: Duration) -> Self {
+        Self {
+            secure,
+            domain,
+            lifetime,
+        }
+    }
+
+    pub fn build(&self, session_id: String) -> Cookie<'static> {
+        let mut cookie = Cookie::build(SESSION_ID_HEADER, session_id)
+            .expires(OffsetDateTime::now_utc() + self.lifetime)
+            .http_only(true)
+            .secure(self.secure)
+            .same_site(SameSite::Strict);
+        if let Some(domain) = &self.domain {
+            cookie = cookie.domain(domain.clone());
+        }
+        cookie.finish()
+    }
+
+    /// Returns a cookie matching the one created by `build`, for removing it from the client.
+    pub fn removal(&self) -> Cookie<'static> {
+        let mut cookie = Cookie::named(SESSION_ID_HEADER);
+        if let Some(domain) = &self.domain {
+            cookie.set_domain(domain.clone());
+        }
+        cookie
+    }
+}
+
+impl Default for SessionCookie {
+    fn default() -> Self {
+        Self::new(false, None, Duration::days(DEFAULT_LIFETIME_DAYS))
+    }
+}
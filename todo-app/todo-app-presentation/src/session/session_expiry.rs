@@ -0,0 +1,81 @@
+use chrono::{DateTime, Duration, Local};
+use getset::CopyGetters;
+
+use crate::session::Session;
+
+const DEFAULT_ABSOLUTE_TIMEOUT_DAYS: i64 = 30;
+const DEFAULT_IDLE_TIMEOUT_DAYS: i64 = 7;
+
+#[derive(Clone, Copy, CopyGetters, Debug, Eq, PartialEq)]
+pub struct SessionExpiry {
+    /// Maximum lifetime of a session, counted from `Session::logged_in_at`.
+    #[getset(get_copy = "pub")]
+    absolute_timeout: Duration,
+    /// Lifetime of a session without any authenticated request.
+    #[getset(get_copy = "pub")]
+    idle_timeout: Duration,
+}
+
+impl SessionExpiry {
+    pub fn new(absolute_timeout: Duration, idle_timeout: Duration) -> Self {
+        Self {
+            absolute_timeout,
+            idle_timeout,
+        }
+    }
+
+    pub fn expires_at(&self, session: &Session) -> DateTime<Local> {
+        *session.logged_in_at() + self.absolute_timeout
+    }
+
+    /// Returns how long the session may stay alive from `now`, or `None` if it has expired.
+    pub fn ttl(&self, session: &Session, now: DateTime<Local>) -> Option<std::time::Duration> {
+        let remaining = self.expires_at(session) - now;
+        remaining
+            .min(self.idle_timeout)
+            .to_std()
+            .ok()
+            .filter(|ttl| !ttl.is_zero())
+    }
+}
+
+impl Default for SessionExpiry {
+    fn default() -> Self {
+        Self::new(
+            Duration::days(DEFAULT_ABSOLUTE_TIMEOUT_DAYS),
+            Duration::days(DEFAULT_IDLE_TIMEOUT_DAYS),
+        )
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use todo_app_domain::aggregate_root::user::value_object::UserId;
+
+    use super::*;
+
+    #[test]
+    fn session_expiry_ttl() {
+        let session_expiry = SessionExpiry::new(Duration::hours(24), Duration::hours(1));
+        let session = Session::new(UserId::new());
+        let logged_in_at = *session.logged_in_at();
+
+        let tests = vec![
+            (logged_in_at, Some(Duration::hours(1))),
+            (
+                logged_in_at + Duration::minutes(23 * 60 + 30),
+                Some(Duration::minutes(30)),
+            ),
+            (logged_in_at + Duration::hours(24), None),
+            (logged_in_at + Duration::hours(25), None),
+        ];
+
+        for (now, expected) in tests {
+            assert_eq!(
+                session_expiry.ttl(&session, now),
+                expected.map(|d| d.to_std().unwrap()),
+                "now: {now}"
+            );
+        }
+    }
+}
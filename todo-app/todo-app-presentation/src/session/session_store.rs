@@ -7,7 +7,7 @@ use uuid::Uuid;
 
 pub const SESSION_ID_HEADER: &str = "_todo_app_session_id";
 
-#[derive(Debug, Deserialize, Getters, Serialize)]
+#[derive(Clone, Debug, Deserialize, Eq, Getters, PartialEq, Serialize)]
 pub struct Session {
     #[getset(get = "pub")]
     user_id: Uuid,
@@ -30,7 +30,15 @@ impl Session {
 
 #[async_trait]
 pub trait SessionStore: std::fmt::Debug + Send + Sync {
-    async fn find(&self, session_id: &str) -> Result<Option<UserId>, anyhow::Error>;
+    /// Finds a live session and renews its idle timeout.
+    async fn find(&self, session_id: &str) -> Result<Option<Session>, anyhow::Error>;
     async fn save(&self, session_id: &str, session: &Session) -> Result<(), anyhow::Error>;
     async fn delete(&self, session_id: &str) -> Result<(), anyhow::Error>;
+    async fn delete_by_user_id(&self, user_id: &UserId) -> Result<(), anyhow::Error>;
+    /// Deletes all sessions of the user except `session_id`.
+    async fn delete_by_user_id_except(
+        &self,
+        user_id: &UserId,
+        session_id: &str,
+    ) -> Result<(), anyhow::Error>;
 }
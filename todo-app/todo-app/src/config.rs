@@ -0,0 +1,632 @@
+use std::{env, fs, net::SocketAddr, path::PathBuf, str::FromStr};
+
+use serde::Deserialize;
+use thiserror::Error;
+
+const CONFIG_FILE_ENV: &str = "TODO_APP_CONFIG";
+
+/// Upper bound of every duration, in seconds.
+const MAX_DURATION: i64 = 10 * 365 * 24 * 60 * 60;
+
+#[derive(Debug, Error)]
+pub enum ConfigError {
+    #[error("failed to read config file `{path}`: {source}")]
+    Read {
+        path: PathBuf,
+        source: std::io::Error,
+    },
+    #[error("failed to parse config file `{path}`: {source}")]
+    Parse {
+        path: PathBuf,
+        source: toml::de::Error,
+    },
+    #[error("invalid value for environment variable `{name}`: `{value}`")]
+    Env { name: &'static str, value: String },
+    #[error("invalid config `{field}`: {message}")]
+    Invalid {
+        field: &'static str,
+        message: &'static str,
+    },
+}
+
+#[derive(Clone, Debug, Default, Deserialize, PartialEq)]
+#[serde(default, deny_unknown_fields)]
+pub struct Config {
+    pub database: DatabaseConfig,
+    pub redis: RedisConfig,
+    pub server: ServerConfig,
+    pub session: SessionConfig,
+    pub cookie: CookieConfig,
+    pub argon2: Argon2Config,
+    pub mailer: MailerConfig,
+    pub password_reset: PasswordResetConfig,
+    pub email_verification: EmailVerificationConfig,
+    pub login_throttle: LoginThrottleConfig,
+}
+
+#[derive(Clone, Debug, Deserialize, PartialEq)]
+#[serde(default, deny_unknown_fields)]
+pub struct DatabaseConfig {
+    pub url: String,
+    pub max_connections: u32,
+}
+
+impl Default for DatabaseConfig {
+    fn default() -> Self {
+        Self {
+            url: Default::default(),
+            max_connections: 5,
+        }
+    }
+}
+
+#[derive(Clone, Debug, Deserialize, PartialEq)]
+#[serde(default, deny_unknown_fields)]
+pub struct RedisConfig {
+    pub url: String,
+}
+
+impl Default for RedisConfig {
+    fn default() -> Self {
+        Self {
+            url: "redis://localhost/".to_owned(),
+        }
+    }
+}
+
+#[derive(Clone, Debug, Deserialize, PartialEq)]
+#[serde(default, deny_unknown_fields)]
+pub struct ServerConfig {
+    pub listen_addr: SocketAddr,
+}
+
+impl Default for ServerConfig {
+    fn default() -> Self {
+        Self {
+            listen_addr: SocketAddr::from(([127, 0, 0, 1], 3000)),
+        }
+    }
+}
+
+/// Server-side session lifetime, in seconds.
+#[derive(Clone, Debug, Deserialize, PartialEq)]
+#[serde(default, deny_unknown_fields)]
+pub struct SessionConfig {
+    pub absolute_timeout: u64,
+    pub idle_timeout: u64,
+}
+
+impl Default for SessionConfig {
+    fn default() -> Self {
+        Self {
+            absolute_timeout: 30 * 24 * 60 * 60,
+            idle_timeout: 7 * 24 * 60 * 60,
+        }
+    }
+}
+
+#[derive(Clone, Debug, Deserialize, PartialEq)]
+#[serde(default, deny_unknown_fields)]
+pub struct CookieConfig {
+    pub secure: bool,
+    pub domain: Option<String>,
+    /// Lifetime of the session cookie, in seconds.
+    pub lifetime: u64,
+}
+
+impl Default for CookieConfig {
+    fn default() -> Self {
+        Self {
+            secure: false,
+            domain: None,
+            lifetime: 30 * 24 * 60 * 60,
+        }
+    }
+}
+
+/// Cost parameters for hashing new passwords; see the `argon2` crate for their meaning.
+#[derive(Clone, Debug, Deserialize, PartialEq)]
+#[serde(default, deny_unknown_fields)]
+pub struct Argon2Config {
+    pub memory_cost: u32,
+    pub time_cost: u32,
+    pub parallelism: u32,
+}
+
+impl Default for Argon2Config {
+    fn default() -> Self {
+        Self {
+            memory_cost: 4096,
+            time_cost: 3,
+            parallelism: 1,
+        }
+    }
+}
+
+#[derive(Clone, Debug, Deserialize, PartialEq)]
+#[serde(default, deny_unknown_fields)]
+pub struct MailerConfig {
+    pub backend: MailerBackend,
+    /// File that mail is appended to by the `file` backend.
+    pub path: PathBuf,
+}
+
+impl Default for MailerConfig {
+    fn default() -> Self {
+        Self {
+            backend: MailerBackend::Log,
+            path: PathBuf::from("mail.txt"),
+        }
+    }
+}
+
+/// Local stand-ins for a mail delivery service.
+#[derive(Clone, Copy, Debug, Deserialize, PartialEq, Eq)]
+#[serde(rename_all = "lowercase")]
+pub enum MailerBackend {
+    Log,
+    File,
+}
+
+impl FromStr for MailerBackend {
+    type Err = ();
+
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        match s {
+            "log" => Ok(Self::Log),
+            "file" => Ok(Self::File),
+            _ => Err(()),
+        }
+    }
+}
+
+#[derive(Clone, Debug, Deserialize, PartialEq)]
+#[serde(default, deny_unknown_fields)]
+pub struct PasswordResetConfig {
+    /// Lifetime of a password reset token, in seconds.
+    pub token_lifetime: u64,
+}
+
+impl Default for PasswordResetConfig {
+    fn default() -> Self {
+        Self {
+            token_lifetime: 60 * 60,
+        }
+    }
+}
+
+#[derive(Clone, Debug, Deserialize, PartialEq)]
+#[serde(default, deny_unknown_fields)]
+pub struct EmailVerificationConfig {
+    /// Lifetime of an email verification token, in seconds.
+    pub token_lifetime: u64,
+    /// Rejects logins until the email address is verified.
+    pub required_for_login: bool,
+    /// Rejects creating todos until the email address is verified.
+    pub required_for_todo_creation: bool,
+}
+
+impl Default for EmailVerificationConfig {
+    fn default() -> Self {
+        Self {
+            token_lifetime: 24 * 60 * 60,
+            required_for_login: false,
+            required_for_todo_creation: false,
+        }
+    }
+}
+
+/// Lockouts after repeated failed logins; durations are in seconds.
+#[derive(Clone, Debug, Deserialize, PartialEq)]
+#[serde(default, deny_unknown_fields)]
+pub struct LoginThrottleConfig {
+    /// Failures per email address before it is locked out.
+    pub max_account_failures: u32,
+    /// Failures per client IP address before it is locked out.
+    pub max_ip_failures: u32,
+    /// The first lockout, doubled for every further failure.
+    pub base_lockout: u64,
+    pub max_lockout: u64,
+    /// How long failures are remembered after the last one.
+    pub failure_window: u64,
+}
+
+impl Default for LoginThrottleConfig {
+    fn default() -> Self {
+        Self {
+            max_account_failures: 5,
+            max_ip_failures: 20,
+            base_lockout: 60,
+            max_lockout: 60 * 60,
+            failure_window: 15 * 60,
+        }
+    }
+}
+
+impl Config {
+    /// Loads the config file named by `TODO_APP_CONFIG`, if any, and overrides it with the
+    /// environment.
+    pub fn load() -> Result<Self, ConfigError> {
+        let config = match env::var_os(CONFIG_FILE_ENV) {
+            Some(path) => {
+                let path = PathBuf::from(path);
+                let content = fs::read_to_string(&path).map_err(|source| ConfigError::Read {
+                    path: path.clone(),
+                    source,
+                })?;
+                toml::from_str(&content).map_err(|source| ConfigError::Parse { path, source })?
+            }
+            None => Config::default(),
+        };
+
+        config.with_env(|name| env::var(name).ok())
+    }
+
+    pub fn with_env(mut self, var: impl Fn(&str) -> Option<String>) -> Result<Self, ConfigError> {
+        let env = |name: &'static str| var(name).map(|value| (name, value));
+
+        if let Some((_, value)) = env("DATABASE_URL") {
+            self.database.url = value;
+        }
+        if let Some(value) = env("TODO_APP_DATABASE_MAX_CONNECTIONS") {
+            self.database.max_connections = parse(value)?;
+        }
+        if let Some((_, value)) = env("REDIS_URL") {
+            self.redis.url = value;
+        }
+        if let Some(value) = env("TODO_APP_LISTEN_ADDR") {
+            self.server.listen_addr = parse(value)?;
+        }
+        if let Some(value) = env("TODO_APP_SESSION_ABSOLUTE_TIMEOUT") {
+            self.session.absolute_timeout = parse(value)?;
+        }
+        if let Some(value) = env("TODO_APP_SESSION_IDLE_TIMEOUT") {
+            self.session.idle_timeout = parse(value)?;
+        }
+        if let Some(value) = env("TODO_APP_COOKIE_SECURE") {
+            self.cookie.secure = parse(value)?;
+        }
+        if let Some((_, value)) = env("TODO_APP_COOKIE_DOMAIN") {
+            self.cookie.domain = Some(value).filter(|domain| !domain.is_empty());
+        }
+        if let Some(value) = env("TODO_APP_COOKIE_LIFETIME") {
+            self.cookie.lifetime = parse(value)?;
+        }
+        if let Some(value) = env("TODO_APP_ARGON2_MEMORY_COST") {
+            self.argon2.memory_cost = parse(value)?;
+        }
+        if let Some(value) = env("TODO_APP_ARGON2_TIME_COST") {
+            self.argon2.time_cost = parse(value)?;
+        }
+        if let Some(value) = env("TODO_APP_ARGON2_PARALLELISM") {
+            self.argon2.parallelism = parse(value)?;
+        }
+        if let Some(value) = env("TODO_APP_MAILER_BACKEND") {
+            self.mailer.backend = parse(value)?;
+        }
+        if let Some((_, value)) = env("TODO_APP_MAILER_PATH") {
+            self.mailer.path = PathBuf::from(value);
+        }
+        if let Some(value) = env("TODO_APP_PASSWORD_RESET_TOKEN_LIFETIME") {
+            self.password_reset.token_lifetime = parse(value)?;
+        }
+        if let Some(value) = env("TODO_APP_EMAIL_VERIFICATION_TOKEN_LIFETIME") {
+            self.email_verification.token_lifetime = parse(value)?;
+        }
+        if let Some(value) = env("TODO_APP_EMAIL_VERIFICATION_REQUIRED_FOR_LOGIN") {
+            self.email_verification.required_for_login = parse(value)?;
+        }
+        if let Some(value) = env("TODO_APP_EMAIL_VERIFICATION_REQUIRED_FOR_TODO_CREATION") {
+            self.email_verification.required_for_todo_creation = parse(value)?;
+        }
+        if let Some(value) = env("TODO_APP_LOGIN_THROTTLE_MAX_ACCOUNT_FAILURES") {
+            self.login_throttle.max_account_failures = parse(value)?;
+        }
+        if let Some(value) = env("TODO_APP_LOGIN_THROTTLE_MAX_IP_FAILURES") {
+            self.login_throttle.max_ip_failures = parse(value)?;
+        }
+        if let Some(value) = env("TODO_APP_LOGIN_THROTTLE_BASE_LOCKOUT") {
+            self.login_throttle.base_lockout = parse(value)?;
+        }
+        if let Some(value) = env("TODO_APP_LOGIN_THROTTLE_MAX_LOCKOUT") {
+            self.login_throttle.max_lockout = parse(value)?;
+        }
+        if let Some(value) = env("TODO_APP_LOGIN_THROTTLE_FAILURE_WINDOW") {
+            self.login_throttle.failure_window = parse(value)?;
+        }
+
+        self.validate()?;
+
+        Ok(self)
+    }
+
+    fn validate(&self) -> Result<(), ConfigError> {
+        if self.database.url.is_empty() {
+            return Err(invalid("database.url", "must be set"));
+        }
+        if self.database.max_connections == 0 {
+            return Err(invalid("database.max_connections", "must be positive"));
+        }
+        if redis::parse_redis_url(&self.redis.url).is_none() {
+            return Err(invalid("redis.url", "must be a redis:// URL"));
+        }
+        duration("session.absolute_timeout", self.session.absolute_timeout)?;
+        duration("session.idle_timeout", self.session.idle_timeout)?;
+        if self.session.idle_timeout > self.session.absolute_timeout {
+            return Err(invalid(
+                "session.idle_timeout",
+                "must be at most session.absolute_timeout",
+            ));
+        }
+        duration("cookie.lifetime", self.cookie.lifetime)?;
+        if self.argon2.time_cost == 0 {
+            return Err(invalid("argon2.time_cost", "must be positive"));
+        }
+        if self.argon2.parallelism == 0 {
+            return Err(invalid("argon2.parallelism", "must be positive"));
+        }
+        if self.argon2.memory_cost < 8 * self.argon2.parallelism {
+            return Err(invalid(
+                "argon2.memory_cost",
+                "must be at least 8 times argon2.parallelism",
+            ));
+        }
+        duration(
+            "password_reset.token_lifetime",
+            self.password_reset.token_lifetime,
+        )?;
+        duration(
+            "email_verification.token_lifetime",
+            self.email_verification.token_lifetime,
+        )?;
+        if self.login_throttle.max_account_failures == 0 {
+            return Err(invalid(
+                "login_throttle.max_account_failures",
+                "must be positive",
+            ));
+        }
+        if self.login_throttle.max_ip_failures == 0 {
+            return Err(invalid(
+                "login_throttle.max_ip_failures",
+                "must be positive",
+            ));
+        }
+        duration(
+            "login_throttle.base_lockout",
+            self.login_throttle.base_lockout,
+        )?;
+        duration(
+            "login_throttle.max_lockout",
+            self.login_throttle.max_lockout,
+        )?;
+        if self.login_throttle.max_lockout < self.login_throttle.base_lockout {
+            return Err(invalid(
+                "login_throttle.max_lockout",
+                "must be at least login_throttle.base_lockout",
+            ));
+        }
+        duration(
+            "login_throttle.failure_window",
+            self.login_throttle.failure_window,
+        )?;
+
+        Ok(())
+    }
+}
+
+fn parse<T: FromStr>((name, value): (&'static str, String)) -> Result<T, ConfigError> {
+    value.parse().map_err(|_| ConfigError::Env { name, value })
+}
+
+fn duration(field: &'static str, seconds: u64) -> Result<(), ConfigError> {
+    match i64::try_from(seconds) {
+        Ok(0) => Err(invalid(field, "must be positive")),
+        Ok(seconds) if seconds <= MAX_DURATION => Ok(()),
+        _ => Err(invalid(field, "must be at most 10 years")),
+    }
+}
+
+fn invalid(field: &'static str, message: &'static str) -> ConfigError {
+    ConfigError::Invalid { field, message }
+}
+
+#[cfg(test)]
+mod tests {
+    use std::collections::HashMap;
+
+    use super::*;
+
+    fn env(vars: &[(&str, &str)]) -> impl Fn(&str) -> Option<String> {
+        let vars = vars
+            .iter()
+            .map(|(k, v)| (k.to_string(), v.to_string()))
+            .collect::<HashMap<_, _>>();
+        move |name| vars.get(name).cloned()
+    }
+
+    #[test]
+    fn config_from_toml() {
+        let config = toml::from_str::<Config>(
+            r#"
+            [database]
+            url = "postgres://localhost/todo_app"
+
+            [server]
+            listen_addr = "0.0.0.0:8080"
+
+            [cookie]
+            secure = true
+            domain = "example.com"
+
+            [mailer]
+            backend = "file"
+            path = "/tmp/mail.txt"
+
+            [email_verification]
+            required_for_login = true
+            "#,
+        )
+        .unwrap()
+        .with_env(env(&[]))
+        .unwrap();
+
+        assert_eq!(config.database.url, "postgres://localhost/todo_app");
+        assert_eq!(config.database.max_connections, 5);
+        assert_eq!(config.server.listen_addr, "0.0.0.0:8080".parse().unwrap());
+        assert!(config.cookie.secure);
+        assert_eq!(config.cookie.domain.as_deref(), Some("example.com"));
+        assert_eq!(config.mailer.backend, MailerBackend::File);
+        assert_eq!(config.mailer.path, PathBuf::from("/tmp/mail.txt"));
+        assert_eq!(config.password_reset.token_lifetime, 60 * 60);
+        assert_eq!(config.email_verification.token_lifetime, 24 * 60 * 60);
+        assert!(config.email_verification.required_for_login);
+        assert!(!config.email_verification.required_for_todo_creation);
+    }
+
+    #[test]
+    fn config_with_env() {
+        let config = toml::from_str::<Config>(
+            r#"
+            [database]
+            url = "postgres://localhost/todo_app"
+            max_connections = 10
+            "#,
+        )
+        .unwrap()
+        .with_env(env(&[
+            ("DATABASE_URL", "postgres://db/todo_app"),
+            ("REDIS_URL", "redis://redis/"),
+            ("TODO_APP_COOKIE_SECURE", "true"),
+            ("TODO_APP_ARGON2_MEMORY_COST", "19456"),
+            ("TODO_APP_MAILER_BACKEND", "file"),
+            ("TODO_APP_PASSWORD_RESET_TOKEN_LIFETIME", "600"),
+            ("TODO_APP_EMAIL_VERIFICATION_TOKEN_LIFETIME", "3600"),
+            (
+                "TODO_APP_EMAIL_VERIFICATION_REQUIRED_FOR_TODO_CREATION",
+                "true",
+            ),
+            ("TODO_APP_LOGIN_THROTTLE_MAX_IP_FAILURES", "100"),
+        ]))
+        .unwrap();
+
+        assert_eq!(config.database.url, "postgres://db/todo_app");
+        assert_eq!(config.database.max_connections, 10);
+        assert_eq!(config.redis.url, "redis://redis/");
+        assert!(config.cookie.secure);
+        assert_eq!(config.argon2.memory_cost, 19456);
+        assert_eq!(config.mailer.backend, MailerBackend::File);
+        assert_eq!(config.password_reset.token_lifetime, 600);
+        assert_eq!(config.email_verification.token_lifetime, 3600);
+        assert!(config.email_verification.required_for_todo_creation);
+        assert_eq!(config.login_throttle.max_account_failures, 5);
+        assert_eq!(config.login_throttle.max_ip_failures, 100);
+    }
+
+    #[test]
+    fn config_errors() {
+        let tests = vec![
+            (vec![], "database.url"),
+            (
+                vec![
+                    ("DATABASE_URL", "postgres://localhost"),
+                    ("TODO_APP_DATABASE_MAX_CONNECTIONS", "0"),
+                ],
+                "database.max_connections",
+            ),
+            (
+                vec![
+                    ("DATABASE_URL", "postgres://localhost"),
+                    ("REDIS_URL", "http://localhost"),
+                ],
+                "redis.url",
+            ),
+            (
+                vec![
+                    ("DATABASE_URL", "postgres://localhost"),
+                    ("TODO_APP_LISTEN_ADDR", "localhost"),
+                ],
+                "TODO_APP_LISTEN_ADDR",
+            ),
+            (
+                vec![
+                    ("DATABASE_URL", "postgres://localhost"),
+                    ("TODO_APP_SESSION_ABSOLUTE_TIMEOUT", "18446744073709551615"),
+                ],
+                "session.absolute_timeout",
+            ),
+            (
+                vec![
+                    ("DATABASE_URL", "postgres://localhost"),
+                    ("TODO_APP_SESSION_IDLE_TIMEOUT", "3600"),
+                    ("TODO_APP_SESSION_ABSOLUTE_TIMEOUT", "60"),
+                ],
+                "session.idle_timeout",
+            ),
+            (
+                vec![
+                    ("DATABASE_URL", "postgres://localhost"),
+                    ("TODO_APP_COOKIE_LIFETIME", "315360001"),
+                ],
+                "cookie.lifetime",
+            ),
+            (
+                vec![
+                    ("DATABASE_URL", "postgres://localhost"),
+                    ("TODO_APP_ARGON2_MEMORY_COST", "1"),
+                ],
+                "argon2.memory_cost",
+            ),
+            (
+                vec![
+                    ("DATABASE_URL", "postgres://localhost"),
+                    ("TODO_APP_MAILER_BACKEND", "smtp"),
+                ],
+                "TODO_APP_MAILER_BACKEND",
+            ),
+            (
+                vec![
+                    ("DATABASE_URL", "postgres://localhost"),
+                    ("TODO_APP_PASSWORD_RESET_TOKEN_LIFETIME", "0"),
+                ],
+                "password_reset.token_lifetime",
+            ),
+            (
+                vec![
+                    ("DATABASE_URL", "postgres://localhost"),
+                    ("TODO_APP_EMAIL_VERIFICATION_TOKEN_LIFETIME", "0"),
+                ],
+                "email_verification.token_lifetime",
+            ),
+            (
+                vec![
+                    ("DATABASE_URL", "postgres://localhost"),
+                    ("TODO_APP_EMAIL_VERIFICATION_REQUIRED_FOR_LOGIN", "yes"),
+                ],
+                "TODO_APP_EMAIL_VERIFICATION_REQUIRED_FOR_LOGIN",
+            ),
+            (
+                vec![
+                    ("DATABASE_URL", "postgres://localhost"),
+                    ("TODO_APP_LOGIN_THROTTLE_MAX_ACCOUNT_FAILURES", "0"),
+                ],
+                "login_throttle.max_account_failures",
+            ),
+            (
+                vec![
+                    ("DATABASE_URL", "postgres://localhost"),
+                    ("TODO_APP_LOGIN_THROTTLE_MAX_LOCKOUT", "30"),
+                ],
+                "login_throttle.max_lockout",
+            ),
+        ];
+
+        for (vars, expected) in tests {
+            let result = Config::default().with_env(env(&vars));
+            let message = result.as_ref().unwrap_err().to_string();
+            assert!(
+                message.contains(expected),
+                "vars: {vars:?}, result: {result:?}"
+            );
+        }
+    }
+}
@@ -0,0 +1,167 @@
+pub mod config;
+
+use std::sync::Arc;
+
+use axum::{
+    routing::{get, post, put},
+    Extension, Router,
+};
+use tower_cookies::CookieManagerLayer;
+
+use todo_app_application::{
+    database::DB,
+    mail::Mailer,
+    throttle::LoginThrottle,
+    usecase::{
+        ChangeEmailUsecase, ChangePasswordUsecase, CreateTagUsecase, CreateTodoListUsecase,
+        CreateTodoUsecase, DeleteTagUsecase, DeleteTodoListUsecase, DeleteTodoUsecase,
+        DeleteUserUsecase, EmailVerificationPolicy, GetTagUsecase, GetTodoListUsecase,
+        GetTodoUsecase, GetUserUsecase, ListTagsUsecase, ListTodoListsUsecase, ListTodosUsecase,
+        LoginUsecase, MoveTodoUsecase, RequestPasswordResetUsecase, ResetPasswordUsecase,
+        SearchTodosUsecase, SetTodoTagsUsecase, SignupUsecase, UpdateTagUsecase,
+        UpdateTodoListUsecase, UpdateTodoUsecase, UpdateUserUsecase, VerifyEmailUsecase,
+    },
+};
+use todo_app_domain::aggregate_root::user_credential::value_object::PasswordHasher;
+use todo_app_presentation::{
+    handler::{
+        credential_handler::{change_email, change_password},
+        email_verification_handler::verify_email,
+        login_handler::login,
+        logout_handler::{logout, logout_all},
+        me_handler::{delete_me, get_me, update_me},
+        password_reset_handler::{request_password_reset, reset_password},
+        signup_handler::signup,
+        tag_handler::{create_tag, delete_tag, get_tag, list_tags, update_tag},
+        todo_handler::{
+            create_todo, delete_todo, get_todo, list_todos, move_todo, search_todos, set_todo_tags,
+            update_todo,
+        },
+        todo_list_handler::{
+            create_todo_list, delete_todo_list, get_todo_list, list_todo_lists, update_todo_list,
+        },
+    },
+    session::{SessionCookie, SessionStore},
+};
+
+#[allow(clippy::too_many_arguments)]
+pub fn app(
+    db: Arc<dyn DB>,
+    session_store: Arc<dyn SessionStore>,
+    session_cookie: SessionCookie,
+    mailer: Arc<dyn Mailer>,
+    password_reset_token_lifetime: chrono::Duration,
+    email_verification_policy: EmailVerificationPolicy,
+    login_throttle: LoginThrottle,
+    password_hasher: PasswordHasher,
+) -> Router {
+    let signup_usecase = SignupUsecase::new(
+        db.clone(),
+        password_hasher.clone(),
+        mailer.clone(),
+        email_verification_policy,
+    );
+    let login_usecase = LoginUsecase::new(
+        db.clone(),
+        password_hasher.clone(),
+        email_verification_policy,
+        login_throttle.clone(),
+    );
+    let get_user_usecase = GetUserUsecase::new(db.clone());
+    let update_user_usecase = UpdateUserUsecase::new(db.clone());
+    let delete_user_usecase = DeleteUserUsecase::new(db.clone());
+    let change_password_usecase =
+        ChangePasswordUsecase::new(db.clone(), password_hasher.clone(), login_throttle.clone());
+    let change_email_usecase = ChangeEmailUsecase::new(
+        db.clone(),
+        mailer.clone(),
+        email_verification_policy,
+        login_throttle,
+    );
+    let request_password_reset_usecase =
+        RequestPasswordResetUsecase::new(db.clone(), mailer, password_reset_token_lifetime);
+    let reset_password_usecase = ResetPasswordUsecase::new(db.clone(), password_hasher);
+    let verify_email_usecase = VerifyEmailUsecase::new(db.clone());
+    let list_todos_usecase = ListTodosUsecase::new(db.clone());
+    let search_todos_usecase = SearchTodosUsecase::new(db.clone());
+    let create_todo_usecase = CreateTodoUsecase::new(db.clone(), email_verification_policy);
+    let get_todo_usecase = GetTodoUsecase::new(db.clone());
+    let update_todo_usecase = UpdateTodoUsecase::new(db.clone());
+    let delete_todo_usecase = DeleteTodoUsecase::new(db.clone());
+    let set_todo_tags_usecase = SetTodoTagsUsecase::new(db.clone());
+    let move_todo_usecase = MoveTodoUsecase::new(db.clone());
+    let list_todo_lists_usecase = ListTodoListsUsecase::new(db.clone());
+    let create_todo_list_usecase = CreateTodoListUsecase::new(db.clone());
+    let get_todo_list_usecase = GetTodoListUsecase::new(db.clone());
+    let update_todo_list_usecase = UpdateTodoListUsecase::new(db.clone());
+    let delete_todo_list_usecase = DeleteTodoListUsecase::new(db.clone());
+    let list_tags_usecase = ListTagsUsecase::new(db.clone());
+    let create_tag_usecase = CreateTagUsecase::new(db.clone());
+    let get_tag_usecase = GetTagUsecase::new(db.clone());
+    let update_tag_usecase = UpdateTagUsecase::new(db.clone());
+    let delete_tag_usecase = DeleteTagUsecase::new(db.clone());
+
+    Router::new()
+        .route("/login", post(login))
+        .route("/logout", post(logout))
+        .route("/logout/all", post(logout_all))
+        .route("/me", get(get_me).patch(update_me).delete(delete_me))
+        .route("/me/password", put(change_password))
+        .route("/me/email", put(change_email))
+        .route("/password-reset/request", post(request_password_reset))
+        .route("/password-reset/confirm", post(reset_password))
+        .route("/signup", post(signup))
+        .route("/verify-email", post(verify_email))
+        .route("/todos", get(list_todos).post(create_todo))
+        .route("/todos/search", get(search_todos))
+        .route(
+            "/todos/:id",
+            get(get_todo).patch(update_todo).delete(delete_todo),
+        )
+        .route("/todos/:id/tags", put(set_todo_tags))
+        .route("/todos/:id/list", put(move_todo))
+        .route("/todo-lists", get(list_todo_lists).post(create_todo_list))
+        .route(
+            "/todo-lists/:id",
+            get(get_todo_list)
+                .patch(update_todo_list)
+                .delete(delete_todo_list),
+        )
+        .route("/tags", get(list_tags).post(create_tag))
+        .route(
+            "/tags/:id",
+            get(get_tag).patch(update_tag).delete(delete_tag),
+        )
+        .layer(Extension(db))
+        .layer(Extension(signup_usecase))
+        .layer(Extension(login_usecase))
+        .layer(Extension(get_user_usecase))
+        .layer(Extension(update_user_usecase))
+        .layer(Extension(delete_user_usecase))
+        .layer(Extension(change_password_usecase))
+        .layer(Extension(change_email_usecase))
+        .layer(Extension(request_password_reset_usecase))
+        .layer(Extension(reset_password_usecase))
+        .layer(Extension(verify_email_usecase))
+        .layer(Extension(list_todos_usecase))
+        .layer(Extension(search_todos_usecase))
+        .layer(Extension(create_todo_usecase))
+        .layer(Extension(get_todo_usecase))
+        .layer(Extension(update_todo_usecase))
+        .layer(Extension(delete_todo_usecase))
+        .layer(Extension(set_todo_tags_usecase))
+        .layer(Extension(move_todo_usecase))
+        .layer(Extension(list_todo_lists_usecase))
+        .layer(Extension(create_todo_list_usecase))
+        .layer(Extension(get_todo_list_usecase))
+        .layer(Extension(update_todo_list_usecase))
+        .layer(Extension(delete_todo_list_usecase))
+        .layer(Extension(list_tags_usecase))
+        .layer(Extension(create_tag_usecase))
+        .layer(Extension(get_tag_usecase))
+        .layer(Extension(update_tag_usecase))
+        .layer(Extension(delete_tag_usecase))
+        .layer(Extension(session_store))
+        .layer(Extension(session_cookie))
+        .layer(CookieManagerLayer::new())
+}
@@ -1,48 +1,104 @@
-use std::{env, net::SocketAddr, sync::Arc};
+use std::{net::SocketAddr, sync::Arc};
 
-use axum::{routing::post, Extension, Router};
+use anyhow::Context;
 use redis::Client;
 use sqlx::postgres::PgPoolOptions;
 
-use todo_app_application::usecase::{LoginUsecase, SignupUsecase};
-use todo_app_infrastructure::{postgres::database::PgDB, redis::session::RedisSessionStore};
-use todo_app_presentation::{
-    handler::{login_handler::login, signup_handler::signup},
-    session::SessionStore,
+use todo_app::config::{Config, MailerBackend};
+use todo_app_application::{
+    mail::Mailer,
+    throttle::{LoginThrottle, LoginThrottlePolicy},
+    usecase::EmailVerificationPolicy,
 };
+use todo_app_domain::aggregate_root::user_credential::value_object::PasswordHasher;
+use todo_app_infrastructure::{
+    mail::{FileMailer, LogMailer},
+    postgres::database::PgDB,
+    redis::{session::RedisSessionStore, throttle::RedisLoginAttemptStore},
+};
+use todo_app_presentation::session::{SessionCookie, SessionExpiry};
 
 #[tokio::main]
-async fn main() {
+async fn main() -> anyhow::Result<()> {
     tracing_subscriber::fmt::init();
 
-    let uri = env::var("DATABASE_URL").unwrap();
+    let config = Config::load().context("failed to load config")?;
+
     let pool = PgPoolOptions::new()
-        .max_connections(5)
-        .connect(&uri)
+        .max_connections(config.database.max_connections)
+        .connect(&config.database.url)
         .await
-        .unwrap();
+        .context("failed to connect to the database")?;
 
     let db = Arc::new(PgDB::new(pool));
-    let signup_usecase = SignupUsecase::new(db.clone());
-    let login_usecase = LoginUsecase::new(db.clone());
 
-    let redis_client = Client::open("redis://localhost/").unwrap();
-    let session_store = Arc::new(RedisSessionStore::new(redis_client)) as Arc<dyn SessionStore>;
+    let redis_client =
+        Client::open(config.redis.url.as_str()).context("failed to open the Redis client")?;
+    let session_expiry = SessionExpiry::new(
+        chrono::Duration::seconds(seconds(config.session.absolute_timeout)),
+        chrono::Duration::seconds(seconds(config.session.idle_timeout)),
+    );
+    let session_store = Arc::new(RedisSessionStore::new(redis_client.clone(), session_expiry));
+    let session_cookie = SessionCookie::new(
+        config.cookie.secure,
+        config.cookie.domain.clone(),
+        time::Duration::seconds(seconds(config.cookie.lifetime)),
+    );
+
+    let mailer: Arc<dyn Mailer> = match config.mailer.backend {
+        MailerBackend::Log => Arc::new(LogMailer::new()),
+        MailerBackend::File => Arc::new(FileMailer::new(config.mailer.path.clone())),
+    };
+
+    let email_verification_policy = EmailVerificationPolicy::new(
+        chrono::Duration::seconds(seconds(config.email_verification.token_lifetime)),
+        config.email_verification.required_for_login,
+        config.email_verification.required_for_todo_creation,
+    );
+
+    let login_throttle = LoginThrottle::new(
+        Arc::new(RedisLoginAttemptStore::new(redis_client)),
+        LoginThrottlePolicy::new(
+            config.login_throttle.max_account_failures,
+            config.login_throttle.max_ip_failures,
+            chrono::Duration::seconds(seconds(config.login_throttle.base_lockout)),
+            chrono::Duration::seconds(seconds(config.login_throttle.max_lockout)),
+            chrono::Duration::seconds(seconds(config.login_throttle.failure_window)),
+        ),
+    );
 
-    let app = Router::new()
-        .route("/login", post(login))
-        .route("/signup", post(signup))
-        .layer(Extension(db))
-        .layer(Extension(signup_usecase))
-        .layer(Extension(login_usecase))
-        .layer(Extension(session_store));
+    let password_hasher = PasswordHasher::new(
+        config.argon2.memory_cost,
+        config.argon2.time_cost,
+        config.argon2.parallelism,
+    )
+    .context("invalid argon2 parameters")?;
 
-    let addr = SocketAddr::from(([127, 0, 0, 1], 3000));
+    let app = todo_app::app(
+        db,
+        session_store,
+        session_cookie,
+        mailer,
+        chrono::Duration::seconds(seconds(config.password_reset.token_lifetime)),
+        email_verification_policy,
+        login_throttle,
+        password_hasher,
+    );
+
+    let addr = config.server.listen_addr;
 
     tracing::info!("listening on {}", addr);
 
-    axum::Server::bind(&addr)
-        .serve(app.into_make_service())
+    axum::Server::try_bind(&addr)
+        .with_context(|| format!("failed to bind {addr}"))?
+        .serve(app.into_make_service_with_connect_info::<SocketAddr>())
         .await
-        .unwrap();
+        .context("server error")?;
+
+    Ok(())
+}
+
+/// Converts a duration in seconds, which `Config` has bounded to fit.
+fn seconds(value: u64) -> i64 {
+    i64::try_from(value).expect("durations are validated by Config")
 }
@@ -0,0 +1,1612 @@
+use std::{env, net::SocketAddr, sync::Arc};
+
+use axum::{
+    body::Body,
+    extract::ConnectInfo,
+    http::{header, Method, Request, StatusCode},
+    Router,
+};
+use serde_json::{json, Value};
+use sqlx::postgres::PgPoolOptions;
+use tower::ServiceExt;
+
+use todo_app_application::{
+    database::DB,
+    throttle::{LoginThrottle, LoginThrottlePolicy},
+    usecase::EmailVerificationPolicy,
+};
+use todo_app_domain::aggregate_root::user_credential::value_object::PasswordHasher;
+use todo_app_infrastructure::{
+    memory::{
+        database::MemoryDB, mail::MemoryMailer, session::MemorySessionStore,
+        throttle::MemoryLoginAttemptStore,
+    },
+    postgres::database::PgDB,
+};
+use todo_app_presentation::session::{SessionCookie, SessionExpiry};
+
+fn app() -> Router {
+    app_with_mailer(Arc::new(MemoryMailer::new()))
+}
+
+fn app_with_mailer(mailer: Arc<MemoryMailer>) -> Router {
+    app_with(
+        mailer,
+        EmailVerificationPolicy::default(),
+        LoginThrottlePolicy::default(),
+    )
+}
+
+fn app_with(
+    mailer: Arc<MemoryMailer>,
+    email_verification_policy: EmailVerificationPolicy,
+    login_throttle_policy: LoginThrottlePolicy,
+) -> Router {
+    app_with_db(
+        Arc::new(MemoryDB::new()),
+        mailer,
+        email_verification_policy,
+        login_throttle_policy,
+    )
+}
+
+/// Runs against the database at `DATABASE_URL`, which the sqlx macros need to build anyway.
+fn pg_app() -> Router {
+    let pool = PgPoolOptions::new()
+        .connect_lazy(&env::var("DATABASE_URL").unwrap())
+        .unwrap();
+    app_with_db(
+        Arc::new(PgDB::new(pool)),
+        Arc::new(MemoryMailer::new()),
+        EmailVerificationPolicy::default(),
+        LoginThrottlePolicy::default(),
+    )
+}
+
+fn app_with_db(
+    db: Arc<dyn DB>,
+    mailer: Arc<MemoryMailer>,
+    email_verification_policy: EmailVerificationPolicy,
+    login_throttle_policy: LoginThrottlePolicy,
+) -> Router {
+    todo_app::app(
+        db,
+        Arc::new(MemorySessionStore::new(SessionExpiry::default())),
+        SessionCookie::default(),
+        mailer,
+        chrono::Duration::hours(1),
+        email_verification_policy,
+        LoginThrottle::new(
+            Arc::new(MemoryLoginAttemptStore::new()),
+            login_throttle_policy,
+        ),
+        // The lowest cost argon2 allows, to keep the tests fast.
+        PasswordHasher::new(8, 1, 1).unwrap(),
+    )
+}
+
+/// Waits until `count` mails have been sent, for mail that is sent in the background.
+async fn wait_for_mail(mailer: &MemoryMailer, count: usize) {
+    for _ in 0..100 {
+        if mailer.messages().len() >= count {
+            return;
+        }
+        tokio::time::sleep(std::time::Duration::from_millis(10)).await;
+    }
+    panic!("expected {count} mails, got {:?}", mailer.messages());
+}
+
+/// Returns the token at the end of the last mail sent to `to`.
+fn last_token(mailer: &MemoryMailer, to: &str) -> String {
+    let message = mailer
+        .messages()
+        .into_iter()
+        .rev()
+        .find(|message| message.to().as_str() == to)
+        .unwrap();
+    message
+        .body()
+        .trim_end()
+        .rsplit('\n')
+        .next()
+        .unwrap()
+        .to_owned()
+}
+
+async fn send(
+    app: &Router,
+    method: Method,
+    uri: &str,
+    cookie: Option<&str>,
+    body: Option<Value>,
+) -> (StatusCode, Option<String>, Value) {
+    let mut request = Request::builder()
+        .method(method)
+        .uri(uri)
+        .extension(ConnectInfo(SocketAddr::from(([127, 0, 0, 1], 0))));
+    if let Some(cookie) = cookie {
+        request = request.header(header::COOKIE, cookie);
+    }
+    let request = match body {
+        Some(body) => request
+            .header(header::CONTENT_TYPE, "application/json")
+            .body(Body::from(body.to_string())),
+        None => request.body(Body::empty()),
+    }
+    .unwrap();
+
+    let response = app.clone().oneshot(request).await.unwrap();
+    let status = response.status();
+    let cookie = response
+        .headers()
+        .get(header::SET_COOKIE)
+        .and_then(|v| v.to_str().ok())
+        .and_then(|v| v.split(';').next())
+        .map(ToOwned::to_owned);
+    let body = hyper::body::to_bytes(response.into_body()).await.unwrap();
+    let body = serde_json::from_slice(&body).unwrap_or(Value::Null);
+
+    (status, cookie, body)
+}
+
+async fn signup_and_login(app: &Router, email: &str) -> String {
+    let (status, _, _) = send(
+        app,
+        Method::POST,
+        "/signup",
+        None,
+        Some(json!({ "name": "user", "email": email, "password": "password" })),
+    )
+    .await;
+    assert_eq!(status, StatusCode::OK);
+
+    let (status, cookie, _) = send(
+        app,
+        Method::POST,
+        "/login",
+        None,
+        Some(json!({ "email": email, "password": "password" })),
+    )
+    .await;
+    assert_eq!(status, StatusCode::OK);
+
+    cookie.unwrap()
+}
+
+async fn login_as(app: &Router, email: &str, password: &str) -> (StatusCode, Option<String>) {
+    let (status, cookie, _) = send(
+        app,
+        Method::POST,
+        "/login",
+        None,
+        Some(json!({ "email": email, "password": password })),
+    )
+    .await;
+    (status, cookie)
+}
+
+/// Logs in from the given client address, returning the status and the `Retry-After` seconds.
+async fn login_from(
+    app: &Router,
+    ip: [u8; 4],
+    email: &str,
+    password: &str,
+) -> (StatusCode, Option<u64>) {
+    let request = Request::builder()
+        .method(Method::POST)
+        .uri("/login")
+        .extension(ConnectInfo(SocketAddr::from((ip, 0))))
+        .header(header::CONTENT_TYPE, "application/json")
+        .body(Body::from(
+            json!({ "email": email, "password": password }).to_string(),
+        ))
+        .unwrap();
+
+    let response = app.clone().oneshot(request).await.unwrap();
+    let retry_after = response
+        .headers()
+        .get(header::RETRY_AFTER)
+        .and_then(|v| v.to_str().ok())
+        .and_then(|v| v.parse().ok());
+
+    (response.status(), retry_after)
+}
+
+#[tokio::test]
+async fn signup() {
+    let app = app();
+    let tests = vec![
+        (
+            json!({ "name": "user", "email": "user@example.com", "password": "password" }),
+            StatusCode::OK,
+        ),
+        (
+            json!({ "name": "user", "email": "USER@example.com", "password": "password" }),
+            StatusCode::CONFLICT,
+        ),
+        (
+            json!({ "name": "", "email": "invalid", "password": "short" }),
+            StatusCode::BAD_REQUEST,
+        ),
+    ];
+
+    for (input, expected) in tests {
+        let (status, _, body) =
+            send(&app, Method::POST, "/signup", None, Some(input.clone())).await;
+        assert_eq!(status, expected, "input: {input}, body: {body}");
+    }
+}
+
+#[tokio::test]
+async fn signup_validation_errors() {
+    let app = app();
+
+    let (status, _, body) = send(
+        &app,
+        Method::POST,
+        "/signup",
+        None,
+        Some(json!({ "name": "", "email": "invalid", "password": "x".repeat(200) })),
+    )
+    .await;
+
+    assert_eq!(status, StatusCode::BAD_REQUEST);
+    assert_eq!(
+        body["errors"],
+        json!([
+            { "field": "email", "code": "email" },
+            { "field": "name", "code": "required" },
+            { "field": "password", "code": "password" },
+        ])
+    );
+
+    let (status, _, body) = send(
+        &app,
+        Method::POST,
+        "/signup",
+        None,
+        Some(
+            json!({ "name": "x".repeat(31), "email": "user@example.com", "password": "password" }),
+        ),
+    )
+    .await;
+
+    assert_eq!(status, StatusCode::BAD_REQUEST);
+    assert_eq!(
+        body["errors"],
+        json!([{ "field": "name", "code": "length", "params": { "max": 30 } }])
+    );
+}
+
+#[tokio::test]
+async fn login() {
+    let app = app();
+    signup_and_login(&app, "user@example.com").await;
+
+    let tests = vec![
+        ("user@example.com", "password", StatusCode::OK),
+        (
+            "user@example.com",
+            "wrong password",
+            StatusCode::BAD_REQUEST,
+        ),
+        ("unknown@example.com", "password", StatusCode::BAD_REQUEST),
+    ];
+
+    for (email, password, expected) in tests {
+        let (status, _, body) = send(
+            &app,
+            Method::POST,
+            "/login",
+            None,
+            Some(json!({ "email": email, "password": password })),
+        )
+        .await;
+        assert_eq!(status, expected, "email: {email}, body: {body}");
+    }
+}
+
+#[tokio::test]
+async fn logout() {
+    let app = app();
+    let cookie = signup_and_login(&app, "user@example.com").await;
+
+    let (status, _, _) = send(&app, Method::POST, "/logout", Some(&cookie), None).await;
+    assert_eq!(status, StatusCode::OK);
+
+    let (status, _, _) = send(&app, Method::GET, "/todos", Some(&cookie), None).await;
+    assert_eq!(status, StatusCode::UNAUTHORIZED);
+}
+
+#[tokio::test]
+async fn todos() {
+    let app = app();
+    let cookie = signup_and_login(&app, "user@example.com").await;
+
+    let (status, _, _) = send(&app, Method::GET, "/todos", None, None).await;
+    assert_eq!(status, StatusCode::UNAUTHORIZED);
+
+    let (status, _, todo) = send(
+        &app,
+        Method::POST,
+        "/todos",
+        Some(&cookie),
+        Some(json!({ "title": "new todo" })),
+    )
+    .await;
+    assert_eq!(status, StatusCode::OK);
+    assert_eq!(todo["title"], "new todo");
+    let uri = format!("/todos/{}", todo["id"].as_str().unwrap());
+
+    let (status, _, body) = send(&app, Method::GET, "/todos", Some(&cookie), None).await;
+    assert_eq!(status, StatusCode::OK);
+    assert_eq!(body["todos"], json!([todo]));
+
+    let (status, _, body) = send(
+        &app,
+        Method::PATCH,
+        &uri,
+        Some(&cookie),
+        Some(json!({ "title": "updated todo" })),
+    )
+    .await;
+    assert_eq!(status, StatusCode::OK);
+    assert_eq!(body["title"], "updated todo");
+
+    let (status, _, body) = send(&app, Method::GET, &uri, Some(&cookie), None).await;
+    assert_eq!(status, StatusCode::OK);
+    assert_eq!(body["title"], "updated todo");
+
+    let (status, _, _) = send(&app, Method::DELETE, &uri, Some(&cookie), None).await;
+    assert_eq!(status, StatusCode::OK);
+
+    let (status, _, _) = send(&app, Method::GET, &uri, Some(&cookie), None).await;
+    assert_eq!(status, StatusCode::NOT_FOUND);
+}
+
+#[tokio::test]
+async fn todo_details() {
+    let app = app();
+    let cookie = signup_and_login(&app, "user@example.com").await;
+
+    let tests = vec![
+        (
+            json!({ "title": "todo", "description": "", "due_date": "tomorrow" }),
+            StatusCode::BAD_REQUEST,
+        ),
+        (
+            json!({ "title": "todo", "description": "x".repeat(1001) }),
+            StatusCode::BAD_REQUEST,
+        ),
+        (
+            json!({ "title": "todo", "description": "details", "due_date": "2022-09-01" }),
+            StatusCode::OK,
+        ),
+    ];
+
+    for (input, expected) in tests {
+        let (status, _, body) = send(
+            &app,
+            Method::POST,
+            "/todos",
+            Some(&cookie),
+            Some(input.clone()),
+        )
+        .await;
+        assert_eq!(status, expected, "input: {input}, body: {body}");
+    }
+
+    let (_, _, body) = send(
+        &app,
+        Method::POST,
+        "/todos",
+        Some(&cookie),
+        Some(json!({ "title": "todo", "description": "", "due_date": "tomorrow" })),
+    )
+    .await;
+    assert_eq!(
+        body["errors"],
+        json!([
+            { "field": "description", "code": "required" },
+            { "field": "due_date", "code": "invalid" },
+        ])
+    );
+
+    let (_, _, todo) = send(&app, Method::GET, "/todos", Some(&cookie), None).await;
+    let todo = &todo["todos"][0];
+    assert_eq!(todo["description"], "details");
+    assert_eq!(todo["due_date"], "2022-09-01");
+    assert_eq!(todo["completed"], false);
+    assert_eq!(todo["completed_at"], Value::Null);
+    assert_eq!(todo["created_at"], todo["updated_at"]);
+    let uri = format!("/todos/{}", todo["id"].as_str().unwrap());
+
+    let (status, _, completed) = send(
+        &app,
+        Method::PATCH,
+        &uri,
+        Some(&cookie),
+        Some(json!({ "completed": true })),
+    )
+    .await;
+    assert_eq!(status, StatusCode::OK);
+    assert_eq!(completed["completed"], true);
+    assert_ne!(completed["completed_at"], Value::Null);
+    assert_ne!(completed["updated_at"], todo["updated_at"]);
+    assert_eq!(completed["created_at"], todo["created_at"]);
+
+    let (_, _, body) = send(
+        &app,
+        Method::PATCH,
+        &uri,
+        Some(&cookie),
+        Some(json!({ "completed": true, "description": null })),
+    )
+    .await;
+    assert_eq!(body["completed_at"], completed["completed_at"]);
+    assert_eq!(body["description"], Value::Null);
+    assert_eq!(body["due_date"], "2022-09-01");
+
+    let (_, _, body) = send(
+        &app,
+        Method::PATCH,
+        &uri,
+        Some(&cookie),
+        Some(json!({ "completed": false, "due_date": null })),
+    )
+    .await;
+    assert_eq!(body["completed"], false);
+    assert_eq!(body["completed_at"], Value::Null);
+    assert_eq!(body["due_date"], Value::Null);
+    assert_eq!(body["title"], "todo");
+}
+
+#[tokio::test]
+async fn todos_query() {
+    let app = app();
+    let cookie = signup_and_login(&app, "user@example.com").await;
+
+    let todos = vec![
+        json!({ "title": "Buy milk", "due_date": "2022-09-01" }),
+        json!({ "title": "Write report", "description": "quarterly milk numbers", "due_date": "2022-09-03" }),
+        json!({ "title": "Call mom" }),
+        json!({ "title": "Pay rent", "due_date": "2022-09-02" }),
+    ];
+    for todo in todos {
+        let (status, _, _) = send(&app, Method::POST, "/todos", Some(&cookie), Some(todo)).await;
+        assert_eq!(status, StatusCode::OK);
+    }
+    let (_, _, body) = send(&app, Method::GET, "/todos", Some(&cookie), None).await;
+    let uri = format!("/todos/{}", body["todos"][0]["id"].as_str().unwrap());
+    send(
+        &app,
+        Method::PATCH,
+        &uri,
+        Some(&cookie),
+        Some(json!({ "completed": true })),
+    )
+    .await;
+
+    let titles = |body: &Value| {
+        body["todos"]
+            .as_array()
+            .unwrap()
+            .iter()
+            .map(|todo| todo["title"].as_str().unwrap().to_owned())
+            .collect::<Vec<_>>()
+    };
+    let tests = vec![
+        (
+            "/todos",
+            vec!["Buy milk", "Write report", "Call mom", "Pay rent"],
+        ),
+        (
+            "/todos?sort=due_date",
+            vec!["Buy milk", "Pay rent", "Write report", "Call mom"],
+        ),
+        (
+            "/todos?sort=title&order=desc",
+            vec!["Write report", "Pay rent", "Call mom", "Buy milk"],
+        ),
+        (
+            "/todos?status=open&sort=title",
+            vec!["Call mom", "Pay rent", "Write report"],
+        ),
+        ("/todos?status=completed", vec!["Buy milk"]),
+        (
+            "/todos?due_after=2022-09-01&due_before=2022-09-03",
+            vec!["Pay rent"],
+        ),
+        ("/todos?contains=MILK", vec!["Buy milk", "Write report"]),
+    ];
+
+    for (uri, expected) in tests {
+        let (status, _, body) = send(&app, Method::GET, uri, Some(&cookie), None).await;
+        assert_eq!(status, StatusCode::OK, "uri: {uri}, body: {body}");
+        assert_eq!(titles(&body), expected, "uri: {uri}");
+        assert_eq!(body["next_cursor"], Value::Null, "uri: {uri}");
+    }
+
+    let mut pages = vec![];
+    let mut uri = "/todos?sort=due_date&order=desc&limit=3".to_owned();
+    loop {
+        let (status, _, body) = send(&app, Method::GET, &uri, Some(&cookie), None).await;
+        assert_eq!(status, StatusCode::OK, "uri: {uri}, body: {body}");
+        pages.push(titles(&body));
+        match body["next_cursor"].as_str() {
+            Some(cursor) => {
+                uri = format!("/todos?sort=due_date&order=desc&limit=3&cursor={cursor}")
+            }
+            None => break,
+        }
+    }
+    assert_eq!(
+        pages,
+        vec![
+            vec!["Call mom", "Write report", "Pay rent"],
+            vec!["Buy milk"],
+        ]
+    );
+
+    let (_, _, body) = send(&app, Method::GET, "/todos?limit=1", Some(&cookie), None).await;
+    let cursor = body["next_cursor"].as_str().unwrap();
+    let tests = vec![
+        "/todos?status=done&due_before=tomorrow&sort=priority&order=up&limit=ten".to_owned(),
+        "/todos?limit=0".to_owned(),
+        "/todos?limit=101".to_owned(),
+        "/todos?cursor=invalid".to_owned(),
+        format!("/todos?sort=title&cursor={cursor}"),
+    ];
+    for uri in tests {
+        let (status, _, body) = send(&app, Method::GET, &uri, Some(&cookie), None).await;
+        assert_eq!(status, StatusCode::BAD_REQUEST, "uri: {uri}, body: {body}");
+    }
+}
+
+#[tokio::test]
+async fn todos_search() {
+    let app = app();
+    let cookie = signup_and_login(&app, "user@example.com").await;
+    let other_cookie = signup_and_login(&app, "other@example.com").await;
+
+    let todos = vec![
+        (&cookie, json!({ "title": "Buy milk" })),
+        (
+            &cookie,
+            json!({ "title": "Write report", "description": "milk <b>prices</b>" }),
+        ),
+        (&cookie, json!({ "title": "Pay rent" })),
+        (&other_cookie, json!({ "title": "Buy more milk" })),
+    ];
+    for (cookie, todo) in todos {
+        let (status, _, _) = send(&app, Method::POST, "/todos", Some(cookie), Some(todo)).await;
+        assert_eq!(status, StatusCode::OK);
+    }
+
+    let (status, _, body) = send(
+        &app,
+        Method::GET,
+        "/todos/search?q=milk",
+        Some(&cookie),
+        None,
+    )
+    .await;
+    assert_eq!(status, StatusCode::OK);
+    let todos = body["todos"].as_array().unwrap();
+    assert_eq!(todos.len(), 2);
+    assert_eq!(todos[0]["title"], "Buy milk");
+    assert_eq!(todos[0]["snippet"], "Buy <mark>milk</mark>");
+    assert_eq!(todos[1]["title"], "Write report");
+    assert_eq!(
+        todos[1]["snippet"],
+        "Write report: <mark>milk</mark> &lt;b&gt;prices&lt;/b&gt;"
+    );
+    assert!(todos[0]["rank"].as_f64().unwrap() > todos[1]["rank"].as_f64().unwrap());
+    assert_eq!(todos[1]["description"], "milk <b>prices</b>");
+
+    let (_, _, body) = send(
+        &app,
+        Method::GET,
+        "/todos/search?q=milk&limit=1",
+        Some(&cookie),
+        None,
+    )
+    .await;
+    assert_eq!(body["todos"].as_array().unwrap().len(), 1);
+
+    let tests = vec![
+        (
+            "/todos/search",
+            json!([{ "field": "q", "code": "required" }]),
+        ),
+        (
+            "/todos/search?q=%20&limit=0",
+            json!([
+                { "field": "limit", "code": "invalid" },
+                { "field": "q", "code": "required" },
+            ]),
+        ),
+    ];
+    for (uri, expected) in tests {
+        let (status, _, body) = send(&app, Method::GET, uri, Some(&cookie), None).await;
+        assert_eq!(status, StatusCode::BAD_REQUEST, "uri: {uri}");
+        assert_eq!(body["errors"], expected, "uri: {uri}");
+    }
+
+    let (status, _, _) = send(&app, Method::GET, "/todos/search?q=milk", None, None).await;
+    assert_eq!(status, StatusCode::UNAUTHORIZED);
+}
+
+#[tokio::test]
+async fn tags() {
+    let app = app();
+    let cookie = signup_and_login(&app, "user@example.com").await;
+    let other_cookie = signup_and_login(&app, "other@example.com").await;
+
+    let tests = vec![
+        (
+            json!({ "name": "", "color": "blue" }),
+            StatusCode::BAD_REQUEST,
+        ),
+        (
+            json!({ "name": "work", "color": "#1E90FF" }),
+            StatusCode::OK,
+        ),
+        (
+            json!({ "name": "Work", "color": "#000000" }),
+            StatusCode::CONFLICT,
+        ),
+        (
+            json!({ "name": "home", "color": "#228b22" }),
+            StatusCode::OK,
+        ),
+    ];
+    for (input, expected) in tests {
+        let (status, _, body) = send(
+            &app,
+            Method::POST,
+            "/tags",
+            Some(&cookie),
+            Some(input.clone()),
+        )
+        .await;
+        assert_eq!(status, expected, "input: {input}, body: {body}");
+    }
+    let (_, _, other_tag) = send(
+        &app,
+        Method::POST,
+        "/tags",
+        Some(&other_cookie),
+        Some(json!({ "name": "work", "color": "#ffffff" })),
+    )
+    .await;
+
+    let (status, _, body) = send(&app, Method::GET, "/tags", Some(&cookie), None).await;
+    assert_eq!(status, StatusCode::OK);
+    let tags = body["tags"].as_array().unwrap();
+    assert_eq!(tags.len(), 2);
+    assert_eq!(tags[0]["name"], "home");
+    assert_eq!(tags[1]["name"], "work");
+    assert_eq!(tags[1]["color"], "#1e90ff");
+    let (home, work) = (
+        tags[0]["id"].as_str().unwrap(),
+        tags[1]["id"].as_str().unwrap(),
+    );
+
+    let uri = format!("/tags/{work}");
+    let (status, _, body) = send(
+        &app,
+        Method::PATCH,
+        &uri,
+        Some(&cookie),
+        Some(json!({ "name": "office" })),
+    )
+    .await;
+    assert_eq!(status, StatusCode::OK);
+    assert_eq!(body["name"], "office");
+    assert_eq!(body["color"], "#1e90ff");
+    let (status, _, _) = send(&app, Method::GET, &uri, Some(&other_cookie), None).await;
+    assert_eq!(status, StatusCode::NOT_FOUND);
+
+    let mut todos = vec![];
+    for (title, tag_ids) in [
+        ("both", json!([work, home])),
+        ("work", json!([work])),
+        ("none", json!([])),
+    ] {
+        let (_, _, todo) = send(
+            &app,
+            Method::POST,
+            "/todos",
+            Some(&cookie),
+            Some(json!({ "title": title })),
+        )
+        .await;
+        let uri = format!("/todos/{}/tags", todo["id"].as_str().unwrap());
+        let (status, _, todo) = send(
+            &app,
+            Method::PUT,
+            &uri,
+            Some(&cookie),
+            Some(json!({ "tag_ids": tag_ids })),
+        )
+        .await;
+        assert_eq!(status, StatusCode::OK, "title: {title}");
+        assert_eq!(
+            todo["tag_ids"].as_array().unwrap().len(),
+            tag_ids.as_array().unwrap().len()
+        );
+        todos.push(uri);
+    }
+
+    let (status, _, _) = send(
+        &app,
+        Method::PUT,
+        &todos[2],
+        Some(&cookie),
+        Some(json!({ "tag_ids": [other_tag["id"]] })),
+    )
+    .await;
+    assert_eq!(status, StatusCode::BAD_REQUEST);
+
+    let titles = |body: &Value| {
+        body["todos"]
+            .as_array()
+            .unwrap()
+            .iter()
+            .map(|todo| todo["title"].as_str().unwrap().to_owned())
+            .collect::<Vec<_>>()
+    };
+    let tests = vec![
+        (format!("/todos?tags={work}"), vec!["both", "work"]),
+        (format!("/todos?tags={work},{home}"), vec!["both"]),
+        (
+            format!("/todos?tags={}", other_tag["id"].as_str().unwrap()),
+            vec![],
+        ),
+    ];
+    for (uri, expected) in tests {
+        let (status, _, body) = send(&app, Method::GET, &uri, Some(&cookie), None).await;
+        assert_eq!(status, StatusCode::OK, "uri: {uri}");
+        assert_eq!(titles(&body), expected, "uri: {uri}");
+    }
+    let (status, _, _) = send(&app, Method::GET, "/todos?tags=work", Some(&cookie), None).await;
+    assert_eq!(status, StatusCode::BAD_REQUEST);
+
+    let (status, _, _) = send(&app, Method::DELETE, &uri, Some(&cookie), None).await;
+    assert_eq!(status, StatusCode::OK);
+    let (_, _, body) = send(
+        &app,
+        Method::GET,
+        &format!("/todos?tags={home}"),
+        Some(&cookie),
+        None,
+    )
+    .await;
+    assert_eq!(body["todos"][0]["tag_ids"], json!([home]));
+    let (status, _, _) = send(&app, Method::DELETE, &uri, Some(&cookie), None).await;
+    assert_eq!(status, StatusCode::NOT_FOUND);
+
+    let (status, _, _) = send(&app, Method::DELETE, "/me", Some(&cookie), None).await;
+    assert_eq!(status, StatusCode::OK);
+}
+
+#[tokio::test]
+async fn todo_lists() {
+    let app = app();
+    let cookie = signup_and_login(&app, "user@example.com").await;
+    let other_cookie = signup_and_login(&app, "other@example.com").await;
+
+    let (status, _, body) = send(&app, Method::GET, "/todo-lists", Some(&cookie), None).await;
+    assert_eq!(status, StatusCode::OK);
+    assert_eq!(body["todo_lists"].as_array().unwrap().len(), 1);
+    assert_eq!(body["todo_lists"][0]["name"], "Inbox");
+    assert_eq!(body["todo_lists"][0]["inbox"], true);
+    let inbox = body["todo_lists"][0]["id"].as_str().unwrap().to_owned();
+    let inbox_uri = format!("/todo-lists/{inbox}");
+
+    let (status, _, _) = send(
+        &app,
+        Method::POST,
+        "/todo-lists",
+        Some(&cookie),
+        Some(json!({ "name": "" })),
+    )
+    .await;
+    assert_eq!(status, StatusCode::BAD_REQUEST);
+    let mut lists = vec![];
+    for name in ["work", "home"] {
+        let (status, _, body) = send(
+            &app,
+            Method::POST,
+            "/todo-lists",
+            Some(&cookie),
+            Some(json!({ "name": name })),
+        )
+        .await;
+        assert_eq!(status, StatusCode::OK, "name: {name}");
+        lists.push(body["id"].as_str().unwrap().to_owned());
+    }
+    let (work, home) = (&lists[0], &lists[1]);
+    let (work_uri, home_uri) = (format!("/todo-lists/{work}"), format!("/todo-lists/{home}"));
+
+    let (status, _, body) = send(
+        &app,
+        Method::PATCH,
+        &home_uri,
+        Some(&cookie),
+        Some(json!({ "name": "house", "position": 0 })),
+    )
+    .await;
+    assert_eq!(status, StatusCode::OK);
+    assert_eq!(body["name"], "house");
+    let (_, _, body) = send(&app, Method::GET, "/todo-lists", Some(&cookie), None).await;
+    let names = body["todo_lists"]
+        .as_array()
+        .unwrap()
+        .iter()
+        .map(|todo_list| todo_list["name"].as_str().unwrap())
+        .collect::<Vec<_>>();
+    assert_eq!(names.len(), 3);
+    assert_eq!(names[2], "work");
+
+    let (status, _, body) = send(
+        &app,
+        Method::PATCH,
+        &work_uri,
+        Some(&cookie),
+        Some(json!({ "position": 10_001 })),
+    )
+    .await;
+    assert_eq!(status, StatusCode::BAD_REQUEST);
+    assert_eq!(
+        body["errors"],
+        json!([{ "field": "position", "code": "invalid" }])
+    );
+    let (status, _, _) = send(
+        &app,
+        Method::PATCH,
+        &work_uri,
+        Some(&cookie),
+        Some(json!({ "position": 10_000 })),
+    )
+    .await;
+    assert_eq!(status, StatusCode::OK);
+    let (status, _, _) = send(
+        &app,
+        Method::POST,
+        "/todo-lists",
+        Some(&cookie),
+        Some(json!({ "name": "one too many" })),
+    )
+    .await;
+    assert_eq!(status, StatusCode::BAD_REQUEST);
+
+    let (status, _, _) = send(&app, Method::GET, &work_uri, Some(&other_cookie), None).await;
+    assert_eq!(status, StatusCode::NOT_FOUND);
+
+    let (_, _, todo) = send(
+        &app,
+        Method::POST,
+        "/todos",
+        Some(&cookie),
+        Some(json!({ "title": "inbox todo" })),
+    )
+    .await;
+    assert_eq!(todo["todo_list_id"], inbox);
+    let (status, _, _) = send(
+        &app,
+        Method::POST,
+        "/todos",
+        Some(&other_cookie),
+        Some(json!({ "title": "other todo", "todo_list_id": work })),
+    )
+    .await;
+    assert_eq!(status, StatusCode::BAD_REQUEST);
+    for (title, list) in [("work todo", work), ("home todo", home)] {
+        let (status, _, todo) = send(
+            &app,
+            Method::POST,
+            "/todos",
+            Some(&cookie),
+            Some(json!({ "title": title, "todo_list_id": list })),
+        )
+        .await;
+        assert_eq!(status, StatusCode::OK, "title: {title}");
+        assert_eq!(&todo["todo_list_id"], list, "title: {title}");
+    }
+
+    let move_uri = format!("/todos/{}/list", todo["id"].as_str().unwrap());
+    let (status, _, body) = send(
+        &app,
+        Method::PUT,
+        &move_uri,
+        Some(&cookie),
+        Some(json!({ "todo_list_id": work })),
+    )
+    .await;
+    assert_eq!(status, StatusCode::OK);
+    assert_eq!(&body["todo_list_id"], work);
+
+    let titles = |body: &Value| {
+        body["todos"]
+            .as_array()
+            .unwrap()
+            .iter()
+            .map(|todo| todo["title"].as_str().unwrap().to_owned())
+            .collect::<Vec<_>>()
+    };
+    let (status, _, body) = send(
+        &app,
+        Method::GET,
+        &format!("/todos?list={work}"),
+        Some(&cookie),
+        None,
+    )
+    .await;
+    assert_eq!(status, StatusCode::OK);
+    assert_eq!(titles(&body), vec!["inbox todo", "work todo"]);
+
+    let (status, _, body) = send(
+        &app,
+        Method::PATCH,
+        &home_uri,
+        Some(&cookie),
+        Some(json!({ "archived": true })),
+    )
+    .await;
+    assert_eq!(status, StatusCode::OK);
+    assert_eq!(body["archived"], true);
+    let (status, _, _) = send(
+        &app,
+        Method::PUT,
+        &move_uri,
+        Some(&cookie),
+        Some(json!({ "todo_list_id": home })),
+    )
+    .await;
+    assert_eq!(status, StatusCode::BAD_REQUEST);
+    let (status, _, _) = send(
+        &app,
+        Method::PATCH,
+        &inbox_uri,
+        Some(&cookie),
+        Some(json!({ "archived": true })),
+    )
+    .await;
+    assert_eq!(status, StatusCode::BAD_REQUEST);
+
+    let tests = vec![
+        (
+            format!("{inbox_uri}?todos=cascade"),
+            StatusCode::BAD_REQUEST,
+        ),
+        (format!("{work_uri}?todos=keep"), StatusCode::BAD_REQUEST),
+        (work_uri.clone(), StatusCode::BAD_REQUEST),
+        (format!("{work_uri}?todos=move_to_inbox"), StatusCode::OK),
+        (format!("{home_uri}?todos=cascade"), StatusCode::OK),
+        (format!("{home_uri}?todos=cascade"), StatusCode::NOT_FOUND),
+    ];
+    for (uri, expected) in tests {
+        let (status, _, body) = send(&app, Method::DELETE, &uri, Some(&cookie), None).await;
+        assert_eq!(status, expected, "uri: {uri}, body: {body}");
+    }
+
+    let (_, _, body) = send(&app, Method::GET, "/todos", Some(&cookie), None).await;
+    assert_eq!(titles(&body), vec!["inbox todo", "work todo"]);
+    assert!(body["todos"]
+        .as_array()
+        .unwrap()
+        .iter()
+        .all(|todo| todo["todo_list_id"] == inbox));
+
+    let (status, _, _) = send(&app, Method::DELETE, "/me", Some(&cookie), None).await;
+    assert_eq!(status, StatusCode::OK);
+}
+
+#[tokio::test]
+async fn todos_of_another_user() {
+    let app = app();
+    let cookie = signup_and_login(&app, "user@example.com").await;
+    let other_cookie = signup_and_login(&app, "other@example.com").await;
+
+    let (_, _, todo) = send(
+        &app,
+        Method::POST,
+        "/todos",
+        Some(&cookie),
+        Some(json!({ "title": "new todo" })),
+    )
+    .await;
+    let uri = format!("/todos/{}", todo["id"].as_str().unwrap());
+
+    let tests = vec![
+        (Method::GET, None),
+        (Method::PATCH, Some(json!({ "title": "updated todo" }))),
+        (Method::DELETE, None),
+    ];
+
+    for (method, body) in tests {
+        let (status, _, _) = send(&app, method.clone(), &uri, Some(&other_cookie), body).await;
+        assert_eq!(status, StatusCode::NOT_FOUND, "method: {method}");
+    }
+
+    let (status, _, body) = send(&app, Method::GET, "/todos", Some(&other_cookie), None).await;
+    assert_eq!(status, StatusCode::OK);
+    assert_eq!(body["todos"], json!([]));
+}
+
+#[tokio::test]
+async fn password_reset() {
+    let mailer = Arc::new(MemoryMailer::new());
+    let app = app_with_mailer(mailer.clone());
+    let cookie = signup_and_login(&app, "user@example.com").await;
+
+    for email in ["unknown@example.com", "invalid", "USER@example.com"] {
+        let (status, _, _) = send(
+            &app,
+            Method::POST,
+            "/password-reset/request",
+            None,
+            Some(json!({ "email": email })),
+        )
+        .await;
+        assert_eq!(status, StatusCode::ACCEPTED, "email: {email}");
+    }
+
+    wait_for_mail(&mailer, 2).await;
+    let messages = mailer.messages();
+    assert_eq!(messages.len(), 2, "verification and reset mail");
+    let token = last_token(&mailer, "user@example.com");
+
+    let tests = vec![
+        (
+            json!({ "token": "0".repeat(64), "password": "newpassword" }),
+            StatusCode::BAD_REQUEST,
+        ),
+        (
+            json!({ "token": token, "password": "short" }),
+            StatusCode::BAD_REQUEST,
+        ),
+        (
+            json!({ "token": token, "password": "newpassword" }),
+            StatusCode::OK,
+        ),
+        (
+            json!({ "token": token, "password": "newpassword" }),
+            StatusCode::BAD_REQUEST,
+        ),
+    ];
+
+    for (input, expected) in tests {
+        let (status, _, body) = send(
+            &app,
+            Method::POST,
+            "/password-reset/confirm",
+            None,
+            Some(input.clone()),
+        )
+        .await;
+        assert_eq!(status, expected, "input: {input}, body: {body}");
+    }
+
+    let (status, _, _) = send(&app, Method::GET, "/todos", Some(&cookie), None).await;
+    assert_eq!(status, StatusCode::UNAUTHORIZED);
+
+    let tests = vec![
+        ("password", StatusCode::BAD_REQUEST),
+        ("newpassword", StatusCode::OK),
+    ];
+
+    for (password, expected) in tests {
+        let (status, _, _) = send(
+            &app,
+            Method::POST,
+            "/login",
+            None,
+            Some(json!({ "email": "user@example.com", "password": password })),
+        )
+        .await;
+        assert_eq!(status, expected, "password: {password}");
+    }
+}
+
+#[tokio::test]
+async fn change_password() {
+    let app = app();
+    let cookie = signup_and_login(&app, "user@example.com").await;
+    let (_, other_cookie) = login_as(&app, "user@example.com", "password").await;
+    let other_cookie = other_cookie.unwrap();
+
+    let (status, _, _) = send(
+        &app,
+        Method::PUT,
+        "/me/password",
+        None,
+        Some(json!({ "current_password": "password", "new_password": "newpassword" })),
+    )
+    .await;
+    assert_eq!(status, StatusCode::UNAUTHORIZED);
+
+    let tests = vec![
+        (
+            json!({ "current_password": "wrongpassword", "new_password": "newpassword" }),
+            StatusCode::BAD_REQUEST,
+        ),
+        (
+            json!({ "current_password": "password", "new_password": "short" }),
+            StatusCode::BAD_REQUEST,
+        ),
+        (
+            json!({ "current_password": "password", "new_password": "newpassword" }),
+            StatusCode::OK,
+        ),
+    ];
+
+    for (input, expected) in tests {
+        let (status, _, body) = send(
+            &app,
+            Method::PUT,
+            "/me/password",
+            Some(&cookie),
+            Some(input.clone()),
+        )
+        .await;
+        assert_eq!(status, expected, "input: {input}, body: {body}");
+    }
+
+    let tests = vec![
+        (&cookie, StatusCode::OK),
+        (&other_cookie, StatusCode::UNAUTHORIZED),
+    ];
+    for (cookie, expected) in tests {
+        let (status, _, _) = send(&app, Method::GET, "/todos", Some(cookie), None).await;
+        assert_eq!(status, expected, "cookie: {cookie}");
+    }
+
+    let tests = vec![
+        ("password", StatusCode::BAD_REQUEST),
+        ("newpassword", StatusCode::OK),
+    ];
+    for (password, expected) in tests {
+        let (status, _) = login_as(&app, "user@example.com", password).await;
+        assert_eq!(status, expected, "password: {password}");
+    }
+}
+
+#[tokio::test]
+async fn change_email() {
+    let app = app();
+    let cookie = signup_and_login(&app, "user@example.com").await;
+    signup_and_login(&app, "other@example.com").await;
+
+    let tests = vec![
+        (
+            json!({ "password": "wrongpassword", "email": "new@example.com" }),
+            StatusCode::BAD_REQUEST,
+        ),
+        (
+            json!({ "password": "password", "email": "invalid" }),
+            StatusCode::BAD_REQUEST,
+        ),
+        (
+            json!({ "password": "password", "email": "OTHER@example.com" }),
+            StatusCode::CONFLICT,
+        ),
+        (
+            json!({ "password": "password", "email": "new@example.com" }),
+            StatusCode::OK,
+        ),
+    ];
+
+    for (input, expected) in tests {
+        let (status, _, body) = send(
+            &app,
+            Method::PUT,
+            "/me/email",
+            Some(&cookie),
+            Some(input.clone()),
+        )
+        .await;
+        assert_eq!(status, expected, "input: {input}, body: {body}");
+    }
+
+    let tests = vec![
+        ("user@example.com", StatusCode::BAD_REQUEST),
+        ("new@example.com", StatusCode::OK),
+    ];
+    for (email, expected) in tests {
+        let (status, _) = login_as(&app, email, "password").await;
+        assert_eq!(status, expected, "email: {email}");
+    }
+}
+
+#[tokio::test]
+async fn me() {
+    let app = app();
+    let cookie = signup_and_login(&app, "user@example.com").await;
+
+    let (status, _, me) = send(&app, Method::GET, "/me", Some(&cookie), None).await;
+    assert_eq!(status, StatusCode::OK);
+    assert_eq!(me["name"], "user");
+    assert_eq!(me["email"], "user@example.com");
+    assert_eq!(me["email_verified"], false);
+
+    let tests = vec![
+        (json!({ "name": "" }), StatusCode::BAD_REQUEST, "user"),
+        (
+            json!({ "name": "x".repeat(31) }),
+            StatusCode::BAD_REQUEST,
+            "user",
+        ),
+        (json!({}), StatusCode::OK, "user"),
+        (json!({ "name": "new name" }), StatusCode::OK, "new name"),
+    ];
+
+    for (input, expected_status, expected_name) in tests {
+        let (status, _, body) = send(
+            &app,
+            Method::PATCH,
+            "/me",
+            Some(&cookie),
+            Some(input.clone()),
+        )
+        .await;
+        assert_eq!(status, expected_status, "input: {input}, body: {body}");
+
+        let (_, _, body) = send(&app, Method::GET, "/me", Some(&cookie), None).await;
+        assert_eq!(body["name"], expected_name, "input: {input}");
+        assert_eq!(body["id"], me["id"], "input: {input}");
+    }
+
+    let (_, _, body) = send(
+        &app,
+        Method::PATCH,
+        "/me",
+        Some(&cookie),
+        Some(json!({ "name": "" })),
+    )
+    .await;
+    assert_eq!(
+        body["errors"],
+        json!([{ "field": "name", "code": "required" }])
+    );
+}
+
+#[tokio::test]
+async fn delete_me() {
+    let app = app();
+    let cookie = signup_and_login(&app, "user@example.com").await;
+    let (_, other_cookie) = login_as(&app, "user@example.com", "password").await;
+    let other_cookie = other_cookie.unwrap();
+
+    let (status, _, _) = send(
+        &app,
+        Method::POST,
+        "/todos",
+        Some(&cookie),
+        Some(json!({ "title": "new todo" })),
+    )
+    .await;
+    assert_eq!(status, StatusCode::OK);
+
+    let (status, _, _) = send(&app, Method::DELETE, "/me", Some(&cookie), None).await;
+    assert_eq!(status, StatusCode::OK);
+
+    for cookie in [&cookie, &other_cookie] {
+        let (status, _, _) = send(&app, Method::GET, "/me", Some(cookie), None).await;
+        assert_eq!(status, StatusCode::UNAUTHORIZED, "cookie: {cookie}");
+    }
+
+    let (status, _) = login_as(&app, "user@example.com", "password").await;
+    assert_eq!(status, StatusCode::BAD_REQUEST);
+
+    signup_and_login(&app, "user@example.com").await;
+}
+
+#[tokio::test]
+async fn email_verification() {
+    let mailer = Arc::new(MemoryMailer::new());
+    let app = app_with_mailer(mailer.clone());
+    let cookie = signup_and_login(&app, "user@example.com").await;
+    let token = last_token(&mailer, "user@example.com");
+
+    let tests = vec![
+        (json!({ "token": "invalid" }), StatusCode::BAD_REQUEST),
+        (json!({ "token": "0".repeat(64) }), StatusCode::BAD_REQUEST),
+        (json!({ "token": token }), StatusCode::OK),
+        (json!({ "token": token }), StatusCode::BAD_REQUEST),
+    ];
+
+    for (input, expected) in tests {
+        let (status, _, body) = send(
+            &app,
+            Method::POST,
+            "/verify-email",
+            None,
+            Some(input.clone()),
+        )
+        .await;
+        assert_eq!(status, expected, "input: {input}, body: {body}");
+    }
+
+    let (_, _, me) = send(&app, Method::GET, "/me", Some(&cookie), None).await;
+    assert_eq!(me["email_verified"], true);
+
+    let (status, _, _) = send(
+        &app,
+        Method::PUT,
+        "/me/email",
+        Some(&cookie),
+        Some(json!({ "password": "password", "email": "new@example.com" })),
+    )
+    .await;
+    assert_eq!(status, StatusCode::OK);
+
+    let (_, _, me) = send(&app, Method::GET, "/me", Some(&cookie), None).await;
+    assert_eq!(me["email_verified"], false);
+
+    let token = last_token(&mailer, "new@example.com");
+    let (status, _, _) = send(
+        &app,
+        Method::POST,
+        "/verify-email",
+        None,
+        Some(json!({ "token": token })),
+    )
+    .await;
+    assert_eq!(status, StatusCode::OK);
+
+    let (_, _, me) = send(&app, Method::GET, "/me", Some(&cookie), None).await;
+    assert_eq!(me["email_verified"], true);
+}
+
+#[tokio::test]
+async fn email_verification_required() {
+    let tests = vec![
+        (
+            EmailVerificationPolicy::new(chrono::Duration::hours(1), true, false),
+            StatusCode::FORBIDDEN,
+            StatusCode::OK,
+        ),
+        (
+            EmailVerificationPolicy::new(chrono::Duration::hours(1), false, true),
+            StatusCode::OK,
+            StatusCode::FORBIDDEN,
+        ),
+    ];
+
+    for (policy, expected_login, expected_create_todo) in tests {
+        let mailer = Arc::new(MemoryMailer::new());
+        let app = app_with(mailer.clone(), policy, LoginThrottlePolicy::default());
+        let (status, _, _) = send(
+            &app,
+            Method::POST,
+            "/signup",
+            None,
+            Some(json!({ "name": "user", "email": "user@example.com", "password": "password" })),
+        )
+        .await;
+        assert_eq!(status, StatusCode::OK);
+
+        let (status, cookie) = login_as(&app, "user@example.com", "password").await;
+        assert_eq!(status, expected_login, "policy: {policy:?}");
+        if let Some(cookie) = cookie {
+            let (status, _, _) = send(
+                &app,
+                Method::POST,
+                "/todos",
+                Some(&cookie),
+                Some(json!({ "title": "todo" })),
+            )
+            .await;
+            assert_eq!(status, expected_create_todo, "policy: {policy:?}");
+        }
+
+        let token = last_token(&mailer, "user@example.com");
+        let (status, _, _) = send(
+            &app,
+            Method::POST,
+            "/verify-email",
+            None,
+            Some(json!({ "token": token })),
+        )
+        .await;
+        assert_eq!(status, StatusCode::OK);
+
+        let (status, cookie) = login_as(&app, "user@example.com", "password").await;
+        assert_eq!(status, StatusCode::OK, "policy: {policy:?}");
+        let (status, _, _) = send(
+            &app,
+            Method::POST,
+            "/todos",
+            Some(&cookie.unwrap()),
+            Some(json!({ "title": "todo" })),
+        )
+        .await;
+        assert_eq!(status, StatusCode::OK, "policy: {policy:?}");
+    }
+}
+
+#[tokio::test]
+async fn login_throttle() {
+    let app = app_with(
+        Arc::new(MemoryMailer::new()),
+        EmailVerificationPolicy::default(),
+        LoginThrottlePolicy::new(
+            3,
+            5,
+            chrono::Duration::minutes(1),
+            chrono::Duration::hours(1),
+            chrono::Duration::minutes(15),
+        ),
+    );
+    for email in ["user@example.com", "other@example.com"] {
+        let (status, _, _) = send(
+            &app,
+            Method::POST,
+            "/signup",
+            None,
+            Some(json!({ "name": "user", "email": email, "password": "password" })),
+        )
+        .await;
+        assert_eq!(status, StatusCode::OK);
+    }
+
+    // Registered and unknown addresses are locked out alike.
+    for (ip, email) in [
+        ([10, 0, 0, 1], "user@example.com"),
+        ([10, 0, 0, 2], "unknown@example.com"),
+    ] {
+        let tests = vec![
+            ("wrongpassword", StatusCode::BAD_REQUEST),
+            ("wrongpassword", StatusCode::BAD_REQUEST),
+            ("wrongpassword", StatusCode::BAD_REQUEST),
+            ("password", StatusCode::TOO_MANY_REQUESTS),
+        ];
+        for (password, expected) in tests {
+            let (status, retry_after) = login_from(&app, ip, email, password).await;
+            assert_eq!(status, expected, "email: {email}, password: {password}");
+            if status == StatusCode::TOO_MANY_REQUESTS {
+                assert!(
+                    matches!(retry_after, Some(1..=60)),
+                    "email: {email}, retry_after: {retry_after:?}"
+                );
+            }
+        }
+    }
+
+    // The lockout follows the account to other addresses, case-insensitively.
+    let (status, _) = login_from(&app, [10, 0, 0, 3], "USER@example.com", "password").await;
+    assert_eq!(status, StatusCode::TOO_MANY_REQUESTS);
+
+    // A successful login forgets the failures of the account.
+    for password in [
+        "wrongpassword",
+        "wrongpassword",
+        "password",
+        "wrongpassword",
+    ] {
+        login_from(&app, [10, 0, 0, 4], "other@example.com", password).await;
+    }
+    let (status, _) = login_from(&app, [10, 0, 0, 5], "other@example.com", "password").await;
+    assert_eq!(status, StatusCode::OK);
+
+    // Failures from one address add up across accounts.
+    for i in 0..5 {
+        let (status, _) = login_from(
+            &app,
+            [10, 0, 0, 6],
+            &format!("user{i}@example.com"),
+            "password",
+        )
+        .await;
+        assert_eq!(status, StatusCode::BAD_REQUEST, "i: {i}");
+    }
+    let (status, _) = login_from(&app, [10, 0, 0, 6], "other@example.com", "password").await;
+    assert_eq!(status, StatusCode::TOO_MANY_REQUESTS);
+    let (status, _) = login_from(&app, [10, 0, 0, 7], "other@example.com", "password").await;
+    assert_eq!(status, StatusCode::OK);
+}
+
+#[tokio::test]
+async fn credential_change_throttle() {
+    let app = app_with(
+        Arc::new(MemoryMailer::new()),
+        EmailVerificationPolicy::default(),
+        LoginThrottlePolicy::new(
+            3,
+            5,
+            chrono::Duration::minutes(1),
+            chrono::Duration::hours(1),
+            chrono::Duration::minutes(15),
+        ),
+    );
+    let cookie = signup_and_login(&app, "user@example.com").await;
+
+    let tests = vec![
+        ("wrongpassword", StatusCode::BAD_REQUEST),
+        ("wrongpassword", StatusCode::BAD_REQUEST),
+        ("wrongpassword", StatusCode::BAD_REQUEST),
+        ("password", StatusCode::TOO_MANY_REQUESTS),
+    ];
+    for (password, expected) in tests {
+        let (status, _, body) = send(
+            &app,
+            Method::PUT,
+            "/me/password",
+            Some(&cookie),
+            Some(json!({ "current_password": password, "new_password": "newpassword" })),
+        )
+        .await;
+        assert_eq!(status, expected, "password: {password}, body: {body}");
+    }
+
+    // The lockout is shared with changing the email address and logging in.
+    let (status, _, _) = send(
+        &app,
+        Method::PUT,
+        "/me/email",
+        Some(&cookie),
+        Some(json!({ "password": "password", "email": "new@example.com" })),
+    )
+    .await;
+    assert_eq!(status, StatusCode::TOO_MANY_REQUESTS);
+    let (status, _) = login_as(&app, "user@example.com", "password").await;
+    assert_eq!(status, StatusCode::TOO_MANY_REQUESTS);
+}
+
+#[tokio::test]
+async fn pg_signup_and_login() {
+    let app = pg_app();
+    // The database is shared between runs, so every run signs up a new user.
+    let email = format!("{}@example.com", uuid::Uuid::new_v4().simple());
+
+    let cookie = signup_and_login(&app, &email).await;
+
+    let (status, _, me) = send(&app, Method::GET, "/me", Some(&cookie), None).await;
+    assert_eq!(status, StatusCode::OK);
+    assert_eq!(me["email"], email.as_str());
+
+    let (status, _, _) = send(&app, Method::DELETE, "/me", Some(&cookie), None).await;
+    assert_eq!(status, StatusCode::OK);
+}
+
+#[tokio::test]
+async fn pg_todo_timestamps_round_trip() {
+    let app = pg_app();
+    let email = format!("{}@example.com", uuid::Uuid::new_v4().simple());
+    let cookie = signup_and_login(&app, &email).await;
+
+    let (status, _, created) = send(
+        &app,
+        Method::POST,
+        "/todos",
+        Some(&cookie),
+        Some(json!({ "title": "todo" })),
+    )
+    .await;
+    assert_eq!(status, StatusCode::OK);
+    let uri = format!("/todos/{}", created["id"].as_str().unwrap());
+
+    let (status, _, body) = send(&app, Method::GET, &uri, Some(&cookie), None).await;
+    assert_eq!(status, StatusCode::OK);
+    assert_eq!(body, created);
+
+    let (status, _, updated) = send(
+        &app,
+        Method::PATCH,
+        &uri,
+        Some(&cookie),
+        Some(json!({ "completed": true })),
+    )
+    .await;
+    assert_eq!(status, StatusCode::OK);
+
+    let (status, _, body) = send(&app, Method::GET, &uri, Some(&cookie), None).await;
+    assert_eq!(status, StatusCode::OK);
+    assert_eq!(body, updated);
+
+    let (status, _, _) = send(&app, Method::DELETE, "/me", Some(&cookie), None).await;
+    assert_eq!(status, StatusCode::OK);
+}